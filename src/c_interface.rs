@@ -324,10 +324,11 @@ pub extern "C" fn sysinfo_cpus_usage(
                 (*procs) =
                     libc::malloc(::std::mem::size_of::<c_float>() * cpus.len()) as *mut c_float;
             }
-            for (pos, cpu) in cpus.iter().skip(1).enumerate() {
+            // `cpus` doesn't contain the global CPU so nothing needs to be skipped.
+            for (pos, cpu) in cpus.iter().enumerate() {
                 (*(*procs).offset(pos as isize)) = cpu.cpu_usage();
             }
-            *length = cpus.len() as c_uint - 1;
+            *length = cpus.len() as c_uint;
         }
         Box::into_raw(system);
     }
@@ -1,8 +1,9 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::{
-    utils::into_iter_mut, ComponentInner, ComponentsInner, CpuInner, NetworkDataInner,
-    NetworksInner, ProcessInner, SystemInner, UserInner,
+    utils::{into_iter_mut, usage_percent},
+    ComponentInner, ComponentsInner, CpuInner, NetworkDataInner, NetworksInner, ProcessInner,
+    SystemInner, UserInner,
 };
 
 use std::cmp::Ordering;
@@ -10,9 +11,10 @@ use std::collections::{HashMap, HashSet};
 use std::ffi::{OsStr, OsString};
 use std::fmt;
 use std::fmt::Formatter;
-use std::net::IpAddr;
-use std::path::Path;
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 /// Structs containing system's information such as processes, memory and CPU.
 ///
@@ -80,7 +82,10 @@ impl System {
     ///      RefreshKind::new().with_processes(ProcessRefreshKind::everything()),
     /// );
     ///
-    /// # if sysinfo::IS_SUPPORTED_SYSTEM && !cfg!(feature = "apple-sandbox") {
+    /// # if sysinfo::IS_SUPPORTED_SYSTEM
+    /// #     && !cfg!(feature = "apple-sandbox")
+    /// #     && cfg!(feature = "processes")
+    /// # {
     /// assert!(!system.processes().is_empty());
     /// # }
     /// ```
@@ -92,6 +97,58 @@ impl System {
         s
     }
 
+    /// Same as [`System::new_with_specifics`] but returns an [`InitError`] listing the system
+    /// subsystems which failed to be initialized instead of silently not providing the
+    /// information depending on them.
+    ///
+    /// It is useful for diagnostic tools wanting to know why some information is missing.
+    ///
+    /// ⚠️ Only macOS and iOS can currently fail, it always returns `Ok` on other platforms.
+    ///
+    /// ```no_run
+    /// use sysinfo::{RefreshKind, System};
+    ///
+    /// match System::try_new_with_specifics(RefreshKind::everything()) {
+    ///     Ok(system) => println!("{} processes", system.processes().len()),
+    ///     Err(error) => eprintln!("{error}"),
+    /// }
+    /// ```
+    pub fn try_new_with_specifics(refreshes: RefreshKind) -> Result<Self, InitError> {
+        let s = Self::new_with_specifics(refreshes);
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "macos", target_os = "ios"),
+                not(feature = "unknown-ci"),
+            ))] {
+                let failed_subsystems = s.inner.failed_subsystems();
+                if !failed_subsystems.is_empty() {
+                    return Err(InitError { failed_subsystems });
+                }
+            }
+        }
+        Ok(s)
+    }
+
+    /// Sets a callback called with a description of the failure (the name of the failing
+    /// system call and its return code) every time a system call fails while this `System` is
+    /// refreshed.
+    ///
+    /// It is useful to understand why some information is missing on exotic systems. Nothing is
+    /// reported until a callback is set.
+    ///
+    /// ⚠️ Failures are only reported on macOS and iOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.set_error_callback(|error| eprintln!("[sysinfo] {error}"));
+    /// s.refresh_all();
+    /// ```
+    pub fn set_error_callback<F: Fn(&str) + Send + Sync + 'static>(&mut self, callback: F) {
+        self.inner.set_error_callback(Box::new(callback));
+    }
+
     /// Refreshes according to the given [`RefreshKind`]. It calls the corresponding
     /// "refresh_" methods.
     ///
@@ -184,6 +241,68 @@ impl System {
         self.refresh_cpu_specifics(CpuRefreshKind::new().with_cpu_usage())
     }
 
+    /// Refreshes only the global CPU usage (returned by [`System::global_cpu_usage`]).
+    ///
+    /// It is cheaper than [`System::refresh_cpu_usage`] since the CPUs usage is read for the
+    /// whole system at once. The information of each CPU (returned by [`System::cpus`]) is left
+    /// untouched, except at the first call where the CPUs list is created.
+    ///
+    /// ⚠️ Like for [`System::refresh_cpu_usage`], you need to call this method at least twice
+    /// (with a bit of time between each call) to get an accurate value.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_global_cpu_usage();
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_global_cpu_usage();
+    /// println!("{}%", s.global_cpu_usage());
+    /// ```
+    pub fn refresh_global_cpu_usage(&mut self) {
+        self.inner.refresh_global_cpu_usage()
+    }
+
+    /// Sets the smoothing factor (`alpha`) applied to the CPU usage. It is clamped between `0`
+    /// and `1`.
+    ///
+    /// At each refresh, the CPU usage returned by [`Cpu::cpu_usage`] and
+    /// [`System::global_cpu_usage`] becomes `alpha * new_usage + (1 - alpha) * previous_usage`
+    /// (an exponential moving average). The lower `alpha` is, the smoother the CPU usage will
+    /// be. The default value is `1`, which means there is no smoothing.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.set_cpu_smoothing(0.3);
+    /// loop {
+    ///     s.refresh_cpu_usage();
+    ///     println!("{}%", s.global_cpu_usage());
+    ///     std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// }
+    /// ```
+    pub fn set_cpu_smoothing(&mut self, alpha: f32) {
+        self.inner.set_cpu_smoothing(if alpha.is_nan() {
+            1.
+        } else {
+            alpha.clamp(0., 1.)
+        });
+    }
+
+    /// Returns the smoothing factor applied to the CPU usage. Take a look at
+    /// [`System::set_cpu_smoothing`] for more information.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// assert_eq!(s.cpu_smoothing(), 1.);
+    /// ```
+    pub fn cpu_smoothing(&self) -> f32 {
+        self.inner.cpu_smoothing()
+    }
+
     /// Refreshes CPUs frequency information.
     ///
     /// Calling this method is the same as calling
@@ -253,7 +372,7 @@ impl System {
     /// s.refresh_cpu_specifics(CpuRefreshKind::everything());
     /// ```
     pub fn refresh_cpu_specifics(&mut self, refresh_kind: CpuRefreshKind) {
-        self.inner.refresh_cpu_specifics(refresh_kind)
+        self.inner.refresh_cpu_specifics(refresh_kind);
     }
 
     /// Gets all processes and updates their information.
@@ -305,7 +424,107 @@ impl System {
     /// s.refresh_processes_specifics(ProcessRefreshKind::new());
     /// ```
     pub fn refresh_processes_specifics(&mut self, refresh_kind: ProcessRefreshKind) {
-        self.inner.refresh_processes_specifics(None, refresh_kind)
+        self.refresh_processes_inner(None, None, refresh_kind, None)
+    }
+
+    /// Gets all processes belonging to the user `uid` and updates their information. If `uid` is
+    /// `None`, the user running the current process is used instead. Processes belonging to other
+    /// users are skipped (and removed from the list if they were already in it).
+    ///
+    /// The owner of a process is checked before retrieving any other information, so it avoids
+    /// the expensive part of the refresh for processes that can't be read anyway.
+    ///
+    /// It refreshes the same information as [`System::refresh_processes`].
+    ///
+    /// ⚠️ On Windows, the owner can only be checked once the process handle was opened, so the
+    /// refresh is done for all processes and the other ones are removed afterwards.
+    ///
+    /// ⚠️ If the current user cannot be retrieved, all processes are refreshed.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// // Only the processes of the current user will be listed.
+    /// s.refresh_processes_filtered(None);
+    /// ```
+    pub fn refresh_processes_filtered(&mut self, uid: Option<&Uid>) {
+        let refresh_kind = ProcessRefreshKind::new()
+            .with_memory()
+            .with_cpu()
+            .with_disk_usage()
+            .with_exe(UpdateKind::OnlyIfNotSet);
+        let current_uid;
+        let uid = match uid {
+            Some(uid) => Some(uid),
+            None => {
+                current_uid = self.current_user_id();
+                current_uid.as_ref()
+            }
+        };
+        self.refresh_processes_inner(None, uid, refresh_kind, None)
+    }
+
+    /// Same as [`System::refresh_processes`] but returns which processes appeared and which ones
+    /// exited since the last refresh.
+    ///
+    /// The exited processes are returned with the information they had before being removed
+    /// from the process list.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new_all();
+    /// // We wait some time...?
+    /// let diff = s.refresh_processes_diff();
+    /// for pid in &diff.added {
+    ///     println!("new process: {pid}");
+    /// }
+    /// for process in &diff.exited {
+    ///     println!("process {} exited", process.pid());
+    /// }
+    /// ```
+    pub fn refresh_processes_diff(&mut self) -> ProcessDiff {
+        let refresh_kind = ProcessRefreshKind::new()
+            .with_memory()
+            .with_cpu()
+            .with_disk_usage()
+            .with_exe(UpdateKind::OnlyIfNotSet);
+        let previous = self.processes().keys().copied().collect::<HashSet<_>>();
+        let mut exited = Vec::new();
+        self.refresh_processes_inner(None, None, refresh_kind, Some(&mut exited));
+        let added = self
+            .processes()
+            .keys()
+            .filter(|pid| !previous.contains(pid))
+            .copied()
+            .collect();
+        ProcessDiff { added, exited }
+    }
+
+    fn current_user_id(&mut self) -> Option<Uid> {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "unknown-ci")] {
+                None
+            } else if #[cfg(any(
+                target_os = "freebsd",
+                target_os = "linux",
+                target_os = "android",
+                target_os = "macos",
+                target_os = "ios",
+            ))] {
+                unsafe { Some(Uid(libc::getuid())) }
+            } else if #[cfg(windows)] {
+                let pid = get_current_pid().ok()?;
+                self.refresh_process_specifics(
+                    pid,
+                    ProcessRefreshKind::new().with_user(UpdateKind::OnlyIfNotSet),
+                );
+                self.process(pid)?.user_id().cloned()
+            } else {
+                None
+            }
+        }
     }
 
     /// Gets specified processes and updates their information.
@@ -362,8 +581,7 @@ impl System {
         if pids.is_empty() {
             return;
         }
-        self.inner
-            .refresh_processes_specifics(Some(pids), refresh_kind)
+        self.refresh_processes_inner(Some(pids), None, refresh_kind, None)
     }
 
     /// Refreshes *only* the process corresponding to `pid`. Returns `false` if the process doesn't
@@ -426,7 +644,7 @@ impl System {
         pid: Pid,
         refresh_kind: ProcessRefreshKind,
     ) -> bool {
-        self.inner.refresh_process_specifics(pid, refresh_kind)
+        self.refresh_process_inner(pid, refresh_kind)
     }
 
     /// Returns the process list.
@@ -443,6 +661,75 @@ impl System {
         self.inner.processes()
     }
 
+    /// Calls `f` on each process currently running on the system, without keeping them in the
+    /// process list afterwards.
+    ///
+    /// The processes are retrieved one at a time, so it uses a lot less memory than
+    /// [`System::refresh_processes_specifics`] when you only need to look at them once. The
+    /// processes which were already in the process list are refreshed (all at once, before `f`
+    /// is called) and kept.
+    ///
+    /// ⚠️ Since the processes are not kept, the CPU usage can't be computed and will always be
+    /// `0` for the ones which weren't already in the process list.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessRefreshKind, System};
+    ///
+    /// let mut s = System::new();
+    /// s.for_each_process(ProcessRefreshKind::new(), |process| {
+    ///     if process.name() == "sshd" {
+    ///         println!("sshd is running with PID {}", process.pid());
+    ///     }
+    /// });
+    /// ```
+    pub fn for_each_process<F: FnMut(&Process)>(
+        &mut self,
+        refresh_kind: ProcessRefreshKind,
+        mut f: F,
+    ) {
+        // The listed processes are refreshed together so the CPUs are only refreshed once and
+        // the CPU usage is computed over the time elapsed since their previous refresh.
+        let listed = self.processes().keys().copied().collect::<Vec<_>>();
+        self.refresh_pids_specifics(&listed, refresh_kind);
+        let listed = listed.into_iter().collect::<HashSet<_>>();
+
+        for pid in self.list_pids() {
+            if listed.contains(&pid) {
+                if let Some(process) = self.process(pid) {
+                    f(process);
+                }
+                continue;
+            }
+            // The CPU usage can't be computed for these ones, so there's no need to refresh the
+            // CPUs for each of them.
+            if !self.refresh_process_specifics(pid, refresh_kind.without_cpu()) {
+                continue;
+            }
+            if let Some(process) = self.process(pid) {
+                f(process);
+            }
+            self.inner.remove_process(pid);
+        }
+    }
+
+    /// Returns the PIDs of all the processes currently running on the system.
+    ///
+    /// Unlike [`System::refresh_processes`], it doesn't retrieve any information about the
+    /// processes and doesn't update the process list, making it much cheaper to call.
+    ///
+    /// ⚠️ This method always returns an empty list on iOS and when the `apple-sandbox` feature
+    /// is enabled.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// println!("{} processes are running", s.list_pids().len());
+    /// ```
+    pub fn list_pids(&self) -> Vec<Pid> {
+        self.list_pids_inner()
+    }
+
     /// Returns the process corresponding to the given `pid` or `None` if no such process exists.
     ///
     /// ```no_run
@@ -457,6 +744,177 @@ impl System {
         self.inner.process(pid)
     }
 
+    /// Returns the process running the current program or `None` if it isn't listed (if the
+    /// processes weren't refreshed for example).
+    ///
+    /// It is a shortcut for calling [`System::process`] with the result of
+    /// [`get_current_pid`][crate::get_current_pid].
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.current_process() {
+    ///     println!("{} KiB used", process.memory() / 1_024);
+    /// }
+    /// ```
+    pub fn current_process(&self) -> Option<&Process> {
+        self.process(get_current_pid().ok()?)
+    }
+
+    /// Returns the sum of the CPU usage of the process corresponding to `pid` and of all its
+    /// descendants, or `0.` if the process isn't listed.
+    ///
+    /// Like [`Process::cpu_usage`], the processes need to have been refreshed at least twice
+    /// with the CPU usage enabled to get a meaningful value. The processes which aren't listed
+    /// (because they were started after the last refresh for example) are not taken into
+    /// account.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_all();
+    /// println!("{}%", s.subtree_cpu_usage(Pid::from(1337)));
+    /// ```
+    pub fn subtree_cpu_usage(&self, pid: Pid) -> f32 {
+        let processes = self.processes();
+        if !processes.contains_key(&pid) {
+            return 0.;
+        }
+        let mut children: HashMap<Pid, Vec<&Process>> = HashMap::new();
+        for process in processes.values() {
+            // The CPU usage of the threads is already included in the one of their process.
+            if process.thread_kind() == Some(ThreadKind::Userland) {
+                continue;
+            }
+            if let Some(parent) = process.parent() {
+                children.entry(parent).or_default().push(process);
+            }
+        }
+        // The tree is walked iteratively so deep trees can't overflow the stack, and the visited
+        // PIDs are tracked in case the parents form a cycle.
+        let mut visited = HashSet::new();
+        let mut to_visit = vec![pid];
+        let mut cpu_usage = 0.;
+        while let Some(pid) = to_visit.pop() {
+            if !visited.insert(pid) {
+                continue;
+            }
+            if let Some(process) = processes.get(&pid) {
+                cpu_usage += process.cpu_usage();
+            }
+            if let Some(children) = children.get(&pid) {
+                to_visit.extend(children.iter().map(|child| child.pid()));
+            }
+        }
+        cpu_usage
+    }
+
+    /// Returns a [`Snapshot`] of the current information: memory, swap, CPUs and processes.
+    ///
+    /// Unlike `System`, the snapshot only contains plain data, so it can be cheaply cloned and
+    /// sent to other threads (a UI thread for example) while `System` keeps being refreshed in
+    /// the background.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new_all();
+    /// let (sender, receiver) = std::sync::mpsc::channel();
+    ///
+    /// std::thread::spawn(move || loop {
+    ///     std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    ///     s.refresh_all();
+    ///     if sender.send(s.snapshot()).is_err() {
+    ///         break;
+    ///     }
+    /// });
+    /// for snapshot in receiver {
+    ///     println!("{} bytes used", snapshot.used_memory);
+    /// }
+    /// ```
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            total_memory: self.total_memory(),
+            free_memory: self.free_memory(),
+            available_memory: self.available_memory(),
+            used_memory: self.used_memory(),
+            total_swap: self.total_swap(),
+            free_swap: self.free_swap(),
+            used_swap: self.used_swap(),
+            global_cpu_info: CpuSnapshot::new(self.global_cpu_info()),
+            cpus: self.cpus().iter().map(CpuSnapshot::new).collect(),
+            processes: self
+                .processes()
+                .iter()
+                .map(|(pid, process)| (*pid, ProcessSnapshot::new(process)))
+                .collect(),
+            disks: Vec::new(),
+            networks: HashMap::new(),
+        }
+    }
+
+    /// Returns the current information as a flat list of named metrics, to be sent to a metrics
+    /// exporter (like StatsD or Prometheus). Disks and networks can be added with
+    /// [`Disks::export_metrics`] and [`Networks::export_metrics`].
+    ///
+    /// The metrics are named as follows (`<index>` being the position of the CPU in
+    /// [`System::cpus`]):
+    ///
+    /// | Name                    | Value                                        |
+    /// |-------------------------|----------------------------------------------|
+    /// | `memory.total`          | [`System::total_memory`], in bytes           |
+    /// | `memory.used`           | [`System::used_memory`], in bytes            |
+    /// | `memory.free`           | [`System::free_memory`], in bytes            |
+    /// | `memory.available`      | [`System::available_memory`], in bytes       |
+    /// | `swap.total`            | [`System::total_swap`], in bytes             |
+    /// | `swap.used`             | [`System::used_swap`], in bytes              |
+    /// | `swap.free`             | [`System::free_swap`], in bytes              |
+    /// | `cpu.usage`             | [`System::global_cpu_usage`], in %           |
+    /// | `cpu.<index>.usage`     | [`Cpu::cpu_usage`], in %                     |
+    /// | `cpu.<index>.frequency` | [`Cpu::frequency`], in MHz                   |
+    /// | `load.one`              | [`LoadAvg::one`]                             |
+    /// | `load.five`             | [`LoadAvg::five`]                            |
+    /// | `load.fifteen`          | [`LoadAvg::fifteen`]                         |
+    /// | `processes.count`       | Number of processes in [`System::processes`] |
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for (name, value) in s.export_metrics() {
+    ///     println!("{name}:{value}|g");
+    /// }
+    /// ```
+    pub fn export_metrics(&self) -> Vec<(String, f64)> {
+        let load_avg = Self::load_average();
+        let mut metrics = vec![
+            ("memory.total".to_owned(), self.total_memory() as f64),
+            ("memory.used".to_owned(), self.used_memory() as f64),
+            ("memory.free".to_owned(), self.free_memory() as f64),
+            (
+                "memory.available".to_owned(),
+                self.available_memory() as f64,
+            ),
+            ("swap.total".to_owned(), self.total_swap() as f64),
+            ("swap.used".to_owned(), self.used_swap() as f64),
+            ("swap.free".to_owned(), self.free_swap() as f64),
+            ("cpu.usage".to_owned(), self.global_cpu_usage() as f64),
+            ("load.one".to_owned(), load_avg.one),
+            ("load.five".to_owned(), load_avg.five),
+            ("load.fifteen".to_owned(), load_avg.fifteen),
+            ("processes.count".to_owned(), self.processes().len() as f64),
+        ];
+        for (index, cpu) in self.cpus().iter().enumerate() {
+            metrics.push((format!("cpu.{index}.usage"), cpu.cpu_usage() as f64));
+            metrics.push((format!("cpu.{index}.frequency"), cpu.frequency() as f64));
+        }
+        metrics
+    }
+
     /// Returns an iterator of process containing the given `name`.
     ///
     /// If you want only the processes with exactly the given `name`, take a look at
@@ -514,1986 +972,4539 @@ impl System {
             .filter(move |val: &&Process| val.name() == name)
     }
 
-    /// Returns "global" CPUs information (aka the addition of all the CPUs).
-    ///
-    /// To have up-to-date information, you need to call [`System::refresh_cpu_specifics`] or
-    /// [`System::refresh_specifics`] with `cpu` enabled.
+    /// Returns an iterator over the processes, without the ones belonging to the system itself.
     ///
-    /// **⚠️ Important ⚠️**
+    /// The process with PID 0 (`kernel_task` on macOS, `System Idle Process` on Windows) and the
+    /// kernel threads (see [`Process::thread_kind`]) are skipped. The init process (PID 1, `init`
+    /// or `systemd` on Linux, `launchd` on macOS) is only returned if `include_init` is `true`.
     ///
-    /// Information like [`Cpu::brand`], [`Cpu::vendor_id`] or [`Cpu::frequency`]
-    /// are not set on the "global" CPU.
+    /// [`System::processes`] still returns the full list.
     ///
     /// ```no_run
-    /// use sysinfo::{CpuRefreshKind, RefreshKind, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_with_specifics(
-    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
-    /// );
-    /// println!("{}%", s.global_cpu_info().cpu_usage());
+    /// let s = System::new_all();
+    /// for process in s.user_processes(false) {
+    ///     println!("{} {:?}", process.pid(), process.name());
+    /// }
     /// ```
-    ///
-    /// [`Cpu::brand`]: crate::Cpu::brand
-    /// [`Cpu::vendor_id`]: crate::Cpu::vendor_id
-    /// [`Cpu::frequency`]: crate::Cpu::frequency
-    pub fn global_cpu_info(&self) -> &Cpu {
-        self.inner.global_cpu_info()
+    pub fn user_processes(&self, include_init: bool) -> impl Iterator<Item = &Process> + '_ {
+        self.processes().values().filter(move |process| {
+            match process.pid().as_u32() {
+                0 => return false,
+                1 if !include_init => return false,
+                _ => {}
+            }
+            process.thread_kind() != Some(ThreadKind::Kernel)
+        })
     }
 
-    /// Returns the list of the CPUs.
-    ///
-    /// By default, the list of CPUs is empty until you call [`System::refresh_cpu_specifics`] or
-    /// [`System::refresh_specifics`] with `cpu` enabled.
+    /// Returns the PIDs of the processes which exited but haven't been reaped by their parent
+    /// yet (in the [`ProcessStatus::Zombie`] state).
     ///
     /// ```no_run
-    /// use sysinfo::{CpuRefreshKind, RefreshKind, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_with_specifics(
-    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
-    /// );
-    /// for cpu in s.cpus() {
-    ///     println!("{}%", cpu.cpu_usage());
+    /// let s = System::new_all();
+    /// for pid in s.zombie_processes() {
+    ///     println!("{pid} is a zombie");
     /// }
     /// ```
-    pub fn cpus(&self) -> &[Cpu] {
-        self.inner.cpus()
+    pub fn zombie_processes(&self) -> Vec<Pid> {
+        self.processes()
+            .values()
+            .filter(|process| process.status() == ProcessStatus::Zombie)
+            .map(|process| process.pid())
+            .collect()
     }
 
-    /// Returns the number of physical cores on the CPU or `None` if it couldn't get it.
-    ///
-    /// In case there are multiple CPUs, it will combine the physical core count of all the CPUs.
+    /// Returns the PIDs of the processes whose parent isn't in the processes list anymore.
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// Since it relies on the processes list, it is only meaningful after a refresh of all the
+    /// processes (with [`System::refresh_processes`] for example).
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// let s = System::new();
-    /// println!("{:?}", s.physical_core_count());
+    /// let s = System::new_all();
+    /// for pid in s.orphaned_processes() {
+    ///     println!("the parent of {pid} is gone");
+    /// }
     /// ```
-    pub fn physical_core_count(&self) -> Option<usize> {
-        self.inner.physical_core_count()
+    pub fn orphaned_processes(&self) -> Vec<Pid> {
+        let processes = self.processes();
+        processes
+            .values()
+            .filter(|process| match process.parent() {
+                // PID 0 isn't a real process on all systems, so its children aren't orphans.
+                Some(parent) => parent.as_u32() != 0 && !processes.contains_key(&parent),
+                None => false,
+            })
+            .map(|process| process.pid())
+            .collect()
     }
 
-    /// Returns the RAM size in bytes.
+    /// Returns the processes sorted with the `compare` function.
+    ///
+    /// The process list isn't modified, only the returned references are sorted.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// println!("{} bytes", s.total_memory());
+    /// // Oldest processes first.
+    /// for process in s.processes_sorted_by(|a, b| b.run_time().cmp(&a.run_time())) {
+    ///     println!("{} {:?}", process.pid(), process.name());
+    /// }
     /// ```
-    ///
-    /// On Linux, if you want to see this information with the limit of your cgroup, take a look
-    /// at [`cgroup_limits`](System::cgroup_limits).
-    pub fn total_memory(&self) -> u64 {
-        self.inner.total_memory()
+    pub fn processes_sorted_by<F>(&self, compare: F) -> Vec<&Process>
+    where
+        F: FnMut(&&Process, &&Process) -> Ordering,
+    {
+        let mut processes = self.processes().values().collect::<Vec<_>>();
+        processes.sort_by(compare);
+        processes
     }
 
-    /// Returns the amount of free RAM in bytes.
-    ///
-    /// Generally, "free" memory refers to unallocated memory whereas "available" memory refers to
-    /// memory that is available for (re)use.
+    /// Returns the (at most) `n` processes using the most CPU, in decreasing order.
     ///
-    /// Side note: Windows doesn't report "free" memory so this method returns the same value
-    /// as [`available_memory`](System::available_memory).
+    /// To have up-to-date information, you need to refresh the processes with `cpu` enabled (at
+    /// least twice, take a look at [`Process::cpu_usage`] for more information).
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// println!("{} bytes", s.free_memory());
+    /// for process in s.top_processes_by_cpu(5) {
+    ///     println!("{:?}: {}%", process.name(), process.cpu_usage());
+    /// }
     /// ```
-    pub fn free_memory(&self) -> u64 {
-        self.inner.free_memory()
+    pub fn top_processes_by_cpu(&self, n: usize) -> Vec<&Process> {
+        let mut processes =
+            self.processes_sorted_by(|a, b| b.cpu_usage().total_cmp(&a.cpu_usage()));
+        processes.truncate(n);
+        processes
     }
 
-    /// Returns the amount of available RAM in bytes.
-    ///
-    /// Generally, "free" memory refers to unallocated memory whereas "available" memory refers to
-    /// memory that is available for (re)use.
-    ///
-    /// ⚠️ Windows and FreeBSD don't report "available" memory so [`System::free_memory`]
-    /// returns the same value as this method.
+    /// Returns the (at most) `n` processes using the most memory, in decreasing order.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// println!("{} bytes", s.available_memory());
+    /// for process in s.top_processes_by_memory(5) {
+    ///     println!("{:?}: {} bytes", process.name(), process.memory());
+    /// }
     /// ```
-    pub fn available_memory(&self) -> u64 {
-        self.inner.available_memory()
+    pub fn top_processes_by_memory(&self, n: usize) -> Vec<&Process> {
+        let mut processes = self.processes_sorted_by(|a, b| b.memory().cmp(&a.memory()));
+        processes.truncate(n);
+        processes
     }
 
-    /// Returns the amount of used RAM in bytes.
+    /// Returns the processes which have memory swapped out, sorted by decreasing
+    /// [`Process::swapped_memory`].
+    ///
+    /// Processes which couldn't be inspected are skipped, and the kernel uses swap too, so the
+    /// sum of their swapped memory doesn't necessarily match [`System::used_swap`]. The
+    /// processes need to have been refreshed with [`ProcessRefreshKind::memory_regions`].
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// println!("{} bytes", s.used_memory());
+    /// for process in s.processes_using_swap().iter().take(5) {
+    ///     println!("{:?}: {} bytes", process.name(), process.swapped_memory());
+    /// }
     /// ```
-    pub fn used_memory(&self) -> u64 {
-        self.inner.used_memory()
+    pub fn processes_using_swap(&self) -> Vec<&Process> {
+        // The swapped memory is retrieved only once per process so the sort order is consistent.
+        let mut processes = self
+            .processes()
+            .values()
+            .map(|process| (process.swapped_memory(), process))
+            .filter(|(swapped, _)| *swapped != 0)
+            .collect::<Vec<_>>();
+        processes.sort_by_key(|(swapped, _)| std::cmp::Reverse(*swapped));
+        processes.into_iter().map(|(_, process)| process).collect()
     }
 
-    /// Returns the SWAP size in bytes.
+    /// Returns "global" CPUs information (aka the addition of all the CPUs).
+    ///
+    /// To have up-to-date information, you need to call [`System::refresh_cpu_specifics`] or
+    /// [`System::refresh_specifics`] with `cpu` enabled.
+    ///
+    /// **⚠️ Important ⚠️**
+    ///
+    /// Information like [`Cpu::brand`], [`Cpu::vendor_id`] or [`Cpu::frequency`]
+    /// are not set on the "global" CPU.
     ///
     /// ```no_run
-    /// use sysinfo::System;
+    /// use sysinfo::{CpuRefreshKind, RefreshKind, System};
     ///
-    /// let s = System::new_all();
-    /// println!("{} bytes", s.total_swap());
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// println!("{}%", s.global_cpu_info().cpu_usage());
     /// ```
-    pub fn total_swap(&self) -> u64 {
-        self.inner.total_swap()
+    ///
+    /// [`Cpu::brand`]: crate::Cpu::brand
+    /// [`Cpu::vendor_id`]: crate::Cpu::vendor_id
+    /// [`Cpu::frequency`]: crate::Cpu::frequency
+    pub fn global_cpu_info(&self) -> &Cpu {
+        self.inner.global_cpu_info()
     }
 
-    /// Returns the amount of free SWAP in bytes.
+    /// Returns the usage (in %) of all the CPUs combined. It is the same as calling
+    /// `system.global_cpu_info().cpu_usage()`.
+    ///
+    /// ⚠️ It is `0` until the CPU usage has been refreshed twice (with a bit of time between
+    /// each call, take a look at [`MINIMUM_CPU_UPDATE_INTERVAL`] for more information). You can
+    /// use [`System::prime_cpu_usage`] to do it.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// println!("{} bytes", s.free_swap());
+    /// let mut s = System::new();
+    /// s.prime_cpu_usage();
+    /// println!("{}%", s.global_cpu_usage());
     /// ```
-    pub fn free_swap(&self) -> u64 {
-        self.inner.free_swap()
+    ///
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`]: crate::MINIMUM_CPU_UPDATE_INTERVAL
+    pub fn global_cpu_usage(&self) -> f32 {
+        self.global_cpu_info().cpu_usage()
     }
 
-    /// Returns the amount of used SWAP in bytes.
+    /// Refreshes the CPU usage twice, waiting [`MINIMUM_CPU_UPDATE_INTERVAL`] in between, so the
+    /// CPU usage values are meaningful right away.
+    ///
+    /// ⚠️ This method blocks the current thread for [`MINIMUM_CPU_UPDATE_INTERVAL`]. Once it
+    /// has been called, refreshing the CPU usage with [`System::refresh_cpu_usage`] is enough.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// println!("{} bytes", s.used_swap());
+    /// let mut s = System::new();
+    /// s.prime_cpu_usage();
+    /// for cpu in s.cpus() {
+    ///     println!("{}%", cpu.cpu_usage());
+    /// }
     /// ```
-    pub fn used_swap(&self) -> u64 {
-        self.inner.used_swap()
+    ///
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`]: crate::MINIMUM_CPU_UPDATE_INTERVAL
+    pub fn prime_cpu_usage(&mut self) {
+        self.refresh_cpu_usage();
+        std::thread::sleep(crate::MINIMUM_CPU_UPDATE_INTERVAL);
+        self.refresh_cpu_usage();
     }
 
-    /// Retrieves the limits for the current cgroup (if any), otherwise it returns `None`.
-    ///
-    /// This information is computed every time the method is called.
+    /// Returns the list of the CPUs.
     ///
-    /// ⚠️ You need to have run [`refresh_memory`](System::refresh_memory) at least once before
-    /// calling this method.
+    /// It contains one entry per logical CPU and doesn't include the aggregated "global" CPU,
+    /// which is available with [`System::global_cpu_info`].
     ///
-    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
-    /// systems.
+    /// By default, the list of CPUs is empty until you call [`System::refresh_cpu_specifics`] or
+    /// [`System::refresh_specifics`] with `cpu` enabled. To get the number of logical CPUs
+    /// without refreshing them, use [`System::logical_cpu_count`].
     ///
     /// ```no_run
-    /// use sysinfo::System;
+    /// use sysinfo::{CpuRefreshKind, RefreshKind, System};
     ///
-    /// let s = System::new_all();
-    /// println!("limits: {:?}", s.cgroup_limits());
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{}%", cpu.cpu_usage());
+    /// }
     /// ```
-    pub fn cgroup_limits(&self) -> Option<CGroupLimits> {
-        self.inner.cgroup_limits()
+    pub fn cpus(&self) -> &[Cpu] {
+        self.inner.cpus()
     }
 
-    /// Returns system uptime (in seconds).
+    /// Returns the cumulative CPU times as of the last CPU refresh.
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// They can be passed later to [`System::cpu_usage_since`] and
+    /// [`System::global_cpu_usage_since`] to get the CPU usage between the two refreshes.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("System running since {} seconds", System::uptime());
+    /// let mut s = System::new();
+    /// s.refresh_cpu_usage();
+    /// let times = s.cpu_times();
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// s.refresh_cpu_usage();
+    /// println!("{}%", s.global_cpu_usage_since(&times));
     /// ```
-    pub fn uptime() -> u64 {
-        SystemInner::uptime()
+    pub fn cpu_times(&self) -> CpuTimes {
+        CpuTimes {
+            global: self.global_cpu_info().inner.times(),
+            cpus: self.cpus().iter().map(|cpu| cpu.inner.times()).collect(),
+        }
     }
 
-    /// Returns the time (in seconds) when the system booted since UNIX epoch.
+    /// Returns the usage of each CPU (in %) between the moment `times` were retrieved with
+    /// [`System::cpu_times`] and the last CPU refresh, in the same order as [`System::cpus`].
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// Unlike [`Cpu::cpu_usage`], the period doesn't depend on the previous CPU refresh.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("System booted at {} seconds", System::boot_time());
+    /// let mut s = System::new();
+    /// s.refresh_cpu_usage();
+    /// let times = s.cpu_times();
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// s.refresh_cpu_usage();
+    /// for (cpu, usage) in s.cpus().iter().zip(s.cpu_usage_since(&times)) {
+    ///     println!("{}: {usage}%", cpu.name());
+    /// }
     /// ```
-    pub fn boot_time() -> u64 {
-        SystemInner::boot_time()
+    pub fn cpu_usage_since(&self, times: &CpuTimes) -> Vec<f32> {
+        self.cpus()
+            .iter()
+            .zip(times.cpus.iter())
+            .map(|(cpu, old)| CpuTimes::usage_between(*old, cpu.inner.times()))
+            .collect()
     }
 
-    /// Returns the system load average value.
-    ///
-    /// **Important**: this information is computed every time this function is called.
-    ///
-    /// ⚠️ This is currently not working on **Windows**.
+    /// Returns the global CPU usage (in %) between the moment `times` were retrieved with
+    /// [`System::cpu_times`] and the last CPU refresh.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// let load_avg = System::load_average();
-    /// println!(
-    ///     "one minute: {}%, five minutes: {}%, fifteen minutes: {}%",
-    ///     load_avg.one,
-    ///     load_avg.five,
-    ///     load_avg.fifteen,
-    /// );
+    /// let mut s = System::new();
+    /// s.refresh_cpu_usage();
+    /// let times = s.cpu_times();
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// s.refresh_cpu_usage();
+    /// println!("{}%", s.global_cpu_usage_since(&times));
     /// ```
-    pub fn load_average() -> LoadAvg {
-        SystemInner::load_average()
+    pub fn global_cpu_usage_since(&self, times: &CpuTimes) -> f32 {
+        CpuTimes::usage_between(times.global, self.global_cpu_info().inner.times())
     }
 
-    /// Returns the system name.
+    /// Returns the number of logical CPUs (including the hyperthreads) or `None` if it couldn't
+    /// get it.
+    ///
+    /// Unlike `cpus().len()`, it doesn't require the CPUs to have been refreshed.
     ///
     /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("OS: {:?}", System::name());
+    /// let s = System::new();
+    /// println!("{:?}", s.logical_cpu_count());
     /// ```
-    pub fn name() -> Option<String> {
-        SystemInner::name()
+    pub fn logical_cpu_count(&self) -> Option<usize> {
+        self.inner.logical_cpu_count()
     }
 
-    /// Returns the system's kernel version.
+    /// Returns the number of logical CPUs which are currently online (available to the
+    /// scheduler), or `None` if it couldn't get it.
+    ///
+    /// It can be lower than [`System::logical_cpu_count`] when some cores were parked to save
+    /// power or limit the temperature. When the number of online CPUs isn't available, the
+    /// logical CPU count is returned instead.
     ///
     /// **Important**: this information is computed every time this function is called.
     ///
+    /// ⚠️ On Linux and Windows, it is the same as [`System::logical_cpu_count`].
+    ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("kernel version: {:?}", System::kernel_version());
+    /// let s = System::new();
+    /// if let (Some(online), Some(logical)) = (s.online_cpu_count(), s.logical_cpu_count()) {
+    ///     println!("{online}/{logical} CPUs online");
+    /// }
     /// ```
-    pub fn kernel_version() -> Option<String> {
-        SystemInner::kernel_version()
+    pub fn online_cpu_count(&self) -> Option<usize> {
+        self.inner.online_cpu_count()
     }
 
-    /// Returns the system version (e.g. for MacOS this will return 11.1 rather than the kernel
-    /// version).
+    /// Returns the number of physical cores on the CPU or `None` if it couldn't get it.
+    ///
+    /// In case there are multiple CPUs, it will combine the physical core count of all the CPUs.
     ///
     /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("OS version: {:?}", System::os_version());
+    /// let s = System::new();
+    /// println!("{:?}", s.physical_core_count());
     /// ```
-    pub fn os_version() -> Option<String> {
-        SystemInner::os_version()
+    pub fn physical_core_count(&self) -> Option<usize> {
+        self.inner.physical_core_count()
     }
 
-    /// Returns the system long os version (e.g "MacOS 11.2 BigSur").
+    /// Returns the size (in bytes) of the L1 data cache of a CPU core, or `None` if it couldn't
+    /// get it.
+    ///
+    /// On systems with different kinds of cores (like Apple Silicon), it is the cache of the
+    /// performance cores.
     ///
     /// **Important**: this information is computed every time this function is called.
     ///
+    /// ⚠️ This method always returns `None` on FreeBSD.
+    ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("Long OS Version: {:?}", System::long_os_version());
+    /// let s = System::new();
+    /// println!("{:?}", s.l1_cache_size());
     /// ```
-    pub fn long_os_version() -> Option<String> {
-        SystemInner::long_os_version()
+    pub fn l1_cache_size(&self) -> Option<u64> {
+        self.inner.cache_size(1)
     }
 
-    /// Returns the distribution id as defined by os-release,
-    /// or [`std::env::consts::OS`].
+    /// Returns the size (in bytes) of the L2 cache used by a CPU core, or `None` if it couldn't
+    /// get it.
     ///
-    /// See also
-    /// - <https://www.freedesktop.org/software/systemd/man/os-release.html#ID=>
-    /// - <https://doc.rust-lang.org/std/env/consts/constant.OS.html>
+    /// The L2 cache is shared between several cores on some CPUs. Like for
+    /// [`System::l1_cache_size`], the cache of the performance cores is returned on systems with
+    /// different kinds of cores.
     ///
     /// **Important**: this information is computed every time this function is called.
     ///
+    /// ⚠️ This method always returns `None` on FreeBSD.
+    ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("Distribution ID: {:?}", System::distribution_id());
+    /// let s = System::new();
+    /// println!("{:?}", s.l2_cache_size());
     /// ```
-    pub fn distribution_id() -> String {
-        SystemInner::distribution_id()
+    pub fn l2_cache_size(&self) -> Option<u64> {
+        self.inner.cache_size(2)
     }
 
-    /// Returns the system hostname based off DNS.
+    /// Returns the size (in bytes) of the L3 cache used by a CPU core, or `None` if there is no
+    /// L3 cache (like on Apple Silicon) or if it couldn't get it.
     ///
     /// **Important**: this information is computed every time this function is called.
     ///
+    /// ⚠️ This method always returns `None` on FreeBSD.
+    ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("Hostname: {:?}", System::host_name());
+    /// let s = System::new();
+    /// if let Some(size) = s.l3_cache_size() {
+    ///     println!("L3 cache: {} KiB", size / 1024);
+    /// }
     /// ```
-    pub fn host_name() -> Option<String> {
-        SystemInner::host_name()
+    pub fn l3_cache_size(&self) -> Option<u64> {
+        self.inner.cache_size(3)
     }
 
-    /// Returns the CPU architecture (eg. x86, amd64, aarch64, ...).
-    ///
-    /// **Important**: this information is computed every time this function is called.
+    /// Returns the RAM size in bytes.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("CPU Archicture: {:?}", System::cpu_arch());
+    /// let s = System::new_all();
+    /// println!("{} bytes", s.total_memory());
     /// ```
-    pub fn cpu_arch() -> Option<String> {
-        SystemInner::cpu_arch()
+    ///
+    /// On Linux, if you want to see this information with the limit of your cgroup, take a look
+    /// at [`cgroup_limits`](System::cgroup_limits).
+    pub fn total_memory(&self) -> u64 {
+        self.inner.total_memory()
     }
-}
-
-/// Struct containing information of a process.
-///
-/// ## iOS
-///
-/// This information cannot be retrieved on iOS due to sandboxing.
-///
-/// ## Apple app store
-///
-/// If you are building a macOS Apple app store, it won't be able
-/// to retrieve this information.
-///
-/// ```no_run
-/// use sysinfo::{Pid, System};
-///
-/// let s = System::new_all();
-/// if let Some(process) = s.process(Pid::from(1337)) {
-///     println!("{:?}", process.name());
-/// }
-/// ```
-pub struct Process {
-    pub(crate) inner: ProcessInner,
-}
 
-impl Process {
-    /// Sends [`Signal::Kill`] to the process (which is the only signal supported on all supported
-    /// platforms by this crate).
-    ///
-    /// If you want to send another signal, take a look at [`Process::kill_with`].
+    /// Returns the size of a memory page in bytes.
     ///
-    /// To get the list of the supported signals on this system, use
-    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+    /// ⚠️ It returns `0` on unsupported platforms.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     process.kill();
-    /// }
+    /// let s = System::new();
+    /// println!("{} bytes", s.page_size());
     /// ```
-    pub fn kill(&self) -> bool {
-        self.kill_with(Signal::Kill).unwrap_or(false)
+    pub fn page_size(&self) -> u64 {
+        self.inner.page_size()
     }
 
-    /// Sends the given `signal` to the process. If the signal doesn't exist on this platform,
-    /// it'll do nothing and will return `None`. Otherwise it'll return if the signal was sent
-    /// successfully.
-    ///
-    /// If you just want to kill the process, use [`Process::kill`] directly.
-    ///
-    /// To get the list of the supported signals on this system, use
-    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+    /// Returns the number of memory pages of the RAM, computed from [`System::total_memory`]
+    /// and [`System::page_size`].
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, Signal, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     if process.kill_with(Signal::Kill).is_none() {
-    ///         println!("This signal isn't supported on this platform");
-    ///     }
-    /// }
+    /// println!("{} pages", s.total_pages());
     /// ```
-    pub fn kill_with(&self, signal: Signal) -> Option<bool> {
-        self.inner.kill_with(signal)
+    pub fn total_pages(&self) -> u64 {
+        self.total_memory()
+            .checked_div(self.page_size())
+            .unwrap_or(0)
     }
 
-    /// Returns the name of the process.
-    ///
-    /// **⚠️ Important ⚠️**
+    /// Returns the amount of free RAM in bytes.
     ///
-    /// On **Linux**, there are two things to know about processes' name:
-    ///  1. It is limited to 15 characters.
-    ///  2. It is not always the exe name.
+    /// Generally, "free" memory refers to unallocated memory whereas "available" memory refers to
+    /// memory that is available for (re)use.
     ///
-    /// If you are looking for a specific process, unless you know what you are
-    /// doing, in most cases it's better to use [`Process::exe`] instead (which
-    /// can be empty sometimes!).
+    /// Side note: Windows doesn't report "free" memory so this method returns the same value
+    /// as [`available_memory`](System::available_memory).
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.name());
-    /// }
+    /// println!("{} bytes", s.free_memory());
     /// ```
-    pub fn name(&self) -> &OsStr {
-        self.inner.name()
+    pub fn free_memory(&self) -> u64 {
+        self.inner.free_memory()
     }
 
-    /// Returns the command line.
+    /// Returns the amount of available RAM in bytes.
+    ///
+    /// Generally, "free" memory refers to unallocated memory whereas "available" memory refers to
+    /// memory that is available for (re)use.
+    ///
+    /// ⚠️ Windows and FreeBSD don't report "available" memory so [`System::free_memory`]
+    /// returns the same value as this method.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.cmd());
-    /// }
+    /// println!("{} bytes", s.available_memory());
     /// ```
-    pub fn cmd(&self) -> &[OsString] {
-        self.inner.cmd()
+    pub fn available_memory(&self) -> u64 {
+        self.inner.available_memory()
     }
 
-    /// Returns the path to the process.
+    /// Returns the amount of used RAM in bytes.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.exe());
-    /// }
+    /// println!("{} bytes", s.used_memory());
     /// ```
-    ///
-    /// ### Implementation notes
-    ///
-    /// On Linux, this method will return an empty path if there
-    /// was an error trying to read `/proc/<pid>/exe`. This can
-    /// happen, for example, if the permission levels or UID namespaces
-    /// between the caller and target processes are different.
-    ///
-    /// It is also the case that `cmd[0]` is _not_ usually a correct
-    /// replacement for this.
-    /// A process [may change its `cmd[0]` value](https://man7.org/linux/man-pages/man5/proc.5.html)
-    /// freely, making this an untrustworthy source of information.
-    pub fn exe(&self) -> Option<&Path> {
-        self.inner.exe()
+    pub fn used_memory(&self) -> u64 {
+        self.inner.used_memory()
     }
 
-    /// Returns the PID of the process.
+    /// Returns the percentage of RAM used, computed from [`System::used_memory`] and
+    /// [`System::total_memory`].
+    ///
+    /// It returns `0.` if the total memory is unknown (if the memory wasn't refreshed yet for
+    /// example).
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{}", process.pid());
-    /// }
+    /// println!("{:.1}% used", s.memory_usage_percent());
     /// ```
-    pub fn pid(&self) -> Pid {
-        self.inner.pid()
+    pub fn memory_usage_percent(&self) -> f32 {
+        usage_percent(self.used_memory(), self.total_memory())
     }
 
-    /// Returns the environment variables of the process.
+    /// Returns the amount of RAM used by the applications in bytes, without the memory used by
+    /// the kernel (the wired memory) and by the compressor.
+    ///
+    /// On macOS, it matches the "App Memory" value displayed by the Activity Monitor and is
+    /// computed from the `vm_statistics64` fields as follows:
+    ///
+    /// ```text
+    /// (internal_page_count - purgeable_count) * page_size
+    /// ```
+    ///
+    /// See [`System::used_memory_full`] to include the wired and compressed memory.
+    ///
+    /// ⚠️ On other platforms than macOS and iOS, this method returns the same value as
+    /// [`System::used_memory`].
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.environ());
-    /// }
+    /// println!("{} bytes", s.used_memory_app());
     /// ```
-    pub fn environ(&self) -> &[OsString] {
-        self.inner.environ()
+    pub fn used_memory_app(&self) -> u64 {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "macos", target_os = "ios"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.used_memory_app()
+            } else {
+                self.used_memory()
+            }
+        }
     }
 
-    /// Returns the current working directory.
+    /// Returns the amount of used RAM in bytes, including the memory used by the compressor.
+    ///
+    /// On macOS, it matches the "Memory Used" value displayed by the Activity Monitor and is
+    /// computed from the `vm_statistics64` fields as follows:
+    ///
+    /// ```text
+    /// (internal_page_count - purgeable_count + wire_count + compressor_page_count) * page_size
+    /// ```
+    ///
+    /// ⚠️ On other platforms than macOS and iOS, the memory isn't compressed so this method
+    /// returns the same value as [`System::used_memory`].
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.cwd());
-    /// }
+    /// println!("{} bytes", s.used_memory_full());
     /// ```
-    pub fn cwd(&self) -> Option<&Path> {
-        self.inner.cwd()
+    pub fn used_memory_full(&self) -> u64 {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "macos", target_os = "ios"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.used_memory_full()
+            } else {
+                self.used_memory()
+            }
+        }
     }
 
-    /// Returns the path of the root directory.
+    /// Returns the amount of inactive RAM in bytes, or `None` if it's not supported.
+    ///
+    /// Inactive pages hold data that hasn't been accessed recently. They are still mapped but are
+    /// the first ones to be reclaimed (or compressed) when the system needs memory, which is why
+    /// they are counted in [`System::available_memory`].
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS and iOS.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.root());
+    /// if let Some(inactive) = s.inactive_memory() {
+    ///     println!("{inactive} bytes");
     /// }
     /// ```
-    pub fn root(&self) -> Option<&Path> {
-        self.inner.root()
+    pub fn inactive_memory(&self) -> Option<u64> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "macos", target_os = "ios"),
+                not(feature = "unknown-ci")
+            ))] {
+                Some(self.inner.inactive_memory())
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the memory usage (in bytes).
+    /// Returns the amount of speculative RAM in bytes, or `None` if it's not supported.
     ///
-    /// This method returns the [size of the resident set], that is, the amount of memory that the
-    /// process allocated and which is currently mapped in physical RAM. It does not include memory
-    /// that is swapped out, or, in some operating systems, that has been allocated but never used.
+    /// Speculative pages hold data that was read ahead from disk but hasn't been used by anyone
+    /// yet. They are already accounted for in the free pages count, which is why they are
+    /// subtracted from [`System::free_memory`].
     ///
-    /// Thus, it represents exactly the amount of physical RAM that the process is using at the
-    /// present time, but it might not be a good indicator of the total memory that the process will
-    /// be using over its lifetime. For that purpose, you can try and use
-    /// [`virtual_memory`](Process::virtual_memory).
+    /// ⚠️ This method always returns `None` on other platforms than macOS and iOS.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{} bytes", process.memory());
+    /// if let Some(speculative) = s.speculative_memory() {
+    ///     println!("{speculative} bytes");
     /// }
     /// ```
-    ///
-    /// [size of the resident set]: https://en.wikipedia.org/wiki/Resident_set_size
-    pub fn memory(&self) -> u64 {
-        self.inner.memory()
+    pub fn speculative_memory(&self) -> Option<u64> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "macos", target_os = "ios"),
+                not(feature = "unknown-ci")
+            ))] {
+                Some(self.inner.speculative_memory())
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the virtual memory usage (in bytes).
+    /// Returns the amount of purgeable RAM in bytes, or `None` if it's not supported.
     ///
-    /// This method returns the [size of virtual memory], that is, the amount of memory that the
-    /// process can access, whether it is currently mapped in physical RAM or not. It includes
-    /// physical RAM, allocated but not used regions, swapped-out regions, and even memory
-    /// associated with [memory-mapped files](https://en.wikipedia.org/wiki/Memory-mapped_file).
+    /// Purgeable pages belong to memory objects that their owner marked as discardable (caches
+    /// for example), so the system can drop them without writing them anywhere when under
+    /// memory pressure.
     ///
-    /// This value has limitations though. Depending on the operating system and type of process,
-    /// this value might be a good indicator of the total memory that the process will be using over
-    /// its lifetime. However, for example, in the version 14 of MacOS this value is in the order of
-    /// the hundreds of gigabytes for every process, and thus not very informative. Moreover, if a
-    /// process maps into memory a very large file, this value will increase accordingly, even if
-    /// the process is not actively using the memory.
+    /// ⚠️ This method always returns `None` on other platforms than macOS and iOS.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{} bytes", process.virtual_memory());
+    /// if let Some(purgeable) = s.purgeable_memory() {
+    ///     println!("{purgeable} bytes");
     /// }
     /// ```
-    ///
-    /// [size of virtual memory]: https://en.wikipedia.org/wiki/Virtual_memory
-    pub fn virtual_memory(&self) -> u64 {
-        self.inner.virtual_memory()
+    pub fn purgeable_memory(&self) -> Option<u64> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "macos", target_os = "ios"),
+                not(feature = "unknown-ci")
+            ))] {
+                Some(self.inner.purgeable_memory())
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the parent PID.
+    /// Returns the SWAP size in bytes.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.parent());
-    /// }
+    /// println!("{} bytes", s.total_swap());
     /// ```
-    pub fn parent(&self) -> Option<Pid> {
-        self.inner.parent()
+    pub fn total_swap(&self) -> u64 {
+        self.inner.total_swap()
     }
 
-    /// Returns the status of the process.
+    /// Returns `true` if the system can use swap.
+    ///
+    /// On macOS, the swap files are created on demand so the swap size is `0` until memory
+    /// pressure requires it: the `vm.swap_enabled` sysctl is used instead, which doesn't require
+    /// the memory to have been refreshed. On other platforms, it returns `true` if the swap size
+    /// is more than `0`, so the swap needs to have been refreshed first (with
+    /// [`System::refresh_memory`] for example).
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.status());
+    /// if !s.is_swap_enabled() {
+    ///     println!("no swap available");
     /// }
     /// ```
-    pub fn status(&self) -> ProcessStatus {
-        self.inner.status()
+    pub fn is_swap_enabled(&self) -> bool {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "macos", target_os = "ios"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.is_swap_enabled()
+            } else {
+                self.total_swap() > 0
+            }
+        }
     }
 
-    /// Returns the time where the process was started (in seconds) from epoch.
+    /// Returns the amount of free SWAP in bytes.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Started at {} seconds", process.start_time());
-    /// }
+    /// println!("{} bytes", s.free_swap());
     /// ```
-    pub fn start_time(&self) -> u64 {
-        self.inner.start_time()
+    pub fn free_swap(&self) -> u64 {
+        self.inner.free_swap()
     }
 
-    /// Returns for how much time the process has been running (in seconds).
+    /// Returns the amount of used SWAP in bytes.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Running since {} seconds", process.run_time());
-    /// }
+    /// println!("{} bytes", s.used_swap());
     /// ```
-    pub fn run_time(&self) -> u64 {
-        self.inner.run_time()
+    pub fn used_swap(&self) -> u64 {
+        self.inner.used_swap()
     }
 
-    /// Returns the total CPU usage (in %). Notice that it might be bigger than
-    /// 100 if run on a multi-core machine.
-    ///
-    /// If you want a value between 0% and 100%, divide the returned value by
-    /// the number of CPUs.
-    ///
-    /// ⚠️ To start to have accurate CPU usage, a process needs to be refreshed
-    /// **twice** because CPU usage computation is based on time diff (process
-    /// time on a given time period divided by total system time on the same
-    /// time period).
+    /// Returns the percentage of SWAP used, computed from [`System::used_swap`] and
+    /// [`System::total_swap`].
     ///
-    /// ⚠️ If you want accurate CPU usage number, better leave a bit of time
-    /// between two calls of this method (take a look at
-    /// [`MINIMUM_CPU_UPDATE_INTERVAL`][crate::MINIMUM_CPU_UPDATE_INTERVAL] for
-    /// more information).
+    /// It returns `0.` if there is no swap (or if it wasn't refreshed yet).
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{}%", process.cpu_usage());
-    /// }
+    /// println!("{:.1}% used", s.swap_usage_percent());
     /// ```
-    pub fn cpu_usage(&self) -> f32 {
-        self.inner.cpu_usage()
+    pub fn swap_usage_percent(&self) -> f32 {
+        usage_percent(self.used_swap(), self.total_swap())
     }
 
-    /// Returns number of bytes read and written to disk.
+    /// Returns the files (or devices) used to store the SWAP, along with their size on disk.
     ///
-    /// ⚠️ On Windows, this method actually returns **ALL** I/O read and
-    /// written bytes.
+    /// The list is empty if the SWAP is disabled.
+    ///
+    /// ⚠️ This method always returns an empty list on Windows.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     let disk_usage = process.disk_usage();
-    ///     println!("read bytes   : new/total => {}/{}",
-    ///         disk_usage.read_bytes,
-    ///         disk_usage.total_read_bytes,
-    ///     );
-    ///     println!("written bytes: new/total => {}/{}",
-    ///         disk_usage.written_bytes,
-    ///         disk_usage.total_written_bytes,
-    ///     );
-    /// }
+    /// let s = System::new();
+    /// let swap_files = s.swap_files();
+    /// println!(
+    ///     "{} swap files using {} bytes",
+    ///     swap_files.len(),
+    ///     swap_files.iter().map(|f| f.size).sum::<u64>(),
+    /// );
     /// ```
-    pub fn disk_usage(&self) -> DiskUsage {
-        self.inner.disk_usage()
+    pub fn swap_files(&self) -> Vec<SwapFile> {
+        self.inner.swap_files()
     }
 
-    /// Returns the ID of the owner user of this process or `None` if this
-    /// information couldn't be retrieved. If you want to get the [`User`] from
-    /// it, take a look at [`Users::get_user_by_id`].
+    /// Retrieves the limits for the current cgroup (if any), otherwise it returns `None`.
+    ///
+    /// This information is computed every time the method is called.
+    ///
+    /// ⚠️ You need to have run [`refresh_memory`](System::refresh_memory) at least once before
+    /// calling this method.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let mut s = System::new_all();
+    /// let s = System::new_all();
+    /// println!("limits: {:?}", s.cgroup_limits());
+    /// ```
+    pub fn cgroup_limits(&self) -> Option<CGroupLimits> {
+        self.inner.cgroup_limits()
+    }
+
+    /// Returns system uptime (in seconds).
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("User id for process 1337: {:?}", process.user_id());
-    /// }
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("System running since {} seconds", System::uptime());
     /// ```
-    pub fn user_id(&self) -> Option<&Uid> {
-        self.inner.user_id()
+    pub fn uptime() -> u64 {
+        SystemInner::uptime()
     }
 
-    /// Returns the user ID of the effective owner of this process or `None` if
-    /// this information couldn't be retrieved. If you want to get the [`User`]
-    /// from it, take a look at [`Users::get_user_by_id`].
+    /// Returns the total time (in seconds) the CPUs have been idle since the system booted, or
+    /// `None` if it couldn't be retrieved.
     ///
-    /// If you run something with `sudo`, the real user ID of the launched
-    /// process will be the ID of the user you are logged in as but effective
-    /// user ID will be `0` (i-e root).
+    /// The idle time of all CPUs is summed, so it can be greater than [`System::uptime`].
     ///
-    /// ⚠️ It always returns `None` on Windows.
+    /// **Important**: this information is computed every time this function is called.
     ///
-    /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// ⚠️ This method always returns `None` on FreeBSD.
     ///
-    /// let mut s = System::new_all();
+    /// ```no_run
+    /// use sysinfo::System;
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("User id for process 1337: {:?}", process.effective_user_id());
+    /// let s = System::new();
+    /// if let Some(idle_time) = s.idle_time() {
+    ///     println!("CPUs idle for {idle_time} seconds since boot");
     /// }
     /// ```
-    pub fn effective_user_id(&self) -> Option<&Uid> {
-        self.inner.effective_user_id()
+    pub fn idle_time(&self) -> Option<u64> {
+        self.inner.idle_time()
     }
 
-    /// Returns the process group ID of the process.
+    /// Returns the time (in seconds) when the system booted since UNIX epoch.
     ///
-    /// ⚠️ It always returns `None` on Windows.
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
-    ///
-    /// let mut s = System::new_all();
+    /// use sysinfo::System;
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Group ID for process 1337: {:?}", process.group_id());
-    /// }
+    /// println!("System booted at {} seconds", System::boot_time());
     /// ```
-    pub fn group_id(&self) -> Option<Gid> {
-        self.inner.group_id()
+    pub fn boot_time() -> u64 {
+        SystemInner::boot_time()
     }
 
-    /// Returns the effective group ID of the process.
+    /// Returns the system load average value.
     ///
-    /// If you run something with `sudo`, the real group ID of the launched
-    /// process will be the primary group ID you are logged in as but effective
-    /// group ID will be `0` (i-e root).
+    /// **Important**: this information is computed every time this function is called.
     ///
-    /// ⚠️ It always returns `None` on Windows.
+    /// ⚠️ This is currently not working on **Windows**.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let mut s = System::new_all();
+    /// let load_avg = System::load_average();
+    /// println!(
+    ///     "one minute: {}%, five minutes: {}%, fifteen minutes: {}%",
+    ///     load_avg.one,
+    ///     load_avg.five,
+    ///     load_avg.fifteen,
+    /// );
+    /// ```
+    pub fn load_average() -> LoadAvg {
+        SystemInner::load_average()
+    }
+
+    /// Returns the thermal pressure of the system, which indicates whether the system is
+    /// throttling its performance to cool down. Returns `None` if it can't be retrieved.
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("User id for process 1337: {:?}", process.effective_group_id());
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS and iOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, ThermalPressure};
+    ///
+    /// if System::thermal_pressure() >= Some(ThermalPressure::Serious) {
+    ///     println!("the system is throttling, let's slow down");
     /// }
     /// ```
-    pub fn effective_group_id(&self) -> Option<Gid> {
-        self.inner.effective_group_id()
+    pub fn thermal_pressure() -> Option<ThermalPressure> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "macos", target_os = "ios"),
+                not(feature = "unknown-ci")
+            ))] {
+                SystemInner::thermal_pressure()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Wait for process termination.
+    /// Returns the maximum speed the CPU is currently allowed to run at, as a percentage of its
+    /// nominal speed (`100` meaning it isn't throttled). Returns `None` if it can't be retrieved.
     ///
-    /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// It is lowered by the system to cool down the CPU, so long-running jobs can use it to
+    /// detect thermal throttling. It is the `CPU_Speed_Limit` reported by `pmset -g therm`.
     ///
-    /// let mut s = System::new_all();
+    /// **Important**: this information is computed every time this function is called.
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Waiting for pid 1337");
-    ///     process.wait();
-    ///     println!("Pid 1337 exited");
+    /// ⚠️ This method always returns `None` on other platforms than macOS, where it is only
+    /// available on Intel Macs. On Apple Silicon, use [`System::thermal_pressure`] instead.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(limit) = System::cpu_speed_limit() {
+    ///     if limit < 100 {
+    ///         println!("the CPU is throttled to {limit}% of its speed");
+    ///     }
     /// }
     /// ```
-    pub fn wait(&self) {
-        self.inner.wait()
+    pub fn cpu_speed_limit() -> Option<u32> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                SystemInner::cpu_speed_limit()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the session ID for the current process or `None` if it couldn't
-    /// be retrieved.
+    /// Calls `callback` every time the system signals a change of its memory pressure, instead of
+    /// having to poll the memory information. Returns `None` if the notifications couldn't be
+    /// set up.
     ///
-    /// ⚠️ This information is computed every time this method is called.
+    /// `callback` is called from a thread owned by the system (a global dispatch queue on
+    /// Apple systems), so it must not block for long. The notifications stop when the returned
+    /// [`MemoryPressureSubscription`] is dropped.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS and iOS.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::{MemoryPressure, System};
     ///
-    /// let mut s = System::new_all();
+    /// let _subscription = System::subscribe_memory_pressure(|pressure| {
+    ///     if pressure >= MemoryPressure::Warn {
+    ///         println!("the system is running low on memory");
+    ///     }
+    /// });
+    /// ```
+    pub fn subscribe_memory_pressure<F>(callback: F) -> Option<MemoryPressureSubscription>
+    where
+        F: Fn(MemoryPressure) + Send + Sync + 'static,
+    {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "macos", target_os = "ios"),
+                not(feature = "unknown-ci")
+            ))] {
+                SystemInner::subscribe_memory_pressure(callback)
+                    .map(|inner| MemoryPressureSubscription { inner })
+            } else {
+                let _callback = callback;
+                None
+            }
+        }
+    }
+
+    /// Returns the GPU usage (in %), or `None` if no GPU was found.
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Session ID for process 1337: {:?}", process.session_id());
+    /// If there are multiple GPUs, the usage of the first one reporting it is returned.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(usage) = System::gpu_usage() {
+    ///     println!("GPU usage: {usage}%");
     /// }
     /// ```
-    pub fn session_id(&self) -> Option<Pid> {
-        self.inner.session_id()
+    pub fn gpu_usage() -> Option<f32> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                SystemInner::gpu_usage()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Tasks run by this process. If there are none, returns `None`.
+    /// Returns the source the system is currently drawing its power from.
     ///
-    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    /// Systems without battery (like most desktops) are considered as being on AC power.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ On iOS, with the `apple-sandbox` feature and on unsupported systems, this method always
+    /// returns [`PowerSource::Unknown`].
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::{PowerSource, System};
     ///
-    /// let mut s = System::new_all();
+    /// if System::power_source() == PowerSource::Battery {
+    ///     println!("running on battery, let's reduce the background work");
+    /// }
+    /// ```
+    pub fn power_source() -> PowerSource {
+        SystemInner::power_source()
+    }
+
+    /// Returns `true` if the Low Power Mode is enabled for the current power source, or `None`
+    /// if this setting isn't available (like on older macOS versions).
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     if let Some(tasks) = process.tasks() {
-    ///         println!("Listing tasks for process {:?}", process.pid());
-    ///         for task_pid in tasks {
-    ///             if let Some(task) = s.process(*task_pid) {
-    ///                 println!("Task {:?}: {:?}", task.pid(), task.name());
-    ///             }
-    ///         }
-    ///     }
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if System::is_low_power_mode_enabled() == Some(true) {
+    ///     println!("Low Power Mode is enabled, let's reduce the background work");
     /// }
     /// ```
-    pub fn tasks(&self) -> Option<&HashSet<Pid>> {
+    pub fn is_low_power_mode_enabled() -> Option<bool> {
         cfg_if::cfg_if! {
             if #[cfg(all(
-                any(target_os = "linux", target_os = "android"),
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
                 not(feature = "unknown-ci")
             ))] {
-                self.inner.tasks.as_ref()
+                SystemInner::is_low_power_mode_enabled()
             } else {
                 None
             }
         }
     }
 
-    /// If the process is a thread, it'll return `Some` with the kind of thread it is. Returns
-    /// `None` otherwise.
+    /// Returns the instantaneous power consumption of the whole system, in watts, as reported by
+    /// the SMC. Returns `None` if it isn't available on this model.
     ///
-    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// let s = System::new_all();
-    ///
-    /// for (_, process) in s.processes() {
-    ///     if let Some(thread_kind) = process.thread_kind() {
-    ///         println!("Process {:?} is a {thread_kind:?} thread", process.pid());
-    ///     }
+    /// if let Some(power) = System::power_consumption() {
+    ///     println!("The system is drawing {power:.1} W");
     /// }
     /// ```
-    pub fn thread_kind(&self) -> Option<ThreadKind> {
+    pub fn power_consumption() -> Option<f32> {
         cfg_if::cfg_if! {
             if #[cfg(all(
-                any(target_os = "linux", target_os = "android"),
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
                 not(feature = "unknown-ci")
             ))] {
-                self.inner.thread_kind()
+                SystemInner::power_consumption()
             } else {
                 None
             }
         }
     }
-}
 
-macro_rules! pid_decl {
-    ($typ:ty) => {
-        #[doc = include_str!("../md_doc/pid.md")]
-        #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-        #[repr(transparent)]
-        pub struct Pid(pub(crate) $typ);
+    /// Returns the number of errors detected by the ECC memory controller since boot. Returns
+    /// `None` if the system doesn't have ECC memory (or doesn't report its errors).
+    ///
+    /// A rising number of correctable errors usually means a memory module is about to fail.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ None of the supported platforms documents a way to retrieve these counters yet, so this
+    /// method currently always returns `None`.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(errors) = System::memory_ecc_errors() {
+    ///     println!(
+    ///         "correctable: {}, uncorrectable: {}",
+    ///         errors.correctable, errors.uncorrectable,
+    ///     );
+    /// }
+    /// ```
+    pub fn memory_ecc_errors() -> Option<MemoryEccErrors> {
+        None
+    }
 
-        impl From<usize> for Pid {
-            fn from(v: usize) -> Self {
-                Self(v as _)
-            }
-        }
-        impl From<Pid> for usize {
-            fn from(v: Pid) -> Self {
-                v.0 as _
+    /// Returns `true` if the System Integrity Protection (SIP) is enabled, or `None` if it
+    /// couldn't be retrieved.
+    ///
+    /// SIP can be partially disabled: it is considered enabled as long as the file system
+    /// protection is active.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if System::is_sip_enabled() == Some(false) {
+    ///     println!("System Integrity Protection is disabled!");
+    /// }
+    /// ```
+    pub fn is_sip_enabled() -> Option<bool> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                SystemInner::is_sip_enabled()
+            } else {
+                None
             }
         }
-        impl FromStr for Pid {
-            type Err = <$typ as FromStr>::Err;
-            fn from_str(s: &str) -> Result<Self, Self::Err> {
-                Ok(Self(<$typ>::from_str(s)?))
-            }
-        }
-        impl fmt::Display for Pid {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(f, "{}", self.0)
-            }
-        }
-        impl Pid {
-            /// Allows to convert [`Pid`][crate::Pid] into [`u32`].
-            ///
-            /// ```
-            /// use sysinfo::Pid;
-            ///
-            /// let pid = Pid::from_u32(0);
-            /// let value: u32 = pid.as_u32();
-            /// ```
-            pub fn as_u32(self) -> u32 {
-                self.0 as _
-            }
-            /// Allows to convert a [`u32`] into [`Pid`][crate::Pid].
-            ///
-            /// ```
-            /// use sysinfo::Pid;
-            ///
-            /// let pid = Pid::from_u32(0);
-            /// ```
-            pub fn from_u32(v: u32) -> Self {
-                Self(v as _)
-            }
-        }
-    };
-}
-
-cfg_if::cfg_if! {
-    if #[cfg(all(
-        not(feature = "unknown-ci"),
-        any(
-            target_os = "freebsd",
-            target_os = "linux",
-            target_os = "android",
-            target_os = "macos",
-            target_os = "ios",
-        )
-    ))] {
-        use libc::pid_t;
+    }
 
-        pid_decl!(pid_t);
-    } else {
-        pid_decl!(usize);
+    /// Returns the system name.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("OS: {:?}", System::name());
+    /// ```
+    pub fn name() -> Option<String> {
+        SystemInner::name()
     }
-}
 
-macro_rules! impl_get_set {
-    ($ty_name:ident, $name:ident, $with:ident, $without:ident $(, $extra_doc:literal)? $(,)?) => {
-        #[doc = concat!("Returns the value of the \"", stringify!($name), "\" refresh kind.")]
-        $(#[doc = concat!("
-", $extra_doc, "
-")])?
-        #[doc = concat!("
-```
-use sysinfo::", stringify!($ty_name), ";
+    /// Returns the system's kernel version.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("kernel version: {:?}", System::kernel_version());
+    /// ```
+    pub fn kernel_version() -> Option<String> {
+        SystemInner::kernel_version()
+    }
 
-let r = ", stringify!($ty_name), "::new();
-assert_eq!(r.", stringify!($name), "(), false);
+    /// Returns the system version (e.g. for MacOS this will return 11.1 rather than the kernel
+    /// version).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("OS version: {:?}", System::os_version());
+    /// ```
+    pub fn os_version() -> Option<String> {
+        SystemInner::os_version()
+    }
 
-let r = r.with_", stringify!($name), "();
-assert_eq!(r.", stringify!($name), "(), true);
+    /// Returns the system long os version (e.g "MacOS 11.2 BigSur").
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Long OS Version: {:?}", System::long_os_version());
+    /// ```
+    pub fn long_os_version() -> Option<String> {
+        SystemInner::long_os_version()
+    }
 
-let r = r.without_", stringify!($name), "();
-assert_eq!(r.", stringify!($name), "(), false);
-```")]
-        pub fn $name(&self) -> bool {
-            self.$name
+    /// Returns a descriptor combining the product name, the product version and the kernel
+    /// release, suitable for user-agent style strings (e.g. "macOS 13.4 (Darwin 22.5.0)").
+    ///
+    /// Missing parts are omitted. `None` is only returned if none of them could be retrieved.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("OS: {:?}", System::os_descriptor());
+    /// ```
+    pub fn os_descriptor() -> Option<String> {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "unknown-ci")] {
+                let (product_name, kernel_name) = (Self::name(), "");
+            } else if #[cfg(target_os = "macos")] {
+                let (product_name, kernel_name) = (Some("macOS".to_owned()), "Darwin");
+            } else if #[cfg(target_os = "ios")] {
+                let (product_name, kernel_name) = (Some("iOS".to_owned()), "Darwin");
+            } else if #[cfg(any(target_os = "linux", target_os = "android"))] {
+                let (product_name, kernel_name) = (Self::name(), "Linux");
+            } else if #[cfg(windows)] {
+                let (product_name, kernel_name) = (Self::name(), "NT");
+            } else {
+                let (product_name, kernel_name) = (Self::name(), "");
+            }
+        }
+        let product = match (product_name, Self::os_version()) {
+            (Some(name), Some(version)) => Some(format!("{name} {version}")),
+            (name, version) => name.or(version),
+        };
+        let kernel = Self::kernel_version().map(|version| {
+            if kernel_name.is_empty() {
+                version
+            } else {
+                format!("{kernel_name} {version}")
+            }
+        });
+        match (product, kernel) {
+            (Some(product), Some(kernel)) => Some(format!("{product} ({kernel})")),
+            (product, kernel) => product.or(kernel),
         }
+    }
 
-        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `true`.
+    /// Returns the distribution id as defined by os-release,
+    /// or [`std::env::consts::OS`].
+    ///
+    /// See also
+    /// - <https://www.freedesktop.org/software/systemd/man/os-release.html#ID=>
+    /// - <https://doc.rust-lang.org/std/env/consts/constant.OS.html>
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Distribution ID: {:?}", System::distribution_id());
+    /// ```
+    pub fn distribution_id() -> String {
+        SystemInner::distribution_id()
+    }
 
-```
-use sysinfo::", stringify!($ty_name), ";
+    /// Returns the system hostname based off DNS.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Hostname: {:?}", System::host_name());
+    /// ```
+    pub fn host_name() -> Option<String> {
+        SystemInner::host_name()
+    }
 
-let r = ", stringify!($ty_name), "::new();
-assert_eq!(r.", stringify!($name), "(), false);
+    /// Returns the CPU architecture (eg. x86, amd64, aarch64, ...).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("CPU Archicture: {:?}", System::cpu_arch());
+    /// ```
+    pub fn cpu_arch() -> Option<String> {
+        SystemInner::cpu_arch()
+    }
 
-let r = r.with_", stringify!($name), "();
-assert_eq!(r.", stringify!($name), "(), true);
-```")]
-        #[must_use]
-        pub fn $with(mut self) -> Self {
-            self.$name = true;
-            self
+    /// Returns the model identifier of the machine (like `MacBookPro18,2` on macOS), as
+    /// provided by the firmware.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Hardware model: {:?}", System::hardware_model());
+    /// ```
+    pub fn hardware_model() -> Option<String> {
+        SystemInner::hardware_model()
+    }
+
+    /// Returns the hardware UUID of the machine (`IOPlatformUUID`), or `None` if it couldn't be
+    /// retrieved.
+    ///
+    /// It identifies the machine without exposing its serial number.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Hardware UUID: {:?}", System::hardware_uuid());
+    /// ```
+    pub fn hardware_uuid() -> Option<String> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                SystemInner::hardware_uuid()
+            } else {
+                None
+            }
         }
+    }
+}
 
-        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `false`.
+// This is the only place where the processes code of the backends is called, so that it can be
+// removed without the `processes` feature.
+#[cfg(feature = "processes")]
+impl System {
+    fn refresh_processes_inner(
+        &mut self,
+        pids: Option<&[Pid]>,
+        uid: Option<&Uid>,
+        refresh_kind: ProcessRefreshKind,
+        exited: Option<&mut Vec<Process>>,
+    ) {
+        self.inner
+            .refresh_processes_specifics(pids, uid, refresh_kind, exited);
+        self.update_last_seen(pids, uid);
+    }
 
-```
-use sysinfo::", stringify!($ty_name), ";
+    fn refresh_process_inner(&mut self, pid: Pid, refresh_kind: ProcessRefreshKind) -> bool {
+        let refreshed = self.inner.refresh_process_specifics(pid, refresh_kind);
+        if refreshed {
+            self.update_last_seen(Some(&[pid]), None);
+        }
+        refreshed
+    }
 
-let r = ", stringify!($ty_name), "::everything();
-assert_eq!(r.", stringify!($name), "(), true);
+    fn list_pids_inner(&self) -> Vec<Pid> {
+        self.inner.list_pids()
+    }
 
-let r = r.without_", stringify!($name), "();
-assert_eq!(r.", stringify!($name), "(), false);
-```")]
-        #[must_use]
-        pub fn $without(mut self) -> Self {
-            self.$name = false;
-            self
+    /// Marks the processes which were just refreshed as seen now.
+    fn update_last_seen(&mut self, pids: Option<&[Pid]>, uid: Option<&Uid>) {
+        let now = Instant::now();
+        let processes = self.inner.processes_mut();
+        match pids {
+            Some(pids) => {
+                for pid in pids {
+                    if let Some(process) = processes.get_mut(pid) {
+                        process.last_seen = now;
+                    }
+                }
+            }
+            None => {
+                for process in processes.values_mut() {
+                    if uid.map_or(true, |uid| process.user_id() == Some(uid)) {
+                        process.last_seen = now;
+                    }
+                }
+            }
         }
-    };
+    }
+}
 
-    // To handle `UpdateKind`.
-    ($ty_name:ident, $name:ident, $with:ident, $without:ident, UpdateKind $(, $extra_doc:literal)? $(,)?) => {
-        #[doc = concat!("Returns the value of the \"", stringify!($name), "\" refresh kind.")]
-        $(#[doc = concat!("
-", $extra_doc, "
-")])?
-        #[doc = concat!("
-```
-use sysinfo::{", stringify!($ty_name), ", UpdateKind};
+#[cfg(not(feature = "processes"))]
+impl System {
+    fn refresh_processes_inner(
+        &mut self,
+        _pids: Option<&[Pid]>,
+        _uid: Option<&Uid>,
+        _refresh_kind: ProcessRefreshKind,
+        _exited: Option<&mut Vec<Process>>,
+    ) {
+    }
 
-let r = ", stringify!($ty_name), "::new();
-assert_eq!(r.", stringify!($name), "(), UpdateKind::Never);
+    fn refresh_process_inner(&mut self, _pid: Pid, _refresh_kind: ProcessRefreshKind) -> bool {
+        false
+    }
 
-let r = r.with_", stringify!($name), "(UpdateKind::OnlyIfNotSet);
-assert_eq!(r.", stringify!($name), "(), UpdateKind::OnlyIfNotSet);
+    fn list_pids_inner(&self) -> Vec<Pid> {
+        Vec::new()
+    }
+}
+
+/// Struct containing information of a process.
+///
+/// ## iOS
+///
+/// This information cannot be retrieved on iOS due to sandboxing.
+///
+/// ## Apple app store
+///
+/// If you are building a macOS Apple app store, it won't be able
+/// to retrieve this information.
+///
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     println!("{:?}", process.name());
+/// }
+/// ```
+///
+/// Cloning a `Process` gives you a snapshot of its information at the time of the last refresh:
+/// the clone is never updated by later refreshes of the [`System`].
+#[derive(Clone)]
+pub struct Process {
+    pub(crate) inner: ProcessInner,
+    first_seen: Instant,
+    last_seen: Instant,
+}
+
+impl Process {
+    #[cfg(feature = "processes")]
+    #[cfg_attr(
+        any(
+            feature = "unknown-ci",
+            not(any(
+                target_os = "macos",
+                target_os = "linux",
+                target_os = "android",
+                target_os = "freebsd",
+                windows,
+            )),
+            all(target_os = "macos", feature = "apple-sandbox"),
+        ),
+        allow(dead_code)
+    )]
+    pub(crate) fn new(inner: ProcessInner) -> Self {
+        let now = Instant::now();
+        Self {
+            inner,
+            first_seen: now,
+            last_seen: now,
+        }
+    }
+
+    /// Sends [`Signal::Kill`] to the process (which is the only signal supported on all supported
+    /// platforms by this crate).
+    ///
+    /// If you want to send another signal, take a look at [`Process::kill_with`].
+    ///
+    /// To get the list of the supported signals on this system, use
+    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     process.kill();
+    /// }
+    /// ```
+    pub fn kill(&self) -> bool {
+        self.kill_with(Signal::Kill).unwrap_or(false)
+    }
+
+    /// Sends the given `signal` to the process. If the signal doesn't exist on this platform,
+    /// it'll do nothing and will return `None`. Otherwise it'll return if the signal was sent
+    /// successfully.
+    ///
+    /// If you just want to kill the process, use [`Process::kill`] directly.
+    ///
+    /// To get the list of the supported signals on this system, use
+    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, Signal, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if process.kill_with(Signal::Kill).is_none() {
+    ///         println!("This signal isn't supported on this platform");
+    ///     }
+    /// }
+    /// ```
+    pub fn kill_with(&self, signal: Signal) -> Option<bool> {
+        self.inner.kill_with(signal)
+    }
+
+    /// Returns the name of the process.
+    ///
+    /// **⚠️ Important ⚠️**
+    ///
+    /// On **Linux**, there are two things to know about processes' name:
+    ///  1. It is limited to 15 characters.
+    ///  2. It is not always the exe name.
+    ///
+    /// If you are looking for a specific process, unless you know what you are
+    /// doing, in most cases it's better to use [`Process::exe`] instead (which
+    /// can be empty sometimes!).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> &OsStr {
+        self.inner.name()
+    }
+
+    /// Returns the command line.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.cmd());
+    /// }
+    /// ```
+    pub fn cmd(&self) -> &[OsString] {
+        self.inner.cmd()
+    }
+
+    /// Returns the command line as a single string: the arguments from [`Process::cmd`] joined
+    /// with spaces.
+    ///
+    /// The arguments which contain whitespace or special characters are quoted (following the
+    /// shell rules on Unix and the `CommandLineToArgvW` ones on Windows) so the arguments can be
+    /// retrieved from it. If the command line is empty, [`Process::exe`] is returned instead.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.command_line().to_string_lossy());
+    /// }
+    /// ```
+    pub fn command_line(&self) -> OsString {
+        match self.cmd() {
+            [] => self
+                .exe()
+                .map(|exe| exe.as_os_str().to_owned())
+                .unwrap_or_default(),
+            cmd => crate::utils::join_command_line(cmd),
+        }
+    }
+
+    /// Same as [`Process::command_line`] but truncated to at most `max_bytes` bytes, which
+    /// avoids dumping huge command lines in logs.
+    ///
+    /// When the command line is truncated, it ends with an ellipsis (`…`). The truncation never
+    /// splits a UTF-8 character and the invalid UTF-8 sequences are replaced with
+    /// [`U+FFFD REPLACEMENT CHARACTER`](char::REPLACEMENT_CHARACTER).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.command_line_truncated(200));
+    /// }
+    /// ```
+    pub fn command_line_truncated(&self, max_bytes: usize) -> String {
+        let command_line = match self.cmd() {
+            [] => self.command_line(),
+            cmd => {
+                // Quoting only makes the arguments longer, so there is no need to join the ones
+                // which start after `max_bytes`. One more byte is kept so the result is longer
+                // than `max_bytes` (and gets the ellipsis) if some arguments were skipped.
+                let mut len = 0;
+                let count = cmd
+                    .iter()
+                    .take_while(|arg| {
+                        let needed = len <= max_bytes.saturating_add(1);
+                        len += arg.len() + 1;
+                        needed
+                    })
+                    .count();
+                crate::utils::join_command_line(&cmd[..count])
+            }
+        };
+        crate::utils::truncate_with_ellipsis(command_line.to_string_lossy().into_owned(), max_bytes)
+    }
+
+    /// Returns the path to the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.exe());
+    /// }
+    /// ```
+    ///
+    /// ### Implementation notes
+    ///
+    /// On Linux, this method will return an empty path if there
+    /// was an error trying to read `/proc/<pid>/exe`. This can
+    /// happen, for example, if the permission levels or UID namespaces
+    /// between the caller and target processes are different.
+    ///
+    /// It is also the case that `cmd[0]` is _not_ usually a correct
+    /// replacement for this.
+    /// A process [may change its `cmd[0]` value](https://man7.org/linux/man-pages/man5/proc.5.html)
+    /// freely, making this an untrustworthy source of information.
+    pub fn exe(&self) -> Option<&Path> {
+        self.inner.exe()
+    }
+
+    /// Returns the path to the process with all the symbolic links resolved, or `None` if
+    /// [`Process::exe`] returned `None` or if the path couldn't be resolved (if the binary was
+    /// removed for example).
+    ///
+    /// It allows to get the real binary when the process was started through a symbolic link
+    /// (like `/usr/bin/python3` which usually points to a versioned binary).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?} -> {:?}", process.exe(), process.exe_resolved());
+    /// }
+    /// ```
+    pub fn exe_resolved(&self) -> Option<PathBuf> {
+        std::fs::canonicalize(self.exe()?).ok()
+    }
+
+    /// Returns the PID of the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.pid());
+    /// }
+    /// ```
+    pub fn pid(&self) -> Pid {
+        self.inner.pid()
+    }
+
+    /// Returns the environment variables of the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.environ());
+    /// }
+    /// ```
+    pub fn environ(&self) -> &[OsString] {
+        self.inner.environ()
+    }
+
+    /// Returns the current working directory.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.cwd());
+    /// }
+    /// ```
+    pub fn cwd(&self) -> Option<&Path> {
+        self.inner.cwd()
+    }
+
+    /// Returns the path of the root directory.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.root());
+    /// }
+    /// ```
+    pub fn root(&self) -> Option<&Path> {
+        self.inner.root()
+    }
+
+    /// Returns the memory usage (in bytes).
+    ///
+    /// This method returns the [size of the resident set], that is, the amount of memory that the
+    /// process allocated and which is currently mapped in physical RAM. It does not include memory
+    /// that is swapped out, or, in some operating systems, that has been allocated but never used.
+    ///
+    /// Thus, it represents exactly the amount of physical RAM that the process is using at the
+    /// present time, but it might not be a good indicator of the total memory that the process will
+    /// be using over its lifetime. For that purpose, you can try and use
+    /// [`virtual_memory`](Process::virtual_memory).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{} bytes", process.memory());
+    /// }
+    /// ```
+    ///
+    /// [size of the resident set]: https://en.wikipedia.org/wiki/Resident_set_size
+    pub fn memory(&self) -> u64 {
+        self.inner.memory()
+    }
+
+    /// Returns the virtual memory usage (in bytes).
+    ///
+    /// This method returns the [size of virtual memory], that is, the amount of memory that the
+    /// process can access, whether it is currently mapped in physical RAM or not. It includes
+    /// physical RAM, allocated but not used regions, swapped-out regions, and even memory
+    /// associated with [memory-mapped files](https://en.wikipedia.org/wiki/Memory-mapped_file).
+    ///
+    /// This value has limitations though. Depending on the operating system and type of process,
+    /// this value might be a good indicator of the total memory that the process will be using over
+    /// its lifetime. However, for example, in the version 14 of MacOS this value is in the order of
+    /// the hundreds of gigabytes for every process, and thus not very informative. Moreover, if a
+    /// process maps into memory a very large file, this value will increase accordingly, even if
+    /// the process is not actively using the memory.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{} bytes", process.virtual_memory());
+    /// }
+    /// ```
+    ///
+    /// [size of virtual memory]: https://en.wikipedia.org/wiki/Virtual_memory
+    pub fn virtual_memory(&self) -> u64 {
+        self.inner.virtual_memory()
+    }
+
+    /// Returns the amount of memory of this process which is currently swapped out (in bytes), or
+    /// `0` if it couldn't be retrieved.
+    ///
+    /// It is only updated when [`ProcessRefreshKind::memory_regions`] is enabled. On macOS, it is
+    /// the memory held by the compressor (which pages out to the swap files). On Linux, it is read
+    /// from `/proc/[pid]/status`.
+    ///
+    /// ⚠️ This method always returns `0` on other platforms than Linux and macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{} bytes swapped out", process.swapped_memory());
+    /// }
+    /// ```
+    pub fn swapped_memory(&self) -> u64 {
+        self.inner.swapped_memory()
+    }
+
+    /// Returns the breakdown of the resident memory of this process (in bytes), or `None` if it
+    /// couldn't be retrieved.
+    ///
+    /// Getting this information requires the rights to access the process' task, which is
+    /// usually only the case for root. It is only retrieved if the "memory regions" refresh kind
+    /// is enabled (see [`ProcessRefreshKind::with_memory_regions`]).
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(regions) = process.memory_regions() {
+    ///         println!("{} shared bytes", regions.shared);
+    ///     }
+    /// }
+    /// ```
+    pub fn memory_regions(&self) -> Option<MemoryRegions> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.memory_regions()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the number of virtual memory regions mapped by this process, or `None` if it
+    /// couldn't be retrieved.
+    ///
+    /// Like [`Process::memory_regions`], it requires the rights to access the process' task and
+    /// is only retrieved with [`ProcessRefreshKind::with_memory_regions`].
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?} memory regions", process.memory_region_count());
+    /// }
+    /// ```
+    pub fn memory_region_count(&self) -> Option<u32> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.memory_region_count()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the number of Mach ports used by this process, or `None` if it couldn't be
+    /// retrieved. A number which keeps growing usually means the process is leaking ports.
+    ///
+    /// Like [`Process::memory_regions`], it requires the rights to access the process' task and
+    /// is only retrieved with [`ProcessRefreshKind::with_memory_regions`].
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?} Mach ports", process.mach_port_count());
+    /// }
+    /// ```
+    pub fn mach_port_count(&self) -> Option<u32> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.mach_port_count()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the disk I/O policy of this process, or `None` if it couldn't be retrieved.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS. On macOS, only the
+    /// I/O policy of the current process can be queried, so it always returns `None` for the
+    /// other processes.
+    ///
+    /// ```no_run
+    /// use sysinfo::{get_current_pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(get_current_pid().unwrap()) {
+    ///     println!("{:?}", process.io_policy());
+    /// }
+    /// ```
+    pub fn io_policy(&self) -> Option<IoPolicy> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.io_policy()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the parent PID.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.parent());
+    /// }
+    /// ```
+    pub fn parent(&self) -> Option<Pid> {
+        self.inner.parent()
+    }
+
+    /// Returns the status of the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.status());
+    /// }
+    /// ```
+    pub fn status(&self) -> ProcessStatus {
+        self.inner.status()
+    }
+
+    /// Returns the time where the process was started (in seconds) from epoch.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Started at {} seconds", process.start_time());
+    /// }
+    /// ```
+    pub fn start_time(&self) -> u64 {
+        self.inner.start_time()
+    }
+
+    /// Returns when the process was added to the process list of the [`System`].
+    ///
+    /// Contrary to [`Process::start_time`], it has the precision of an [`Instant`], so it can be
+    /// used with [`Process::last_seen`] to know for how long a short-lived process was observed.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Seen for the first time {:?} ago", process.first_seen().elapsed());
+    /// }
+    /// ```
+    pub fn first_seen(&self) -> Instant {
+        self.first_seen
+    }
+
+    /// Returns when the process was last seen running, meaning the last time it was refreshed.
+    ///
+    /// For the processes which exited (returned by [`System::refresh_processes_diff`]), it is
+    /// the last refresh before they exited.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new_all();
+    /// // We wait some time...?
+    /// for process in s.refresh_processes_diff().exited {
+    ///     println!(
+    ///         "{:?} was seen running for at least {:?}",
+    ///         process.name(),
+    ///         process.last_seen() - process.first_seen(),
+    ///     );
+    /// }
+    /// ```
+    pub fn last_seen(&self) -> Instant {
+        self.last_seen
+    }
+
+    /// Returns for how much time the process has been running (in seconds).
+    ///
+    /// It is computed when the process is refreshed, so it doesn't increase until the process is
+    /// refreshed again. It is derived from the same clock as [`System::uptime`]: it is computed
+    /// as `uptime - (start_time - boot_time)` (see [`Process::start_time`] and
+    /// [`System::boot_time`]).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Running since {} seconds", process.run_time());
+    /// }
+    /// ```
+    pub fn run_time(&self) -> u64 {
+        self.inner.run_time()
+    }
+
+    /// Returns the CPU time (user and system) used by the process since it started.
+    ///
+    /// ⚠️ It is only updated when the CPU usage is refreshed.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Used the CPU for {:?}", process.accumulated_cpu_time());
+    /// }
+    /// ```
+    pub fn accumulated_cpu_time(&self) -> Duration {
+        Duration::from_millis(self.inner.accumulated_cpu_time())
+    }
+
+    /// Returns the average CPU usage (in %) of the process over its whole lifetime, computed as
+    /// the CPU time it used divided by its [`run_time`][Process::run_time].
+    ///
+    /// Like [`cpu_usage`][Process::cpu_usage], it can go over 100% if the process uses more than
+    /// one CPU core. It returns `0.` if the process has been running for less than a second.
+    ///
+    /// ⚠️ The CPU time is only updated when the CPU usage is refreshed.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}% on average", process.average_cpu_usage());
+    /// }
+    /// ```
+    pub fn average_cpu_usage(&self) -> f32 {
+        let run_time = self.inner.run_time();
+        if run_time == 0 {
+            return 0.;
+        }
+        // The CPU time is in milliseconds whereas the run time is in seconds.
+        self.inner.accumulated_cpu_time() as f32 / (run_time as f32 * 10.)
+    }
+
+    /// Returns the total CPU usage (in %). Notice that it might be bigger than
+    /// 100 if run on a multi-core machine.
+    ///
+    /// If you want a value between 0% and 100%, divide the returned value by
+    /// the number of CPUs.
+    ///
+    /// ⚠️ To start to have accurate CPU usage, a process needs to be refreshed
+    /// **twice** because CPU usage computation is based on time diff (process
+    /// time on a given time period divided by total system time on the same
+    /// time period).
+    ///
+    /// ⚠️ If you want accurate CPU usage number, better leave a bit of time
+    /// between two calls of this method (take a look at
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`][crate::MINIMUM_CPU_UPDATE_INTERVAL] for
+    /// more information).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}%", process.cpu_usage());
+    /// }
+    /// ```
+    pub fn cpu_usage(&self) -> f32 {
+        self.inner.cpu_usage()
+    }
+
+    /// Returns number of bytes read and written to disk.
+    ///
+    /// The `total_*` fields are the cumulative values since the process was started (on macOS,
+    /// they come from the `ri_diskio_*` fields of `proc_pid_rusage`), so they can be used to
+    /// compare processes without keeping track of the previous refreshes. The other fields only
+    /// contain what was read and written since the last refresh.
+    ///
+    /// ⚠️ On Windows, this method actually returns **ALL** I/O read and
+    /// written bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     let disk_usage = process.disk_usage();
+    ///     println!("read bytes   : new/total => {}/{}",
+    ///         disk_usage.read_bytes,
+    ///         disk_usage.total_read_bytes,
+    ///     );
+    ///     println!("written bytes: new/total => {}/{}",
+    ///         disk_usage.written_bytes,
+    ///         disk_usage.total_written_bytes,
+    ///     );
+    /// }
+    /// ```
+    pub fn disk_usage(&self) -> DiskUsage {
+        self.inner.disk_usage()
+    }
+
+    /// Returns the number of page faults of the process, or `None` if it wasn't retrieved.
+    ///
+    /// A high rate of major page faults (the ones requiring to read from the disk) indicates
+    /// that the process is suffering from memory pressure.
+    ///
+    /// They are updated when the processes are refreshed with `memory` enabled. Like for
+    /// [`Process::disk_usage`], the first refresh considers all the page faults since the
+    /// process started as new ones.
+    ///
+    /// ⚠️ Windows doesn't tell apart the major page faults, so they're all counted as minor
+    /// ones and the major ones are always `0`.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(faults) = process.page_faults() {
+    ///         println!("{} major page faults since last refresh", faults.major);
+    ///     }
+    /// }
+    /// ```
+    pub fn page_faults(&self) -> Option<PageFaults> {
+        self.inner.page_faults()
+    }
+
+    /// Returns the number of times the process woke up the CPU, or `None` if it wasn't
+    /// retrieved.
+    ///
+    /// Wakeups prevent the CPU from staying in its low power states, so along with the CPU usage,
+    /// they are the main contributors to the "Energy Impact" displayed by the Activity Monitor.
+    /// How they should be weighted is up to the caller.
+    ///
+    /// They are updated when the processes are refreshed with `cpu` enabled.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(wakeups) = process.wakeups() {
+    ///         println!("{} idle wakeups since last refresh", wakeups.idle);
+    ///     }
+    /// }
+    /// ```
+    pub fn wakeups(&self) -> Option<Wakeups> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.wakeups()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the total number of pages the process read from disk (its executable, mapped
+    /// files or swap) since it started.
+    ///
+    /// It is a cumulative counter, so comparing it between processes shows which one pages in the
+    /// most. It is updated when the processes are refreshed with `memory` enabled and is `0` if
+    /// it couldn't be retrieved.
+    ///
+    /// ⚠️ This method always returns `0` on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.processes().values().max_by_key(|p| p.total_pageins()) {
+    ///     println!("{:?} paged in {} pages", process.name(), process.total_pageins());
+    /// }
+    /// ```
+    pub fn total_pageins(&self) -> u64 {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.total_pageins()
+            } else {
+                0
+            }
+        }
+    }
+
+    /// Returns the TCP and UDP sockets opened by the process (like `lsof -i`).
+    ///
+    /// It returns an empty list if the process can't be inspected, which is usually the case for
+    /// the processes of other users if not running as root.
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns an empty list on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     let connections = process.connections();
+    ///     let established = connections.iter().filter(|c| c.is_established).count();
+    ///     println!("{established} established connections");
+    /// }
+    /// ```
+    pub fn connections(&self) -> Vec<Connection> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.connections()
+            } else {
+                Vec::new()
+            }
+        }
+    }
+
+    /// Returns the number of file descriptors opened by the process, by type.
+    ///
+    /// It helps finding out what kind of file descriptors are leaking. It returns an empty
+    /// breakdown (all counts at `0`) if the process can't be inspected.
+    ///
+    /// **Important**: this information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns an empty breakdown on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     let fds = process.fd_breakdown();
+    ///     println!("{} file descriptors, {} sockets", fds.total(), fds.sockets);
+    /// }
+    /// ```
+    pub fn fd_breakdown(&self) -> FdBreakdown {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.fd_breakdown()
+            } else {
+                FdBreakdown::default()
+            }
+        }
+    }
+
+    /// Returns the ID of the owner user of this process or `None` if this
+    /// information couldn't be retrieved. If you want to get the [`User`] from
+    /// it, take a look at [`Users::get_user_by_id`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("User id for process 1337: {:?}", process.user_id());
+    /// }
+    /// ```
+    pub fn user_id(&self) -> Option<&Uid> {
+        self.inner.user_id()
+    }
+
+    /// Returns the user ID of the effective owner of this process or `None` if
+    /// this information couldn't be retrieved. If you want to get the [`User`]
+    /// from it, take a look at [`Users::get_user_by_id`].
+    ///
+    /// If you run something with `sudo`, the real user ID of the launched
+    /// process will be the ID of the user you are logged in as but effective
+    /// user ID will be `0` (i-e root).
+    ///
+    /// ⚠️ It always returns `None` on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("User id for process 1337: {:?}", process.effective_user_id());
+    /// }
+    /// ```
+    pub fn effective_user_id(&self) -> Option<&Uid> {
+        self.inner.effective_user_id()
+    }
+
+    /// Returns the process group ID of the process.
+    ///
+    /// ⚠️ It always returns `None` on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Group ID for process 1337: {:?}", process.group_id());
+    /// }
+    /// ```
+    pub fn group_id(&self) -> Option<Gid> {
+        self.inner.group_id()
+    }
+
+    /// Returns the effective group ID of the process.
+    ///
+    /// If you run something with `sudo`, the real group ID of the launched
+    /// process will be the primary group ID you are logged in as but effective
+    /// group ID will be `0` (i-e root).
+    ///
+    /// ⚠️ It always returns `None` on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("User id for process 1337: {:?}", process.effective_group_id());
+    /// }
+    /// ```
+    pub fn effective_group_id(&self) -> Option<Gid> {
+        self.inner.effective_group_id()
+    }
+
+    /// Returns the IDs of all the groups the owner of this process (returned by
+    /// [`Process::user_id`]) belongs to, including its primary group. Returns `None` if the
+    /// owner couldn't be retrieved or if it isn't in the users database.
+    ///
+    /// The groups are resolved from the users database once per user and then cached, so
+    /// changes of group membership made while the program is running aren't taken into
+    /// account. To get the names of the groups, take a look at [`User::groups`].
+    ///
+    /// ⚠️ It always returns `None` on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Groups of the owner of 1337: {:?}", process.supplementary_groups());
+    /// }
+    /// ```
+    pub fn supplementary_groups(&self) -> Option<Vec<Gid>> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                not(feature = "unknown-ci"),
+                any(
+                    target_os = "freebsd",
+                    target_os = "linux",
+                    target_os = "android",
+                    target_os = "macos",
+                    target_os = "ios",
+                )
+            ))] {
+                crate::unix::users::get_supplementary_groups(self.user_id()?)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Wait for process termination.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Waiting for pid 1337");
+    ///     process.wait();
+    ///     println!("Pid 1337 exited");
+    /// }
+    /// ```
+    pub fn wait(&self) {
+        self.inner.wait()
+    }
+
+    /// Returns the session ID for the current process or `None` if it couldn't
+    /// be retrieved.
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Session ID for process 1337: {:?}", process.session_id());
+    /// }
+    /// ```
+    pub fn session_id(&self) -> Option<Pid> {
+        self.inner.session_id()
+    }
+
+    /// Returns `true` if the process is being traced or debugged (like with `ptrace`, `gdb`,
+    /// `lldb` or a Windows debugger), or `None` if the process couldn't be inspected (which
+    /// usually happens when it belongs to another user).
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Being traced: {:?}", process.is_being_traced());
+    /// }
+    /// ```
+    pub fn is_being_traced(&self) -> Option<bool> {
+        self.inner.is_being_traced()
+    }
+
+    /// Returns the PID of the process macOS holds responsible for this one, or `None` if it
+    /// couldn't be retrieved.
+    ///
+    /// It is used to attribute the resources used by helper processes (like XPC services) to
+    /// the application which launched them. Processes which aren't helpers are responsible for
+    /// themselves, so their own PID is returned.
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(responsible) = process.responsible_pid().and_then(|pid| s.process(pid)) {
+    ///         println!("1337 works for {:?}", responsible.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn responsible_pid(&self) -> Option<Pid> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.responsible_pid()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the number of on-screen windows owned by this process.
+    ///
+    /// It can be used to tell GUI applications apart from background processes and daemons,
+    /// which return `0`. Windows which are minimized or on another space are not counted.
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `0` on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for (pid, process) in s.processes() {
+    ///     let windows = process.window_count();
+    ///     if windows != 0 {
+    ///         println!("[{pid}] {:?} has {windows} window(s)", process.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn window_count(&self) -> u32 {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.window_count()
+            } else {
+                0
+            }
+        }
+    }
+
+    /// Returns the path of the controlling terminal device of the process (like
+    /// `/dev/ttys003`), or `None` if it isn't attached to one (like daemons).
+    ///
+    /// It can be used to group the processes by terminal session.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.tty());
+    /// }
+    /// ```
+    pub fn tty(&self) -> Option<&Path> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.tty()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the percentage of GPU time used by the process since the last refresh.
+    ///
+    /// It is computed from the GPU time the graphics driver accounts to the process (the one
+    /// Activity Monitor displays), so it is only available with Apple GPUs. It is refreshed
+    /// alongside the CPU usage (with [`ProcessRefreshKind::with_cpu`]) when refreshing all
+    /// processes and, like the CPU usage, it needs two refreshes to be computed: before that, or
+    /// if the process never used the GPU, it returns `None`.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_processes();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}%", process.gpu_usage());
+    /// }
+    /// ```
+    pub fn gpu_usage(&self) -> Option<f32> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.gpu_usage()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the bundle identifier of the application (like `com.apple.Safari`), or `None` if
+    /// the process isn't part of an application bundle (like command-line tools).
+    ///
+    /// The bundle is located from the [`Process::exe`] path, so this information requires it to
+    /// have been retrieved. It is computed every time this method is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.bundle_id());
+    /// }
+    /// ```
+    pub fn bundle_id(&self) -> Option<String> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.bundle_id()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the code signing identifier of the process (its bundle identifier for
+    /// applications). Returns `None` if the process isn't signed or can't be inspected.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for (pid, process) in s.processes() {
+    ///     if process.code_signing_id().is_none() {
+    ///         println!("{pid} ({:?}) is not signed", process.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn code_signing_id(&self) -> Option<String> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.code_signing_id()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Tasks run by this process. If there are none, returns `None`.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(tasks) = process.tasks() {
+    ///         println!("Listing tasks for process {:?}", process.pid());
+    ///         for task_pid in tasks {
+    ///             if let Some(task) = s.process(*task_pid) {
+    ///                 println!("Task {:?}: {:?}", task.pid(), task.name());
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn tasks(&self) -> Option<&HashSet<Pid>> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.tasks.as_ref()
+            } else {
+                None
+            }
+        }
+    }
+
+    /// If the process is a thread, it'll return `Some` with the kind of thread it is. Returns
+    /// `None` otherwise.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    ///
+    /// for (_, process) in s.processes() {
+    ///     if let Some(thread_kind) = process.thread_kind() {
+    ///         println!("Process {:?} is a {thread_kind:?} thread", process.pid());
+    ///     }
+    /// }
+    /// ```
+    pub fn thread_kind(&self) -> Option<ThreadKind> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.thread_kind()
+            } else {
+                None
+            }
+        }
+    }
+}
+
+macro_rules! pid_decl {
+    ($typ:ty) => {
+        #[doc = include_str!("../md_doc/pid.md")]
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        #[repr(transparent)]
+        pub struct Pid(pub(crate) $typ);
+
+        impl From<usize> for Pid {
+            fn from(v: usize) -> Self {
+                Self(v as _)
+            }
+        }
+        impl From<Pid> for usize {
+            fn from(v: Pid) -> Self {
+                v.0 as _
+            }
+        }
+        impl From<Pid> for u32 {
+            fn from(v: Pid) -> Self {
+                v.as_u32()
+            }
+        }
+        impl FromStr for Pid {
+            type Err = <$typ as FromStr>::Err;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self(<$typ>::from_str(s)?))
+            }
+        }
+        impl fmt::Display for Pid {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+        impl Pid {
+            /// Allows to convert [`Pid`][crate::Pid] into [`u32`].
+            ///
+            /// ```
+            /// use sysinfo::Pid;
+            ///
+            /// let pid = Pid::from_u32(0);
+            /// let value: u32 = pid.as_u32();
+            /// ```
+            pub fn as_u32(self) -> u32 {
+                self.0 as _
+            }
+            /// Allows to convert a [`u32`] into [`Pid`][crate::Pid].
+            ///
+            /// ```
+            /// use sysinfo::Pid;
+            ///
+            /// let pid = Pid::from_u32(0);
+            /// ```
+            pub fn from_u32(v: u32) -> Self {
+                Self(v as _)
+            }
+        }
+    };
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(all(
+        not(feature = "unknown-ci"),
+        any(
+            target_os = "freebsd",
+            target_os = "linux",
+            target_os = "android",
+            target_os = "macos",
+            target_os = "ios",
+        )
+    ))] {
+        use libc::pid_t;
+
+        pid_decl!(pid_t);
+    } else {
+        pid_decl!(usize);
+    }
+}
+
+macro_rules! impl_get_set {
+    ($ty_name:ident, $name:ident, $with:ident, $without:ident $(, $extra_doc:literal)? $(,)?) => {
+        #[doc = concat!("Returns the value of the \"", stringify!($name), "\" refresh kind.")]
+        $(#[doc = concat!("
+", $extra_doc, "
+")])?
+        #[doc = concat!("
+```
+use sysinfo::", stringify!($ty_name), ";
+
+let r = ", stringify!($ty_name), "::new();
+assert_eq!(r.", stringify!($name), "(), false);
+
+let r = r.with_", stringify!($name), "();
+assert_eq!(r.", stringify!($name), "(), true);
+
+let r = r.without_", stringify!($name), "();
+assert_eq!(r.", stringify!($name), "(), false);
+```")]
+        pub fn $name(&self) -> bool {
+            self.$name
+        }
+
+        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `true`.
+
+```
+use sysinfo::", stringify!($ty_name), ";
+
+let r = ", stringify!($ty_name), "::new();
+assert_eq!(r.", stringify!($name), "(), false);
+
+let r = r.with_", stringify!($name), "();
+assert_eq!(r.", stringify!($name), "(), true);
+```")]
+        #[must_use]
+        pub fn $with(mut self) -> Self {
+            self.$name = true;
+            self
+        }
+
+        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `false`.
+
+```
+use sysinfo::", stringify!($ty_name), ";
+
+let r = ", stringify!($ty_name), "::everything();
+assert_eq!(r.", stringify!($name), "(), true);
+
+let r = r.without_", stringify!($name), "();
+assert_eq!(r.", stringify!($name), "(), false);
+```")]
+        #[must_use]
+        pub fn $without(mut self) -> Self {
+            self.$name = false;
+            self
+        }
+    };
+
+    // To handle `UpdateKind`.
+    ($ty_name:ident, $name:ident, $with:ident, $without:ident, UpdateKind $(, $extra_doc:literal)? $(,)?) => {
+        #[doc = concat!("Returns the value of the \"", stringify!($name), "\" refresh kind.")]
+        $(#[doc = concat!("
+", $extra_doc, "
+")])?
+        #[doc = concat!("
+```
+use sysinfo::{", stringify!($ty_name), ", UpdateKind};
+
+let r = ", stringify!($ty_name), "::new();
+assert_eq!(r.", stringify!($name), "(), UpdateKind::Never);
+
+let r = r.with_", stringify!($name), "(UpdateKind::OnlyIfNotSet);
+assert_eq!(r.", stringify!($name), "(), UpdateKind::OnlyIfNotSet);
+
+let r = r.without_", stringify!($name), "();
+assert_eq!(r.", stringify!($name), "(), UpdateKind::Never);
+```")]
+        pub fn $name(&self) -> UpdateKind {
+            self.$name
+        }
+
+        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind.
+
+```
+use sysinfo::{", stringify!($ty_name), ", UpdateKind};
+
+let r = ", stringify!($ty_name), "::new();
+assert_eq!(r.", stringify!($name), "(), UpdateKind::Never);
+
+let r = r.with_", stringify!($name), "(UpdateKind::OnlyIfNotSet);
+assert_eq!(r.", stringify!($name), "(), UpdateKind::OnlyIfNotSet);
+```")]
+        #[must_use]
+        pub fn $with(mut self, kind: UpdateKind) -> Self {
+            self.$name = kind;
+            self
+        }
+
+        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `UpdateKind::Never`.
+
+```
+use sysinfo::{", stringify!($ty_name), ", UpdateKind};
+
+let r = ", stringify!($ty_name), "::everything();
+assert_eq!(r.", stringify!($name), "(), UpdateKind::OnlyIfNotSet);
+
+let r = r.without_", stringify!($name), "();
+assert_eq!(r.", stringify!($name), "(), UpdateKind::Never);
+```")]
+        #[must_use]
+        pub fn $without(mut self) -> Self {
+            self.$name = UpdateKind::Never;
+            self
+        }
+    };
+
+    // To handle `*RefreshKind`.
+    ($ty_name:ident, $name:ident, $with:ident, $without:ident, $typ:ty $(,)?) => {
+        #[doc = concat!("Returns the value of the \"", stringify!($name), "\" refresh kind.
+
+```
+use sysinfo::{", stringify!($ty_name), ", ", stringify!($typ), "};
+
+let r = ", stringify!($ty_name), "::new();
+assert_eq!(r.", stringify!($name), "().is_some(), false);
+
+let r = r.with_", stringify!($name), "(", stringify!($typ), "::everything());
+assert_eq!(r.", stringify!($name), "().is_some(), true);
+
+let r = r.without_", stringify!($name), "();
+assert_eq!(r.", stringify!($name), "().is_some(), false);
+```")]
+        pub fn $name(&self) -> Option<$typ> {
+            self.$name
+        }
+
+        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `Some(...)`.
+
+```
+use sysinfo::{", stringify!($ty_name), ", ", stringify!($typ), "};
+
+let r = ", stringify!($ty_name), "::new();
+assert_eq!(r.", stringify!($name), "().is_some(), false);
+
+let r = r.with_", stringify!($name), "(", stringify!($typ), "::everything());
+assert_eq!(r.", stringify!($name), "().is_some(), true);
+```")]
+        #[must_use]
+        pub fn $with(mut self, kind: $typ) -> Self {
+            self.$name = Some(kind);
+            self
+        }
+
+        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `None`.
+
+```
+use sysinfo::", stringify!($ty_name), ";
+
+let r = ", stringify!($ty_name), "::everything();
+assert_eq!(r.", stringify!($name), "().is_some(), true);
+
+let r = r.without_", stringify!($name), "();
+assert_eq!(r.", stringify!($name), "().is_some(), false);
+```")]
+        #[must_use]
+        pub fn $without(mut self) -> Self {
+            self.$name = None;
+            self
+        }
+    };
+}
+
+/// This enum allows you to specify when you want the related information to be updated.
+///
+/// For example if you only want the [`Process::exe()`] information to be refreshed only if it's not
+/// already set:
+///
+/// ```no_run
+/// use sysinfo::{ProcessRefreshKind, System, UpdateKind};
+///
+/// let mut system = System::new();
+/// system.refresh_processes_specifics(
+///     ProcessRefreshKind::new().with_exe(UpdateKind::OnlyIfNotSet),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UpdateKind {
+    /// Never update the related information.
+    #[default]
+    Never,
+    /// Always update the related information.
+    Always,
+    /// Only update the related information if it was not already set at least once.
+    OnlyIfNotSet,
+}
+
+impl UpdateKind {
+    /// If `self` is `OnlyIfNotSet`, `f` is called and its returned value is returned.
+    #[allow(dead_code)] // Needed for unsupported targets.
+    pub(crate) fn needs_update(self, f: impl Fn() -> bool) -> bool {
+        match self {
+            Self::Never => false,
+            Self::Always => true,
+            Self::OnlyIfNotSet => f(),
+        }
+    }
+}
+
+/// Used to determine what you want to refresh specifically on the [`Process`] type.
+///
+/// When all refresh are ruled out, a [`Process`] will still retrieve the following information:
+///  * Process ID ([`Pid`])
+///  * Parent process ID (on Windows it never changes though)
+///  * Process name
+///  * Start time
+///
+/// ⚠️ Just like all other refresh types, ruling out a refresh doesn't assure you that
+/// the information won't be retrieved if the information is accessible without needing
+/// extra computation.
+///
+/// ```
+/// use sysinfo::{ProcessRefreshKind, System};
+///
+/// let mut system = System::new();
+///
+/// // We don't want to update the CPU information.
+/// system.refresh_processes_specifics(ProcessRefreshKind::everything().without_cpu());
+///
+/// for (_, proc_) in system.processes() {
+///     // We use a `==` comparison on float only because we know it's set to 0 here.
+///     assert_eq!(proc_.cpu_usage(), 0.);
+/// }
+/// ```
+///
+/// [`Process`]: crate::Process
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProcessRefreshKind {
+    cpu: bool,
+    disk_usage: bool,
+    memory: bool,
+    user: UpdateKind,
+    cwd: UpdateKind,
+    root: UpdateKind,
+    environ: UpdateKind,
+    cmd: UpdateKind,
+    exe: UpdateKind,
+    memory_regions: bool,
+}
+
+impl ProcessRefreshKind {
+    /// Creates a new `ProcessRefreshKind` with every refresh set to `false`.
+    ///
+    /// ```
+    /// use sysinfo::{ProcessRefreshKind, UpdateKind};
+    ///
+    /// let r = ProcessRefreshKind::new();
+    ///
+    /// assert_eq!(r.cpu(), false);
+    /// assert_eq!(r.user(), UpdateKind::Never);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `ProcessRefreshKind` with every refresh set to `true` or
+    /// [`UpdateKind::OnlyIfNotSet`].
+    ///
+    /// ```
+    /// use sysinfo::{ProcessRefreshKind, UpdateKind};
+    ///
+    /// let r = ProcessRefreshKind::everything();
+    ///
+    /// assert_eq!(r.cpu(), true);
+    /// assert_eq!(r.user(), UpdateKind::OnlyIfNotSet);
+    /// ```
+    pub fn everything() -> Self {
+        Self {
+            cpu: true,
+            disk_usage: true,
+            memory: true,
+            user: UpdateKind::OnlyIfNotSet,
+            cwd: UpdateKind::OnlyIfNotSet,
+            root: UpdateKind::OnlyIfNotSet,
+            environ: UpdateKind::OnlyIfNotSet,
+            cmd: UpdateKind::OnlyIfNotSet,
+            exe: UpdateKind::OnlyIfNotSet,
+            memory_regions: true,
+        }
+    }
+
+    impl_get_set!(ProcessRefreshKind, cpu, with_cpu, without_cpu);
+    impl_get_set!(
+        ProcessRefreshKind,
+        disk_usage,
+        with_disk_usage,
+        without_disk_usage
+    );
+    impl_get_set!(
+        ProcessRefreshKind,
+        user,
+        with_user,
+        without_user,
+        UpdateKind,
+        "\
+It will retrieve the following information:
+
+ * user ID
+ * user effective ID (if available on the platform)
+ * user group ID (if available on the platform)
+ * user effective ID (if available on the platform)"
+    );
+    impl_get_set!(ProcessRefreshKind, memory, with_memory, without_memory);
+    impl_get_set!(ProcessRefreshKind, cwd, with_cwd, without_cwd, UpdateKind);
+    impl_get_set!(
+        ProcessRefreshKind,
+        root,
+        with_root,
+        without_root,
+        UpdateKind
+    );
+    impl_get_set!(
+        ProcessRefreshKind,
+        environ,
+        with_environ,
+        without_environ,
+        UpdateKind
+    );
+    impl_get_set!(ProcessRefreshKind, cmd, with_cmd, without_cmd, UpdateKind);
+    impl_get_set!(ProcessRefreshKind, exe, with_exe, without_exe, UpdateKind);
+    impl_get_set!(
+        ProcessRefreshKind,
+        memory_regions,
+        with_memory_regions,
+        without_memory_regions,
+        "\
+It will retrieve the following information (only on macOS, it requires the rights to access the
+process' task):
+
+ * [`Process::memory_regions`]
+ * [`Process::memory_region_count`]
+ * [`Process::mach_port_count`]
+ * [`Process::swapped_memory`] (also on Linux, where it requires to read an extra file)"
+    );
+}
+
+/// Used to determine what you want to refresh specifically on the [`Cpu`] type.
+///
+/// ⚠️ Just like all other refresh types, ruling out a refresh doesn't assure you that
+/// the information won't be retrieved if the information is accessible without needing
+/// extra computation.
+///
+/// ```
+/// use sysinfo::{CpuRefreshKind, System};
+///
+/// let mut system = System::new();
+///
+/// // We don't want to update all the CPU information.
+/// system.refresh_cpu_specifics(CpuRefreshKind::everything().without_frequency());
+///
+/// for cpu in system.cpus() {
+///     assert_eq!(cpu.frequency(), 0);
+/// }
+/// ```
+///
+/// [`Cpu`]: crate::Cpu
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CpuRefreshKind {
+    cpu_usage: bool,
+    frequency: bool,
+}
+
+impl CpuRefreshKind {
+    /// Creates a new `CpuRefreshKind` with every refresh set to `false`.
+    ///
+    /// ```
+    /// use sysinfo::CpuRefreshKind;
+    ///
+    /// let r = CpuRefreshKind::new();
+    ///
+    /// assert_eq!(r.frequency(), false);
+    /// assert_eq!(r.cpu_usage(), false);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `CpuRefreshKind` with every refresh set to `true`.
+    ///
+    /// ```
+    /// use sysinfo::CpuRefreshKind;
+    ///
+    /// let r = CpuRefreshKind::everything();
+    ///
+    /// assert_eq!(r.frequency(), true);
+    /// assert_eq!(r.cpu_usage(), true);
+    /// ```
+    pub fn everything() -> Self {
+        Self {
+            cpu_usage: true,
+            frequency: true,
+        }
+    }
+
+    impl_get_set!(CpuRefreshKind, cpu_usage, with_cpu_usage, without_cpu_usage);
+    impl_get_set!(CpuRefreshKind, frequency, with_frequency, without_frequency);
+}
+
+/// Used to determine which memory you want to refresh specifically.
+///
+/// ⚠️ Just like all other refresh types, ruling out a refresh doesn't assure you that
+/// the information won't be retrieved if the information is accessible without needing
+/// extra computation.
+///
+/// ```
+/// use sysinfo::{MemoryRefreshKind, System};
+///
+/// let mut system = System::new();
+///
+/// // We don't want to update all memories information.
+/// system.refresh_memory_specifics(MemoryRefreshKind::new().with_ram());
+///
+/// println!("total RAM: {}", system.total_memory());
+/// println!("free RAM:  {}", system.free_memory());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryRefreshKind {
+    ram: bool,
+    swap: bool,
+}
 
-let r = r.without_", stringify!($name), "();
-assert_eq!(r.", stringify!($name), "(), UpdateKind::Never);
-```")]
-        pub fn $name(&self) -> UpdateKind {
-            self.$name
+impl MemoryRefreshKind {
+    /// Creates a new `MemoryRefreshKind` with every refresh set to `false`.
+    ///
+    /// ```
+    /// use sysinfo::MemoryRefreshKind;
+    ///
+    /// let r = MemoryRefreshKind::new();
+    ///
+    /// assert_eq!(r.ram(), false);
+    /// assert_eq!(r.swap(), false);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `MemoryRefreshKind` with every refresh set to `true`.
+    ///
+    /// ```
+    /// use sysinfo::MemoryRefreshKind;
+    ///
+    /// let r = MemoryRefreshKind::everything();
+    ///
+    /// assert_eq!(r.ram(), true);
+    /// assert_eq!(r.swap(), true);
+    /// ```
+    pub fn everything() -> Self {
+        Self {
+            ram: true,
+            swap: true,
         }
+    }
 
-        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind.
+    impl_get_set!(MemoryRefreshKind, ram, with_ram, without_ram);
+    impl_get_set!(MemoryRefreshKind, swap, with_swap, without_swap);
+}
 
-```
-use sysinfo::{", stringify!($ty_name), ", UpdateKind};
+/// Used to determine what you want to refresh specifically on the [`System`][crate::System] type.
+///
+/// ⚠️ Just like all other refresh types, ruling out a refresh doesn't assure you that
+/// the information won't be retrieved if the information is accessible without needing
+/// extra computation.
+///
+/// ```
+/// use sysinfo::{RefreshKind, System};
+///
+/// // We want everything except memory.
+/// let mut system = System::new_with_specifics(RefreshKind::everything().without_memory());
+///
+/// assert_eq!(system.total_memory(), 0);
+/// # if sysinfo::IS_SUPPORTED_SYSTEM
+/// #     && !cfg!(feature = "apple-sandbox")
+/// #     && cfg!(feature = "processes")
+/// # {
+/// assert!(system.processes().len() > 0);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RefreshKind {
+    processes: Option<ProcessRefreshKind>,
+    memory: Option<MemoryRefreshKind>,
+    cpu: Option<CpuRefreshKind>,
+}
 
-let r = ", stringify!($ty_name), "::new();
-assert_eq!(r.", stringify!($name), "(), UpdateKind::Never);
+impl RefreshKind {
+    /// Creates a new `RefreshKind` with every refresh set to `false`/`None`.
+    ///
+    /// ```
+    /// use sysinfo::RefreshKind;
+    ///
+    /// let r = RefreshKind::new();
+    ///
+    /// assert_eq!(r.processes().is_some(), false);
+    /// assert_eq!(r.memory().is_some(), false);
+    /// assert_eq!(r.cpu().is_some(), false);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-let r = r.with_", stringify!($name), "(UpdateKind::OnlyIfNotSet);
-assert_eq!(r.", stringify!($name), "(), UpdateKind::OnlyIfNotSet);
-```")]
-        #[must_use]
-        pub fn $with(mut self, kind: UpdateKind) -> Self {
-            self.$name = kind;
-            self
+    /// Creates a new `RefreshKind` with every refresh set to `true`/`Some(...)`.
+    ///
+    /// ```
+    /// use sysinfo::RefreshKind;
+    ///
+    /// let r = RefreshKind::everything();
+    ///
+    /// assert_eq!(r.processes().is_some(), true);
+    /// assert_eq!(r.memory().is_some(), true);
+    /// assert_eq!(r.cpu().is_some(), true);
+    /// ```
+    pub fn everything() -> Self {
+        Self {
+            processes: Some(ProcessRefreshKind::everything()),
+            memory: Some(MemoryRefreshKind::everything()),
+            cpu: Some(CpuRefreshKind::everything()),
         }
+    }
 
-        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `UpdateKind::Never`.
+    impl_get_set!(
+        RefreshKind,
+        processes,
+        with_processes,
+        without_processes,
+        ProcessRefreshKind
+    );
+    impl_get_set!(
+        RefreshKind,
+        memory,
+        with_memory,
+        without_memory,
+        MemoryRefreshKind
+    );
+    impl_get_set!(RefreshKind, cpu, with_cpu, without_cpu, CpuRefreshKind);
+}
 
-```
-use sysinfo::{", stringify!($ty_name), ", UpdateKind};
+/// Interacting with network interfaces.
+///
+/// ```no_run
+/// use sysinfo::Networks;
+///
+/// let networks = Networks::new_with_refreshed_list();
+/// for (interface_name, network) in &networks {
+///     println!("[{interface_name}]: {network:?}");
+/// }
+/// ```
+pub struct Networks {
+    pub(crate) inner: NetworksInner,
+    last_refresh: Option<Instant>,
+}
 
-let r = ", stringify!($ty_name), "::everything();
-assert_eq!(r.", stringify!($name), "(), UpdateKind::OnlyIfNotSet);
+impl<'a> IntoIterator for &'a Networks {
+    type Item = (&'a String, &'a NetworkData);
+    type IntoIter = std::collections::hash_map::Iter<'a, String, NetworkData>;
 
-let r = r.without_", stringify!($name), "();
-assert_eq!(r.", stringify!($name), "(), UpdateKind::Never);
-```")]
-        #[must_use]
-        pub fn $without(mut self) -> Self {
-            self.$name = UpdateKind::Never;
-            self
-        }
-    };
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
 
-    // To handle `*RefreshKind`.
-    ($ty_name:ident, $name:ident, $with:ident, $without:ident, $typ:ty $(,)?) => {
-        #[doc = concat!("Returns the value of the \"", stringify!($name), "\" refresh kind.
+impl Default for Networks {
+    fn default() -> Self {
+        Networks::new()
+    }
+}
 
-```
-use sysinfo::{", stringify!($ty_name), ", ", stringify!($typ), "};
+impl Networks {
+    /// Creates a new empty [`Networks`][crate::Networks] type.
+    ///
+    /// If you want it to be filled directly, take a look at [`Networks::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new();
+    /// networks.refresh_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("[{interface_name}]: {network:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            inner: NetworksInner::new(),
+            last_refresh: None,
+        }
+    }
 
-let r = ", stringify!($ty_name), "::new();
-assert_eq!(r.", stringify!($name), "().is_some(), false);
+    /// Creates a new [`Networks`][crate::Networks] type with the disk list
+    /// loaded. It is a combination of [`Networks::new`] and
+    /// [`Networks::refresh_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for network in &networks {
+    ///     println!("{network:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut networks = Self::new();
+        networks.refresh_list();
+        networks
+    }
 
-let r = r.with_", stringify!($name), "(", stringify!($typ), "::everything());
-assert_eq!(r.", stringify!($name), "().is_some(), true);
+    /// Returns the network interfaces map.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for network in networks.list() {
+    ///     println!("{network:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &HashMap<String, NetworkData> {
+        self.inner.list()
+    }
 
-let r = r.without_", stringify!($name), "();
-assert_eq!(r.", stringify!($name), "().is_some(), false);
-```")]
-        pub fn $name(&self) -> Option<$typ> {
-            self.$name
-        }
+    /// Refreshes the network interfaces list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new();
+    /// networks.refresh_list();
+    /// ```
+    pub fn refresh_list(&mut self) {
+        self.inner.refresh_list();
+        self.update_elapsed();
+    }
 
-        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `Some(...)`.
+    /// Refreshes the network interfaces' content. If you didn't run [`Networks::refresh_list`]
+    /// before, calling this method won't do anything as no interfaces are present.
+    ///
+    /// ⚠️ If a network interface is added or removed, this method won't take it into account. Use
+    /// [`Networks::refresh_list`] instead.
+    ///
+    /// ⚠️ If you didn't call [`Networks::refresh_list`] beforehand, this method will do nothing
+    /// as the network list will be empty.
+    ///
+    /// ⚠️ The rates (like [`NetworkData::received_rate`]) are computed over the time elapsed
+    /// since the previous refresh, so they are only meaningful if it was long enough (calling
+    /// this method twice in a row gives unreliable rates). Use [`Networks::refresh_rates`] if
+    /// you want them right away.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Wait some time...? Then refresh the data of each network.
+    /// networks.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        self.inner.refresh();
+        self.update_elapsed();
+    }
 
-```
-use sysinfo::{", stringify!($ty_name), ", ", stringify!($typ), "};
+    /// Refreshes the network interfaces' content twice, waiting `interval` in between, so the
+    /// rates (like [`NetworkData::received_rate`]) are meaningful right away.
+    ///
+    /// ⚠️ This method blocks the current thread for `interval`. The longer it is, the smoother
+    /// the rates are.
+    ///
+    /// ⚠️ Like [`Networks::refresh`], this method does nothing if [`Networks::refresh_list`]
+    /// wasn't called beforehand.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::time::Duration;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// networks.refresh_rates(Duration::from_secs(1));
+    /// for (interface_name, network) in &networks {
+    ///     println!("[{interface_name}] in: {:.2} B/s", network.received_rate());
+    /// }
+    /// ```
+    pub fn refresh_rates(&mut self, interval: Duration) {
+        self.refresh();
+        std::thread::sleep(interval);
+        self.refresh();
+    }
 
-let r = ", stringify!($ty_name), "::new();
-assert_eq!(r.", stringify!($name), "().is_some(), false);
+    /// Returns the name of the network interface used by the default route (the one used to reach
+    /// the internet), or `None` if there is no default route (when offline for example).
+    ///
+    /// If there is both an IPv4 and an IPv6 default route, the IPv4 one is used.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// if let Some(name) = Networks::default_interface() {
+    ///     if let Some(data) = networks.get(&name) {
+    ///         println!("{name}: {} B received", data.total_received());
+    ///     }
+    /// }
+    /// ```
+    pub fn default_interface() -> Option<String> {
+        NetworksInner::default_interface()
+    }
 
-let r = r.with_", stringify!($name), "(", stringify!($typ), "::everything());
-assert_eq!(r.", stringify!($name), "().is_some(), true);
-```")]
-        #[must_use]
-        pub fn $with(mut self, kind: $typ) -> Self {
-            self.$name = Some(kind);
-            self
+    /// Returns the received and transmitted data summed over all the network interfaces, except
+    /// the loopback ones (see [`NetworkData::is_loopback`]).
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// networks.refresh();
+    /// let global = networks.global_network();
+    /// println!(
+    ///     "in: {:.0} B/s, out: {:.0} B/s",
+    ///     global.received_rate, global.transmitted_rate,
+    /// );
+    /// ```
+    pub fn global_network(&self) -> NetworkTotals {
+        self.list()
+            .values()
+            .filter(|data| !data.is_loopback())
+            .fold(NetworkTotals::default(), |totals, data| NetworkTotals {
+                received: totals.received.saturating_add(data.received()),
+                total_received: totals.total_received.saturating_add(data.total_received()),
+                received_rate: totals.received_rate + data.received_rate(),
+                transmitted: totals.transmitted.saturating_add(data.transmitted()),
+                total_transmitted: totals
+                    .total_transmitted
+                    .saturating_add(data.total_transmitted()),
+                transmitted_rate: totals.transmitted_rate + data.transmitted_rate(),
+            })
+    }
+
+    /// Returns the information of the network interfaces as a flat list of named metrics, like
+    /// [`System::export_metrics`].
+    ///
+    /// The metrics are named as follows (`<name>` being the name of the interface, sanitized like
+    /// the mount points in [`Disks::export_metrics`]. The interfaces are sorted by name, so the
+    /// suffixes added to the colliding names are stable):
+    ///
+    /// | Name                        | Value                                          |
+    /// |-----------------------------|------------------------------------------------|
+    /// | `network.<name>.rx_rate`    | [`NetworkData::received_rate`], in bytes/s     |
+    /// | `network.<name>.tx_rate`    | [`NetworkData::transmitted_rate`], in bytes/s  |
+    /// | `network.<name>.rx_bytes`   | [`NetworkData::total_received`]                |
+    /// | `network.<name>.tx_bytes`   | [`NetworkData::total_transmitted`]             |
+    /// | `network.<name>.rx_packets` | [`NetworkData::total_packets_received`]        |
+    /// | `network.<name>.tx_packets` | [`NetworkData::total_packets_transmitted`]     |
+    /// | `network.<name>.rx_errors`  | [`NetworkData::total_errors_on_received`]      |
+    /// | `network.<name>.tx_errors`  | [`NetworkData::total_errors_on_transmitted`]   |
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (name, value) in networks.export_metrics() {
+    ///     println!("{name}:{value}|g");
+    /// }
+    /// ```
+    pub fn export_metrics(&self) -> Vec<(String, f64)> {
+        let mut metrics = Vec::with_capacity(self.list().len() * 8);
+        let mut interfaces = self.list().iter().collect::<Vec<_>>();
+        interfaces.sort_unstable_by_key(|(name, _)| *name);
+        let mut names = crate::utils::MetricNameComponents::default();
+        for (name, data) in interfaces {
+            let name = names.get(name);
+            metrics.extend([
+                (format!("network.{name}.rx_rate"), data.received_rate()),
+                (format!("network.{name}.tx_rate"), data.transmitted_rate()),
+                (
+                    format!("network.{name}.rx_bytes"),
+                    data.total_received() as f64,
+                ),
+                (
+                    format!("network.{name}.tx_bytes"),
+                    data.total_transmitted() as f64,
+                ),
+                (
+                    format!("network.{name}.rx_packets"),
+                    data.total_packets_received() as f64,
+                ),
+                (
+                    format!("network.{name}.tx_packets"),
+                    data.total_packets_transmitted() as f64,
+                ),
+                (
+                    format!("network.{name}.rx_errors"),
+                    data.total_errors_on_received() as f64,
+                ),
+                (
+                    format!("network.{name}.tx_errors"),
+                    data.total_errors_on_transmitted() as f64,
+                ),
+            ]);
         }
+        metrics
+    }
 
-        #[doc = concat!("Sets the value of the \"", stringify!($name), "\" refresh kind to `None`.
-
-```
-use sysinfo::", stringify!($ty_name), ";
-
-let r = ", stringify!($ty_name), "::everything();
-assert_eq!(r.", stringify!($name), "().is_some(), true);
-
-let r = r.without_", stringify!($name), "();
-assert_eq!(r.", stringify!($name), "().is_some(), false);
-```")]
-        #[must_use]
-        pub fn $without(mut self) -> Self {
-            self.$name = None;
-            self
+    /// Stores in each interface the time elapsed since the previous refresh, which is needed to
+    /// compute the rates.
+    fn update_elapsed(&mut self) {
+        let now = Instant::now();
+        let elapsed = self.last_refresh.map(|last| now.duration_since(last));
+        self.last_refresh = Some(now);
+        for data in self.inner.interfaces.values_mut() {
+            data.elapsed = elapsed;
         }
-    };
+    }
 }
 
-/// This enum allows you to specify when you want the related information to be updated.
-///
-/// For example if you only want the [`Process::exe()`] information to be refreshed only if it's not
-/// already set:
-///
-/// ```no_run
-/// use sysinfo::{ProcessRefreshKind, System, UpdateKind};
-///
-/// let mut system = System::new();
-/// system.refresh_processes_specifics(
-///     ProcessRefreshKind::new().with_exe(UpdateKind::OnlyIfNotSet),
-/// );
-/// ```
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub enum UpdateKind {
-    /// Never update the related information.
-    #[default]
-    Never,
-    /// Always update the related information.
-    Always,
-    /// Only update the related information if it was not already set at least once.
-    OnlyIfNotSet,
-}
+impl std::ops::Deref for Networks {
+    type Target = HashMap<String, NetworkData>;
 
-impl UpdateKind {
-    /// If `self` is `OnlyIfNotSet`, `f` is called and its returned value is returned.
-    #[allow(dead_code)] // Needed for unsupported targets.
-    pub(crate) fn needs_update(self, f: impl Fn() -> bool) -> bool {
-        match self {
-            Self::Never => false,
-            Self::Always => true,
-            Self::OnlyIfNotSet => f(),
-        }
+    fn deref(&self) -> &Self::Target {
+        self.list()
     }
 }
 
-/// Used to determine what you want to refresh specifically on the [`Process`] type.
+/// Received and transmitted data summed over several network interfaces.
 ///
-/// When all refresh are ruled out, a [`Process`] will still retrieve the following information:
-///  * Process ID ([`Pid`])
-///  * Parent process ID (on Windows it never changes though)
-///  * Process name
-///  * Start time
+/// It is returned by [`Networks::global_network`][crate::Networks::global_network].
 ///
-/// ⚠️ Just like all other refresh types, ruling out a refresh doesn't assure you that
-/// the information won't be retrieved if the information is accessible without needing
-/// extra computation.
+/// ```no_run
+/// use sysinfo::Networks;
 ///
+/// let networks = Networks::new_with_refreshed_list();
+/// let global = networks.global_network();
+/// println!("{} B received, {} B transmitted", global.total_received, global.total_transmitted);
 /// ```
-/// use sysinfo::{ProcessRefreshKind, System};
-///
-/// let mut system = System::new();
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct NetworkTotals {
+    /// Number of bytes received since the last refresh (see [`NetworkData::received`]).
+    pub received: u64,
+    /// Total number of bytes received (see [`NetworkData::total_received`]).
+    pub total_received: u64,
+    /// Number of bytes received per second (see [`NetworkData::received_rate`]).
+    pub received_rate: f64,
+    /// Number of bytes transmitted since the last refresh (see [`NetworkData::transmitted`]).
+    pub transmitted: u64,
+    /// Total number of bytes transmitted (see [`NetworkData::total_transmitted`]).
+    pub total_transmitted: u64,
+    /// Number of bytes transmitted per second (see [`NetworkData::transmitted_rate`]).
+    pub transmitted_rate: f64,
+}
+
+/// Getting volume of received and transmitted data.
 ///
-/// // We don't want to update the CPU information.
-/// system.refresh_processes_specifics(ProcessRefreshKind::everything().without_cpu());
+/// ```no_run
+/// use sysinfo::Networks;
 ///
-/// for (_, proc_) in system.processes() {
-///     // We use a `==` comparison on float only because we know it's set to 0 here.
-///     assert_eq!(proc_.cpu_usage(), 0.);
+/// let networks = Networks::new_with_refreshed_list();
+/// for (interface_name, network) in &networks {
+///     println!("[{interface_name}] {network:?}");
 /// }
 /// ```
-///
-/// [`Process`]: crate::Process
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub struct ProcessRefreshKind {
-    cpu: bool,
-    disk_usage: bool,
-    memory: bool,
-    user: UpdateKind,
-    cwd: UpdateKind,
-    root: UpdateKind,
-    environ: UpdateKind,
-    cmd: UpdateKind,
-    exe: UpdateKind,
+pub struct NetworkData {
+    pub(crate) inner: NetworkDataInner,
+    /// Time elapsed between the two last refreshes.
+    pub(crate) elapsed: Option<Duration>,
 }
 
-impl ProcessRefreshKind {
-    /// Creates a new `ProcessRefreshKind` with every refresh set to `false`.
+impl NetworkData {
+    /// Returns the number of received bytes since the last refresh.
+    ///
+    /// If you want the total number of bytes received, take a look at the
+    /// [`total_received`](NetworkData::total_received) method.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh();
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {} B", network.received());
+    /// }
+    /// ```
+    pub fn received(&self) -> u64 {
+        self.inner.received()
+    }
+
+    /// Returns the reception rate (in bytes per second) between the two last refreshes.
+    ///
+    /// It returns `0.` if there was less than two refreshes or if they happened at the same
+    /// instant.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(500));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh();
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("[{interface_name}] in: {:.2} B/s", network.received_rate());
+    /// }
+    /// ```
+    pub fn received_rate(&self) -> f64 {
+        self.rate(self.received())
+    }
+
+    /// Returns the total number of received bytes.
+    ///
+    /// If you want the amount of received bytes since the last refresh, take a look at the
+    /// [`received`](NetworkData::received) method.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {} B", network.total_received());
+    /// }
+    /// ```
+    pub fn total_received(&self) -> u64 {
+        self.inner.total_received()
+    }
+
+    /// Returns the number of transmitted bytes since the last refresh.
+    ///
+    /// If you want the total number of bytes transmitted, take a look at the
+    /// [`total_transmitted`](NetworkData::total_transmitted) method.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh();
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {} B", network.transmitted());
+    /// }
+    /// ```
+    pub fn transmitted(&self) -> u64 {
+        self.inner.transmitted()
+    }
+
+    /// Returns the transmission rate (in bytes per second) between the two last refreshes.
+    ///
+    /// It returns `0.` if there was less than two refreshes or if they happened at the same
+    /// instant.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(500));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh();
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("[{interface_name}] out: {:.2} B/s", network.transmitted_rate());
+    /// }
+    /// ```
+    pub fn transmitted_rate(&self) -> f64 {
+        self.rate(self.transmitted())
+    }
+
+    /// Returns the total number of transmitted bytes.
+    ///
+    /// If you want the amount of transmitted bytes since the last refresh, take a look at the
+    /// [`transmitted`](NetworkData::transmitted) method.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
     ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {} B", network.total_transmitted());
+    /// }
     /// ```
-    /// use sysinfo::{ProcessRefreshKind, UpdateKind};
+    pub fn total_transmitted(&self) -> u64 {
+        self.inner.total_transmitted()
+    }
+
+    /// Returns the number of incoming packets since the last refresh.
     ///
-    /// let r = ProcessRefreshKind::new();
+    /// If you want the total number of packets received, take a look at the
+    /// [`total_packets_received`](NetworkData::total_packets_received) method.
     ///
-    /// assert_eq!(r.cpu(), false);
-    /// assert_eq!(r.user(), UpdateKind::Never);
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh();
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {}", network.packets_received());
+    /// }
     /// ```
-    pub fn new() -> Self {
-        Self::default()
+    pub fn packets_received(&self) -> u64 {
+        self.inner.packets_received()
     }
 
-    /// Creates a new `ProcessRefreshKind` with every refresh set to `true` or
-    /// [`UpdateKind::OnlyIfNotSet`].
+    /// Returns the total number of incoming packets.
     ///
-    /// ```
-    /// use sysinfo::{ProcessRefreshKind, UpdateKind};
+    /// If you want the amount of received packets since the last refresh, take a look at the
+    /// [`packets_received`](NetworkData::packets_received) method.
     ///
-    /// let r = ProcessRefreshKind::everything();
+    /// ```no_run
+    /// use sysinfo::Networks;
     ///
-    /// assert_eq!(r.cpu(), true);
-    /// assert_eq!(r.user(), UpdateKind::OnlyIfNotSet);
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {}", network.total_packets_received());
+    /// }
     /// ```
-    pub fn everything() -> Self {
-        Self {
-            cpu: true,
-            disk_usage: true,
-            memory: true,
-            user: UpdateKind::OnlyIfNotSet,
-            cwd: UpdateKind::OnlyIfNotSet,
-            root: UpdateKind::OnlyIfNotSet,
-            environ: UpdateKind::OnlyIfNotSet,
-            cmd: UpdateKind::OnlyIfNotSet,
-            exe: UpdateKind::OnlyIfNotSet,
-        }
+    pub fn total_packets_received(&self) -> u64 {
+        self.inner.total_packets_received()
     }
 
-    impl_get_set!(ProcessRefreshKind, cpu, with_cpu, without_cpu);
-    impl_get_set!(
-        ProcessRefreshKind,
-        disk_usage,
-        with_disk_usage,
-        without_disk_usage
-    );
-    impl_get_set!(
-        ProcessRefreshKind,
-        user,
-        with_user,
-        without_user,
-        UpdateKind,
-        "\
-It will retrieve the following information:
-
- * user ID
- * user effective ID (if available on the platform)
- * user group ID (if available on the platform)
- * user effective ID (if available on the platform)"
-    );
-    impl_get_set!(ProcessRefreshKind, memory, with_memory, without_memory);
-    impl_get_set!(ProcessRefreshKind, cwd, with_cwd, without_cwd, UpdateKind);
-    impl_get_set!(
-        ProcessRefreshKind,
-        root,
-        with_root,
-        without_root,
-        UpdateKind
-    );
-    impl_get_set!(
-        ProcessRefreshKind,
-        environ,
-        with_environ,
-        without_environ,
-        UpdateKind
-    );
-    impl_get_set!(ProcessRefreshKind, cmd, with_cmd, without_cmd, UpdateKind);
-    impl_get_set!(ProcessRefreshKind, exe, with_exe, without_exe, UpdateKind);
-}
-
-/// Used to determine what you want to refresh specifically on the [`Cpu`] type.
-///
-/// ⚠️ Just like all other refresh types, ruling out a refresh doesn't assure you that
-/// the information won't be retrieved if the information is accessible without needing
-/// extra computation.
-///
-/// ```
-/// use sysinfo::{CpuRefreshKind, System};
-///
-/// let mut system = System::new();
-///
-/// // We don't want to update all the CPU information.
-/// system.refresh_cpu_specifics(CpuRefreshKind::everything().without_frequency());
-///
-/// for cpu in system.cpus() {
-///     assert_eq!(cpu.frequency(), 0);
-/// }
-/// ```
-///
-/// [`Cpu`]: crate::Cpu
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub struct CpuRefreshKind {
-    cpu_usage: bool,
-    frequency: bool,
-}
-
-impl CpuRefreshKind {
-    /// Creates a new `CpuRefreshKind` with every refresh set to `false`.
+    /// Returns the number of outcoming packets since the last refresh.
     ///
-    /// ```
-    /// use sysinfo::CpuRefreshKind;
+    /// If you want the total number of packets transmitted, take a look at the
+    /// [`total_packets_transmitted`](NetworkData::total_packets_transmitted) method.
     ///
-    /// let r = CpuRefreshKind::new();
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
     ///
-    /// assert_eq!(r.frequency(), false);
-    /// assert_eq!(r.cpu_usage(), false);
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh();
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {}", network.packets_transmitted());
+    /// }
     /// ```
-    pub fn new() -> Self {
-        Self::default()
+    pub fn packets_transmitted(&self) -> u64 {
+        self.inner.packets_transmitted()
     }
 
-    /// Creates a new `CpuRefreshKind` with every refresh set to `true`.
+    /// Returns the total number of outcoming packets.
     ///
-    /// ```
-    /// use sysinfo::CpuRefreshKind;
+    /// If you want the amount of transmitted packets since the last refresh, take a look at the
+    /// [`packets_transmitted`](NetworkData::packets_transmitted) method.
     ///
-    /// let r = CpuRefreshKind::everything();
+    /// ```no_run
+    /// use sysinfo::Networks;
     ///
-    /// assert_eq!(r.frequency(), true);
-    /// assert_eq!(r.cpu_usage(), true);
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {}", network.total_packets_transmitted());
+    /// }
     /// ```
-    pub fn everything() -> Self {
-        Self {
-            cpu_usage: true,
-            frequency: true,
-        }
+    pub fn total_packets_transmitted(&self) -> u64 {
+        self.inner.total_packets_transmitted()
     }
 
-    impl_get_set!(CpuRefreshKind, cpu_usage, with_cpu_usage, without_cpu_usage);
-    impl_get_set!(CpuRefreshKind, frequency, with_frequency, without_frequency);
-}
-
-/// Used to determine which memory you want to refresh specifically.
-///
-/// ⚠️ Just like all other refresh types, ruling out a refresh doesn't assure you that
-/// the information won't be retrieved if the information is accessible without needing
-/// extra computation.
-///
-/// ```
-/// use sysinfo::{MemoryRefreshKind, System};
-///
-/// let mut system = System::new();
-///
-/// // We don't want to update all memories information.
-/// system.refresh_memory_specifics(MemoryRefreshKind::new().with_ram());
-///
-/// println!("total RAM: {}", system.total_memory());
-/// println!("free RAM:  {}", system.free_memory());
-/// ```
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub struct MemoryRefreshKind {
-    ram: bool,
-    swap: bool,
-}
+    /// Returns the number of incoming errors since the last refresh.
+    ///
+    /// If you want the total number of errors on received packets, take a look at the
+    /// [`total_errors_on_received`](NetworkData::total_errors_on_received) method.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh();
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {}", network.errors_on_received());
+    /// }
+    /// ```
+    pub fn errors_on_received(&self) -> u64 {
+        self.inner.errors_on_received()
+    }
 
-impl MemoryRefreshKind {
-    /// Creates a new `MemoryRefreshKind` with every refresh set to `false`.
+    /// Returns the total number of incoming errors.
+    ///
+    /// If you want the amount of errors on received packets since the last refresh, take a look at
+    /// the [`errors_on_received`](NetworkData::errors_on_received) method.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
     ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {}", network.total_errors_on_received());
+    /// }
     /// ```
-    /// use sysinfo::MemoryRefreshKind;
+    pub fn total_errors_on_received(&self) -> u64 {
+        self.inner.total_errors_on_received()
+    }
+
+    /// Returns the number of outcoming errors since the last refresh.
     ///
-    /// let r = MemoryRefreshKind::new();
+    /// If you want the total number of errors on transmitted packets, take a look at the
+    /// [`total_errors_on_transmitted`](NetworkData::total_errors_on_transmitted) method.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh();
     ///
-    /// assert_eq!(r.ram(), false);
-    /// assert_eq!(r.swap(), false);
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {}", network.errors_on_transmitted());
+    /// }
     /// ```
-    pub fn new() -> Self {
-        Self::default()
+    pub fn errors_on_transmitted(&self) -> u64 {
+        self.inner.errors_on_transmitted()
     }
 
-    /// Creates a new `MemoryRefreshKind` with every refresh set to `true`.
+    /// Returns the total number of outcoming errors.
     ///
-    /// ```
-    /// use sysinfo::MemoryRefreshKind;
+    /// If you want the amount of errors on transmitted packets since the last refresh, take a look at
+    /// the [`errors_on_transmitted`](NetworkData::errors_on_transmitted) method.
     ///
-    /// let r = MemoryRefreshKind::everything();
+    /// ```no_run
+    /// use sysinfo::Networks;
     ///
-    /// assert_eq!(r.ram(), true);
-    /// assert_eq!(r.swap(), true);
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {}", network.total_errors_on_transmitted());
+    /// }
     /// ```
-    pub fn everything() -> Self {
-        Self {
-            ram: true,
-            swap: true,
-        }
+    pub fn total_errors_on_transmitted(&self) -> u64 {
+        self.inner.total_errors_on_transmitted()
     }
 
-    impl_get_set!(MemoryRefreshKind, ram, with_ram, without_ram);
-    impl_get_set!(MemoryRefreshKind, swap, with_swap, without_swap);
-}
-
-/// Used to determine what you want to refresh specifically on the [`System`][crate::System] type.
-///
-/// ⚠️ Just like all other refresh types, ruling out a refresh doesn't assure you that
-/// the information won't be retrieved if the information is accessible without needing
-/// extra computation.
-///
-/// ```
-/// use sysinfo::{RefreshKind, System};
-///
-/// // We want everything except memory.
-/// let mut system = System::new_with_specifics(RefreshKind::everything().without_memory());
-///
-/// assert_eq!(system.total_memory(), 0);
-/// # if sysinfo::IS_SUPPORTED_SYSTEM && !cfg!(feature = "apple-sandbox") {
-/// assert!(system.processes().len() > 0);
-/// # }
-/// ```
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub struct RefreshKind {
-    processes: Option<ProcessRefreshKind>,
-    memory: Option<MemoryRefreshKind>,
-    cpu: Option<CpuRefreshKind>,
-}
-
-impl RefreshKind {
-    /// Creates a new `RefreshKind` with every refresh set to `false`/`None`.
-    ///
-    /// ```
-    /// use sysinfo::RefreshKind;
+    /// Returns the MAC address associated to current interface.
     ///
-    /// let r = RefreshKind::new();
+    /// ```no_run
+    /// use sysinfo::Networks;
     ///
-    /// assert_eq!(r.processes().is_some(), false);
-    /// assert_eq!(r.memory().is_some(), false);
-    /// assert_eq!(r.cpu().is_some(), false);
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("MAC address: {}", network.mac_address());
+    /// }
     /// ```
-    pub fn new() -> Self {
-        Self::default()
+    pub fn mac_address(&self) -> MacAddr {
+        self.inner.mac_address()
     }
 
-    /// Creates a new `RefreshKind` with every refresh set to `true`/`Some(...)`.
-    ///
-    /// ```
-    /// use sysinfo::RefreshKind;
+    /// Returns the Ip Networks associated to current interface.
     ///
-    /// let r = RefreshKind::everything();
+    /// ```no_run
+    /// use sysinfo::Networks;
     ///
-    /// assert_eq!(r.processes().is_some(), true);
-    /// assert_eq!(r.memory().is_some(), true);
-    /// assert_eq!(r.cpu().is_some(), true);
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("Ip Networks: {:?}", network.ip_networks());
+    /// }
     /// ```
-    pub fn everything() -> Self {
-        Self {
-            processes: Some(ProcessRefreshKind::everything()),
-            memory: Some(MemoryRefreshKind::everything()),
-            cpu: Some(CpuRefreshKind::everything()),
-        }
-    }
-
-    impl_get_set!(
-        RefreshKind,
-        processes,
-        with_processes,
-        without_processes,
-        ProcessRefreshKind
-    );
-    impl_get_set!(
-        RefreshKind,
-        memory,
-        with_memory,
-        without_memory,
-        MemoryRefreshKind
-    );
-    impl_get_set!(RefreshKind, cpu, with_cpu, without_cpu, CpuRefreshKind);
-}
-
-/// Interacting with network interfaces.
-///
-/// ```no_run
-/// use sysinfo::Networks;
-///
-/// let networks = Networks::new_with_refreshed_list();
-/// for (interface_name, network) in &networks {
-///     println!("[{interface_name}]: {network:?}");
-/// }
-/// ```
-pub struct Networks {
-    pub(crate) inner: NetworksInner,
-}
-
-impl<'a> IntoIterator for &'a Networks {
-    type Item = (&'a String, &'a NetworkData);
-    type IntoIter = std::collections::hash_map::Iter<'a, String, NetworkData>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+    pub fn ip_networks(&self) -> &[IpNetwork] {
+        self.inner.ip_networks()
     }
-}
 
-impl Default for Networks {
-    fn default() -> Self {
-        Networks::new()
+    /// Returns the Ip Networks associated to current interface which belong to the given address
+    /// `family`.
+    ///
+    /// ```no_run
+    /// use sysinfo::{AddressFamily, Networks};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     for ip_network in network.ip_networks_by_family(AddressFamily::Ipv6) {
+    ///         println!("[{interface_name}] IPv6 network: {ip_network}");
+    ///     }
+    /// }
+    /// ```
+    pub fn ip_networks_by_family(
+        &self,
+        family: AddressFamily,
+    ) -> impl Iterator<Item = &IpNetwork> + '_ {
+        self.ip_networks()
+            .iter()
+            .filter(move |ip_network| family.contains(&ip_network.addr))
     }
-}
 
-impl Networks {
-    /// Creates a new empty [`Networks`][crate::Networks] type.
+    /// Returns `true` if the interface is administratively enabled (`IFF_UP`).
     ///
-    /// If you want it to be filled directly, take a look at [`Networks::new_with_refreshed_list`].
+    /// Like the addresses, it is only updated by [`Networks::refresh_list`].
+    ///
+    /// ⚠️ It always returns `false` on unsupported platforms.
     ///
     /// ```no_run
     /// use sysinfo::Networks;
     ///
-    /// let mut networks = Networks::new();
-    /// networks.refresh_list();
+    /// let networks = Networks::new_with_refreshed_list();
     /// for (interface_name, network) in &networks {
-    ///     println!("[{interface_name}]: {network:?}");
+    ///     println!("{interface_name} is up: {}", network.is_up());
     /// }
     /// ```
-    pub fn new() -> Self {
-        Self {
-            inner: NetworksInner::new(),
-        }
+    pub fn is_up(&self) -> bool {
+        self.inner.is_up()
     }
 
-    /// Creates a new [`Networks`][crate::Networks] type with the disk list
-    /// loaded. It is a combination of [`Networks::new`] and
-    /// [`Networks::refresh_list`].
+    /// Returns `true` if the interface is a loopback one (like `lo`).
+    ///
+    /// Like the addresses, it is only updated by [`Networks::refresh_list`].
     ///
     /// ```no_run
     /// use sysinfo::Networks;
     ///
     /// let networks = Networks::new_with_refreshed_list();
-    /// for network in &networks {
-    ///     println!("{network:?}");
+    /// // Only keeps the "real" interfaces which are up.
+    /// for (interface_name, _) in networks
+    ///     .iter()
+    ///     .filter(|(_, network)| network.is_up() && !network.is_loopback())
+    /// {
+    ///     println!("{interface_name}");
     /// }
     /// ```
-    pub fn new_with_refreshed_list() -> Self {
-        let mut networks = Self::new();
-        networks.refresh_list();
-        networks
+    pub fn is_loopback(&self) -> bool {
+        self.inner.is_loopback()
     }
 
-    /// Returns the network interfaces map.
+    /// Returns `true` if the interface is operational, meaning it can transmit packets
+    /// (`IFF_RUNNING`, which usually requires a connected link).
+    ///
+    /// Like the addresses, it is only updated by [`Networks::refresh_list`].
+    ///
+    /// ⚠️ On Windows, it returns the same value as [`NetworkData::is_up`].
     ///
     /// ```no_run
     /// use sysinfo::Networks;
     ///
     /// let networks = Networks::new_with_refreshed_list();
-    /// for network in networks.list() {
-    ///     println!("{network:?}");
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name} is running: {}", network.is_running());
     /// }
     /// ```
-    pub fn list(&self) -> &HashMap<String, NetworkData> {
-        self.inner.list()
+    pub fn is_running(&self) -> bool {
+        self.inner.is_running()
     }
 
-    /// Refreshes the network interfaces list.
+    /// Returns the maximum transmission unit (MTU) of the interface, in bytes.
+    ///
+    /// It is updated when calling [`Networks::refresh_list`].
     ///
     /// ```no_run
     /// use sysinfo::Networks;
     ///
-    /// let mut networks = Networks::new();
-    /// networks.refresh_list();
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name}: MTU of {} B", network.mtu());
+    /// }
     /// ```
-    pub fn refresh_list(&mut self) {
-        self.inner.refresh_list()
+    pub fn mtu(&self) -> u64 {
+        self.inner.mtu()
     }
 
-    /// Refreshes the network interfaces' content. If you didn't run [`Networks::refresh_list`]
-    /// before, calling this method won't do anything as no interfaces are present.
-    ///
-    /// ⚠️ If a network interface is added or removed, this method won't take it into account. Use
-    /// [`Networks::refresh_list`] instead.
+    /// Returns the speed of the link of the interface, in bits per second, or `0` if it is
+    /// unknown (which is usually the case for the virtual interfaces).
     ///
-    /// ⚠️ If you didn't call [`Networks::refresh_list`] beforehand, this method will do nothing
-    /// as the network list will be empty.
+    /// It is updated when calling [`Networks::refresh_list`].
     ///
     /// ```no_run
     /// use sysinfo::Networks;
     ///
-    /// let mut networks = Networks::new_with_refreshed_list();
-    /// // Wait some time...? Then refresh the data of each network.
-    /// networks.refresh();
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name}: {} Mbit/s", network.link_speed() / 1_000_000);
+    /// }
     /// ```
-    pub fn refresh(&mut self) {
-        self.inner.refresh()
+    pub fn link_speed(&self) -> u64 {
+        self.inner.link_speed()
     }
-}
-
-impl std::ops::Deref for Networks {
-    type Target = HashMap<String, NetworkData>;
 
-    fn deref(&self) -> &Self::Target {
-        self.list()
+    fn rate(&self, bytes: u64) -> f64 {
+        match self.elapsed {
+            Some(elapsed) if !elapsed.is_zero() => bytes as f64 / elapsed.as_secs_f64(),
+            _ => 0.,
+        }
     }
 }
 
-/// Getting volume of received and transmitted data.
+/// Struct containing a disk information.
 ///
 /// ```no_run
-/// use sysinfo::Networks;
+/// use sysinfo::Disks;
 ///
-/// let networks = Networks::new_with_refreshed_list();
-/// for (interface_name, network) in &networks {
-///     println!("[{interface_name}] {network:?}");
+/// let disks = Disks::new_with_refreshed_list();
+/// for disk in disks.list() {
+///     println!("{:?}: {:?}", disk.name(), disk.kind());
 /// }
 /// ```
-pub struct NetworkData {
-    pub(crate) inner: NetworkDataInner,
+pub struct Disk {
+    pub(crate) inner: crate::DiskInner,
 }
 
-impl NetworkData {
-    /// Returns the number of received bytes since the last refresh.
+impl Disk {
+    /// Returns the kind of disk.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.name(), disk.kind());
+    /// }
+    /// ```
+    pub fn kind(&self) -> DiskKind {
+        self.inner.kind()
+    }
+
+    /// Returns the disk name.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("{:?}", disk.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> &OsStr {
+        self.inner.name()
+    }
+
+    /// Returns the name of the device the disk is mounted from, without the `/dev/` prefix (so
+    /// for example: `disk3s1s1` on macOS or `nvme0n1p2` on Linux). It is `None` if the disk isn't
+    /// mounted from a device (like a ZFS dataset or a tmpfs).
     ///
-    /// If you want the total number of bytes received, take a look at the
-    /// [`total_received`](NetworkData::total_received) method.
+    /// On macOS, it is the BSD name used by `diskutil`.
     ///
-    /// ```no_run
-    /// use sysinfo::Networks;
-    /// use std::{thread, time};
+    /// ⚠️ This method always returns `None` on Windows.
     ///
-    /// let mut networks = Networks::new_with_refreshed_list();
-    /// // Waiting a bit to get data from network...
-    /// thread::sleep(time::Duration::from_millis(10));
-    /// // Refreshing again to generate diff.
-    /// networks.refresh();
+    /// ```no_run
+    /// use sysinfo::Disks;
     ///
-    /// for (interface_name, network) in &networks {
-    ///     println!("in: {} B", network.received());
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("{:?} is mounted from {:?}", disk.mount_point(), disk.device_name());
     /// }
     /// ```
-    pub fn received(&self) -> u64 {
-        self.inner.received()
+    pub fn device_name(&self) -> Option<&OsStr> {
+        self.inner.device_name()
     }
 
-    /// Returns the total number of received bytes.
-    ///
-    /// If you want the amount of received bytes since the last refresh, take a look at the
-    /// [`received`](NetworkData::received) method.
+    /// Returns the file system used on this disk (so for example: `EXT4`, `NTFS`, etc...).
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::Disks;
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("in: {} B", network.total_received());
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.name(), disk.file_system());
     /// }
     /// ```
-    pub fn total_received(&self) -> u64 {
-        self.inner.total_received()
+    pub fn file_system(&self) -> &OsStr {
+        self.inner.file_system()
     }
 
-    /// Returns the number of transmitted bytes since the last refresh.
-    ///
-    /// If you want the total number of bytes transmitted, take a look at the
-    /// [`total_transmitted`](NetworkData::total_transmitted) method.
+    /// Returns the mount point of the disk (`/` for example).
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
-    /// use std::{thread, time};
-    ///
-    /// let mut networks = Networks::new_with_refreshed_list();
-    /// // Waiting a bit to get data from network...
-    /// thread::sleep(time::Duration::from_millis(10));
-    /// // Refreshing again to generate diff.
-    /// networks.refresh();
+    /// use sysinfo::Disks;
     ///
-    /// for (interface_name, network) in &networks {
-    ///     println!("out: {} B", network.transmitted());
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:?}", disk.name(), disk.mount_point());
     /// }
     /// ```
-    pub fn transmitted(&self) -> u64 {
-        self.inner.transmitted()
+    pub fn mount_point(&self) -> &Path {
+        self.inner.mount_point()
     }
 
-    /// Returns the total number of transmitted bytes.
-    ///
-    /// If you want the amount of transmitted bytes since the last refresh, take a look at the
-    /// [`transmitted`](NetworkData::transmitted) method.
+    /// Returns the total disk size, in bytes.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::Disks;
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("out: {} B", network.total_transmitted());
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {}B", disk.name(), disk.total_space());
     /// }
     /// ```
-    pub fn total_transmitted(&self) -> u64 {
-        self.inner.total_transmitted()
+    pub fn total_space(&self) -> u64 {
+        self.inner.total_space()
     }
 
-    /// Returns the number of incoming packets since the last refresh.
-    ///
-    /// If you want the total number of packets received, take a look at the
-    /// [`total_packets_received`](NetworkData::total_packets_received) method.
+    /// Returns the available disk size, in bytes.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
-    /// use std::{thread, time};
-    ///
-    /// let mut networks = Networks::new_with_refreshed_list();
-    /// // Waiting a bit to get data from network...
-    /// thread::sleep(time::Duration::from_millis(10));
-    /// // Refreshing again to generate diff.
-    /// networks.refresh();
+    /// use sysinfo::Disks;
     ///
-    /// for (interface_name, network) in &networks {
-    ///     println!("in: {}", network.packets_received());
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {}B", disk.name(), disk.available_space());
     /// }
     /// ```
-    pub fn packets_received(&self) -> u64 {
-        self.inner.packets_received()
+    pub fn available_space(&self) -> u64 {
+        self.inner.available_space()
     }
 
-    /// Returns the total number of incoming packets.
+    /// Returns whether the available space crossed `threshold` (in bytes) during the last
+    /// refresh of this disk.
     ///
-    /// If you want the amount of received packets since the last refresh, take a look at the
-    /// [`packets_received`](NetworkData::packets_received) method.
+    /// The available space is compared with the one before the last refresh, so if this disk
+    /// wasn't refreshed since it was listed, it always returns [`ThresholdCrossing::NoChange`].
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::{Disks, ThresholdCrossing};
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("in: {}", network.total_packets_received());
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// // We wait some time...?
+    /// disks.refresh();
+    /// for disk in disks.list() {
+    ///     if disk.available_space_crossing(1_000_000_000) == ThresholdCrossing::CrossedBelow {
+    ///         println!("{:?} has less than 1GB left", disk.mount_point());
+    ///     }
     /// }
     /// ```
-    pub fn total_packets_received(&self) -> u64 {
-        self.inner.total_packets_received()
+    pub fn available_space_crossing(&self, threshold: u64) -> ThresholdCrossing {
+        let previous = self.inner.previous_available_space();
+        let current = self.available_space();
+        match (previous >= threshold, current >= threshold) {
+            (true, false) => ThresholdCrossing::CrossedBelow,
+            (false, true) => ThresholdCrossing::CrossedAbove,
+            _ => ThresholdCrossing::NoChange,
+        }
     }
 
-    /// Returns the number of outcoming packets since the last refresh.
+    /// Returns the total number of inodes of the file system.
     ///
-    /// If you want the total number of packets transmitted, take a look at the
-    /// [`total_packets_transmitted`](NetworkData::total_packets_transmitted) method.
+    /// A file system can run out of inodes while still having free space, in which case no new
+    /// file can be created on it.
     ///
-    /// ```no_run
-    /// use sysinfo::Networks;
-    /// use std::{thread, time};
+    /// ⚠️ This method always returns `0` on Windows and for the file systems which don't have a
+    /// fixed number of inodes.
     ///
-    /// let mut networks = Networks::new_with_refreshed_list();
-    /// // Waiting a bit to get data from network...
-    /// thread::sleep(time::Duration::from_millis(10));
-    /// // Refreshing again to generate diff.
-    /// networks.refresh();
+    /// ```no_run
+    /// use sysinfo::Disks;
     ///
-    /// for (interface_name, network) in &networks {
-    ///     println!("out: {}", network.packets_transmitted());
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {} inodes", disk.name(), disk.total_inodes());
     /// }
     /// ```
-    pub fn packets_transmitted(&self) -> u64 {
-        self.inner.packets_transmitted()
+    pub fn total_inodes(&self) -> u64 {
+        self.inner.total_inodes()
     }
 
-    /// Returns the total number of outcoming packets.
+    /// Returns the number of inodes still available on the file system.
     ///
-    /// If you want the amount of transmitted packets since the last refresh, take a look at the
-    /// [`packets_transmitted`](NetworkData::packets_transmitted) method.
+    /// ⚠️ This method always returns `0` on Windows and for the file systems which don't have a
+    /// fixed number of inodes.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::Disks;
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("out: {}", network.total_packets_transmitted());
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!(
+    ///         "[{:?}] {}/{} inodes available",
+    ///         disk.name(),
+    ///         disk.available_inodes(),
+    ///         disk.total_inodes(),
+    ///     );
     /// }
     /// ```
-    pub fn total_packets_transmitted(&self) -> u64 {
-        self.inner.total_packets_transmitted()
+    pub fn available_inodes(&self) -> u64 {
+        self.inner.available_inodes()
     }
 
-    /// Returns the number of incoming errors since the last refresh.
-    ///
-    /// If you want the total number of errors on received packets, take a look at the
-    /// [`total_errors_on_received`](NetworkData::total_errors_on_received) method.
+    /// Returns `true` if the disk is removable.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
-    /// use std::{thread, time};
-    ///
-    /// let mut networks = Networks::new_with_refreshed_list();
-    /// // Waiting a bit to get data from network...
-    /// thread::sleep(time::Duration::from_millis(10));
-    /// // Refreshing again to generate diff.
-    /// networks.refresh();
+    /// use sysinfo::Disks;
     ///
-    /// for (interface_name, network) in &networks {
-    ///     println!("in: {}", network.errors_on_received());
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {}", disk.name(), disk.is_removable());
     /// }
     /// ```
-    pub fn errors_on_received(&self) -> u64 {
-        self.inner.errors_on_received()
+    pub fn is_removable(&self) -> bool {
+        self.inner.is_removable()
     }
 
-    /// Returns the total number of incoming errors.
+    /// Returns `true` if the disk is a network mount (NFS, SMB, AFP...).
     ///
-    /// If you want the amount of errors on received packets since the last refresh, take a look at
-    /// the [`errors_on_received`](NetworkData::errors_on_received) method.
+    /// ⚠️ Network drives are not listed on Windows, so this method always returns `false` there.
+    /// On Linux, they are only listed if the `linux-netdevs` feature is enabled.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::Disks;
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("in: {}", network.total_errors_on_received());
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list().iter().filter(|disk| !disk.is_network()) {
+    ///     println!("[{:?}] local disk", disk.name());
     /// }
     /// ```
-    pub fn total_errors_on_received(&self) -> u64 {
-        self.inner.total_errors_on_received()
+    pub fn is_network(&self) -> bool {
+        self.inner.is_network()
     }
 
-    /// Returns the number of outcoming errors since the last refresh.
-    ///
-    /// If you want the total number of errors on transmitted packets, take a look at the
-    /// [`total_errors_on_transmitted`](NetworkData::total_errors_on_transmitted) method.
+    /// Returns `true` if the disk is the one the system booted from: the one mounted at `/` on
+    /// Unix systems (the APFS system volume on macOS) and the system drive (usually `C:\`) on
+    /// Windows.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
-    /// use std::{thread, time};
-    ///
-    /// let mut networks = Networks::new_with_refreshed_list();
-    /// // Waiting a bit to get data from network...
-    /// thread::sleep(time::Duration::from_millis(10));
-    /// // Refreshing again to generate diff.
-    /// networks.refresh();
+    /// use sysinfo::Disks;
     ///
-    /// for (interface_name, network) in &networks {
-    ///     println!("out: {}", network.errors_on_transmitted());
+    /// let disks = Disks::new_with_refreshed_list();
+    /// if let Some(disk) = disks.list().iter().find(|disk| disk.is_boot_disk()) {
+    ///     println!("{} bytes free on the system disk", disk.available_space());
     /// }
     /// ```
-    pub fn errors_on_transmitted(&self) -> u64 {
-        self.inner.errors_on_transmitted()
+    pub fn is_boot_disk(&self) -> bool {
+        self.inner.is_boot_disk()
     }
 
-    /// Returns the total number of outcoming errors.
-    ///
-    /// If you want the amount of errors on transmitted packets since the last refresh, take a look at
-    /// the [`errors_on_transmitted`](NetworkData::errors_on_transmitted) method.
+    /// Returns `true` if the disk is mounted read-only (like the macOS system volume).
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::Disks;
     ///
-    /// let networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("out: {}", network.total_errors_on_transmitted());
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list().iter().filter(|disk| !disk.is_read_only()) {
+    ///     println!("[{:?}] is writable", disk.mount_point());
     /// }
     /// ```
-    pub fn total_errors_on_transmitted(&self) -> u64 {
-        self.inner.total_errors_on_transmitted()
+    pub fn is_read_only(&self) -> bool {
+        self.inner.is_read_only()
     }
 
-    /// Returns the MAC address associated to current interface.
+    /// Returns `true` if the disk is mounted with the `nosuid` flag, meaning that the set-user-ID
+    /// and set-group-ID bits of its files are ignored.
+    ///
+    /// ⚠️ This method always returns `false` on Windows.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::Disks;
     ///
-    /// let mut networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("MAC address: {}", network.mac_address());
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] nosuid: {}", disk.mount_point(), disk.is_nosuid());
     /// }
     /// ```
-    pub fn mac_address(&self) -> MacAddr {
-        self.inner.mac_address()
+    pub fn is_nosuid(&self) -> bool {
+        self.inner.is_nosuid()
     }
 
-    /// Returns the Ip Networks associated to current interface.
+    /// Returns `true` if the disk is mounted with the `noexec` flag, meaning that its files
+    /// cannot be executed.
+    ///
+    /// ⚠️ This method always returns `false` on Windows.
     ///
     /// ```no_run
-    /// use sysinfo::Networks;
+    /// use sysinfo::Disks;
     ///
-    /// let mut networks = Networks::new_with_refreshed_list();
-    /// for (interface_name, network) in &networks {
-    ///     println!("Ip Networks: {:?}", network.ip_networks());
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] noexec: {}", disk.mount_point(), disk.is_noexec());
     /// }
     /// ```
-    pub fn ip_networks(&self) -> &[IpNetwork] {
-        self.inner.ip_networks()
+    pub fn is_noexec(&self) -> bool {
+        self.inner.is_noexec()
     }
-}
-
-/// Struct containing a disk information.
-///
-/// ```no_run
-/// use sysinfo::Disks;
-///
-/// let disks = Disks::new_with_refreshed_list();
-/// for disk in disks.list() {
-///     println!("{:?}: {:?}", disk.name(), disk.kind());
-/// }
-/// ```
-pub struct Disk {
-    pub(crate) inner: crate::DiskInner,
-}
 
-impl Disk {
-    /// Returns the kind of disk.
+    /// Returns `Some(true)` if the disk is encrypted (with FileVault for example), or `None` if it
+    /// can't be determined for this disk.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS and iOS.
     ///
     /// ```no_run
     /// use sysinfo::Disks;
     ///
     /// let disks = Disks::new_with_refreshed_list();
     /// for disk in disks.list() {
-    ///     println!("[{:?}] {:?}", disk.name(), disk.kind());
+    ///     println!("[{:?}] encrypted: {:?}", disk.name(), disk.is_encrypted());
     /// }
     /// ```
-    pub fn kind(&self) -> DiskKind {
-        self.inner.kind()
+    pub fn is_encrypted(&self) -> Option<bool> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "macos", target_os = "ios"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.is_encrypted()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the disk name.
+    /// Returns `Some(true)` if journaling is enabled on the disk, or `None` if it can't be
+    /// determined for this disk.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS and iOS.
     ///
     /// ```no_run
     /// use sysinfo::Disks;
     ///
     /// let disks = Disks::new_with_refreshed_list();
     /// for disk in disks.list() {
-    ///     println!("{:?}", disk.name());
+    ///     println!("[{:?}] journaled: {:?}", disk.name(), disk.is_journaled());
     /// }
     /// ```
-    pub fn name(&self) -> &OsStr {
-        self.inner.name()
+    pub fn is_journaled(&self) -> Option<bool> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "macos", target_os = "ios"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.is_journaled()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the file system used on this disk (so for example: `EXT4`, `NTFS`, etc...).
+    /// Returns the identifier of the APFS container the disk belongs to (the BSD name of the
+    /// container, like `disk3`), or `None` if the disk isn't an APFS volume.
+    ///
+    /// All the volumes of an APFS container share its free space, so they all report the same
+    /// [`available_space`](Disk::available_space). This identifier can be used to only count it
+    /// once.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS and iOS.
     ///
     /// ```no_run
+    /// use std::collections::HashMap;
     /// use sysinfo::Disks;
     ///
     /// let disks = Disks::new_with_refreshed_list();
+    /// let mut available_per_container = HashMap::new();
     /// for disk in disks.list() {
-    ///     println!("[{:?}] {:?}", disk.name(), disk.file_system());
+    ///     if let Some(container_id) = disk.container_id() {
+    ///         available_per_container.insert(container_id, disk.available_space());
+    ///     }
     /// }
+    /// println!("{:?}", available_per_container);
     /// ```
-    pub fn file_system(&self) -> &OsStr {
-        self.inner.file_system()
+    pub fn container_id(&self) -> Option<&OsStr> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "macos", target_os = "ios"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.container_id()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the mount point of the disk (`/` for example).
+    /// Returns `Some(true)` if the disk is mounted from an APFS snapshot (which is the case of
+    /// the system volume since macOS 11), or `None` if it isn't an APFS volume.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS and iOS.
     ///
     /// ```no_run
     /// use sysinfo::Disks;
     ///
     /// let disks = Disks::new_with_refreshed_list();
     /// for disk in disks.list() {
-    ///     println!("[{:?}] {:?}", disk.name(), disk.mount_point());
+    ///     println!("[{:?}] snapshot: {:?}", disk.name(), disk.is_snapshot());
     /// }
     /// ```
-    pub fn mount_point(&self) -> &Path {
-        self.inner.mount_point()
+    pub fn is_snapshot(&self) -> Option<bool> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "macos", target_os = "ios"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.is_snapshot()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the total disk size, in bytes.
+    /// Returns `Some(true)` if the disk is a sealed APFS volume (the signed system volume, whose
+    /// content is cryptographically verified), or `None` if it isn't an APFS volume or if the
+    /// system doesn't support sealed volumes.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than macOS and iOS.
     ///
     /// ```no_run
     /// use sysinfo::Disks;
     ///
     /// let disks = Disks::new_with_refreshed_list();
-    /// for disk in disks.list() {
-    ///     println!("[{:?}] {}B", disk.name(), disk.total_space());
+    /// if let Some(disk) = disks.list().iter().find(|disk| disk.is_boot_disk()) {
+    ///     println!("the boot volume is sealed: {:?}", disk.is_sealed());
     /// }
     /// ```
-    pub fn total_space(&self) -> u64 {
-        self.inner.total_space()
+    pub fn is_sealed(&self) -> Option<bool> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "macos", target_os = "ios"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.is_sealed()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the available disk size, in bytes.
+    /// Updates the disk' information.
     ///
     /// ```no_run
     /// use sysinfo::Disks;
     ///
-    /// let disks = Disks::new_with_refreshed_list();
-    /// for disk in disks.list() {
-    ///     println!("[{:?}] {}B", disk.name(), disk.available_space());
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list_mut() {
+    ///     disk.refresh();
     /// }
     /// ```
-    pub fn available_space(&self) -> u64 {
-        self.inner.available_space()
+    pub fn refresh(&mut self) -> bool {
+        self.inner.refresh()
     }
 
-    /// Returns `true` if the disk is removable.
+    /// Updates the disk' information like [`Disk::refresh`], but gives up if it takes more
+    /// than `timeout` (which can happen with an unresponsive network mount). In this case, the
+    /// disk is marked as timed out (see [`Disk::is_timed_out`]) and keeps its previous
+    /// information.
+    ///
+    /// The information is retrieved on a separate thread which is left running if it times out.
+    /// In this case, the next calls wait for this thread to complete instead of starting a new
+    /// one.
     ///
     /// ```no_run
+    /// use std::time::Duration;
     /// use sysinfo::Disks;
     ///
-    /// let disks = Disks::new_with_refreshed_list();
-    /// for disk in disks.list() {
-    ///     println!("[{:?}] {}", disk.name(), disk.is_removable());
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list_mut() {
+    ///     if !disk.refresh_with_timeout(Duration::from_secs(1)) && disk.is_timed_out() {
+    ///         println!("{:?} isn't responding", disk.mount_point());
+    ///     }
     /// }
     /// ```
-    pub fn is_removable(&self) -> bool {
-        self.inner.is_removable()
+    pub fn refresh_with_timeout(&mut self, timeout: Duration) -> bool {
+        self.inner.start_refresh();
+        self.inner.wait_refresh(Instant::now() + timeout)
     }
 
-    /// Updates the disk' information.
+    /// Returns `true` if the last [`Disk::refresh_with_timeout`] (or
+    /// [`Disks::refresh_with_timeout`]) call on this disk didn't complete in time. Its information
+    /// is then the one from the refresh before.
     ///
     /// ```no_run
+    /// use std::time::Duration;
     /// use sysinfo::Disks;
     ///
     /// let mut disks = Disks::new_with_refreshed_list();
-    /// for disk in disks.list_mut() {
-    ///     disk.refresh();
+    /// disks.refresh_with_timeout(Duration::from_secs(1));
+    /// for disk in disks.list().iter().filter(|disk| disk.is_timed_out()) {
+    ///     println!("{:?} isn't responding", disk.mount_point());
     /// }
     /// ```
-    pub fn refresh(&mut self) -> bool {
-        self.inner.refresh()
+    pub fn is_timed_out(&self) -> bool {
+        self.inner.is_timed_out()
     }
 }
 
@@ -2645,6 +5656,128 @@ impl Disks {
         }
     }
 
+    /// Refreshes the listed disks' information like [`Disks::refresh`], but skips the disks
+    /// which don't respond within `timeout` instead of blocking on them. The skipped disks are
+    /// kept in the list and marked as timed out (see [`Disk::is_timed_out`]).
+    ///
+    /// The disks are refreshed in parallel, so this method doesn't take more than `timeout`,
+    /// whatever the number of unresponsive disks.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use sysinfo::Disks;
+    ///
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// disks.refresh_with_timeout(Duration::from_millis(500));
+    /// ```
+    pub fn refresh_with_timeout(&mut self, timeout: Duration) {
+        let deadline = Instant::now() + timeout;
+        for disk in self.list_mut() {
+            disk.inner.start_refresh();
+        }
+        for disk in self.list_mut() {
+            disk.inner.wait_refresh(deadline);
+        }
+    }
+
+    /// Refreshes the information of the disk mounted on `mount_point` only, which prevents
+    /// querying all the other mounted volumes (which can block on slow network mounts).
+    ///
+    /// Returns `false` if no listed disk is mounted on `mount_point` or if its information
+    /// couldn't be refreshed.
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use sysinfo::Disks;
+    ///
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// // We wait some time...?
+    /// if disks.refresh_disk(Path::new("/")) {
+    ///     println!("refreshed the root disk");
+    /// }
+    /// ```
+    pub fn refresh_disk(&mut self, mount_point: &Path) -> bool {
+        self.list_mut()
+            .iter_mut()
+            .find(|disk| disk.mount_point() == mount_point)
+            .is_some_and(|disk| disk.refresh())
+    }
+
+    /// Returns whether the available space of the disk mounted on `mount_point` crossed
+    /// `threshold` (in bytes) during the last refresh, or `None` if no listed disk is mounted on
+    /// `mount_point`.
+    ///
+    /// Take a look at [`Disk::available_space_crossing`] for more information.
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use sysinfo::{Disks, ThresholdCrossing};
+    ///
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// // We wait some time...?
+    /// disks.refresh_disk(Path::new("/"));
+    /// if disks.available_space_crossing(Path::new("/"), 1_000_000_000)
+    ///     == Some(ThresholdCrossing::CrossedBelow)
+    /// {
+    ///     println!("less than 1GB left on the root disk");
+    /// }
+    /// ```
+    pub fn available_space_crossing(
+        &self,
+        mount_point: &Path,
+        threshold: u64,
+    ) -> Option<ThresholdCrossing> {
+        self.list()
+            .iter()
+            .find(|disk| disk.mount_point() == mount_point)
+            .map(|disk| disk.available_space_crossing(threshold))
+    }
+
+    /// Returns the disk `path` is stored on, or `None` if it couldn't be found (if `path` doesn't
+    /// exist or if its disk isn't listed).
+    ///
+    /// On macOS, the firmlinked paths (like `/Users` or `/Applications`) are attributed to the
+    /// data volume they are actually stored on (mounted on `/System/Volumes/Data`) and not to
+    /// the system volume mounted on `/`.
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// if let Some(disk) = disks.disk_for_path(Path::new("/home")) {
+    ///     println!("/home is on {:?}", disk.name());
+    /// }
+    /// ```
+    pub fn disk_for_path(&self, path: &Path) -> Option<&Disk> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                not(feature = "unknown-ci"),
+                any(target_os = "freebsd", target_os = "macos", target_os = "ios")
+            ))] {
+                let mount_point = crate::unix::utils::get_mount_point_of(path);
+                if let Some(disk) = self
+                    .list()
+                    .iter()
+                    .find(|disk| Some(disk.mount_point()) == mount_point.as_deref())
+                {
+                    return Some(disk);
+                }
+            }
+        }
+        // Symlinks are resolved so they are attributed to the disk of their target.
+        let path = path.canonicalize().ok()?;
+        #[cfg(windows)]
+        let path = match path.to_str().and_then(|path| path.strip_prefix(r"\\?\")) {
+            Some(path) => std::path::PathBuf::from(path),
+            None => path,
+        };
+        self.list()
+            .iter()
+            .filter(|disk| path.starts_with(disk.mount_point()))
+            .max_by_key(|disk| disk.mount_point().as_os_str().len())
+    }
+
     /// The disk list will be emptied then completely recomputed.
     ///
     /// ## Linux
@@ -2657,6 +5790,12 @@ impl Disks {
     /// requiring to call `systemctl stop` to terminate the NFS service from the remote
     /// server in some cases.
     ///
+    /// ## macOS
+    ///
+    /// The disk kind is retrieved from IOKit, which requires to walk its registry tree. To keep
+    /// it reasonably fast on systems with a lot of APFS volumes and snapshots, it is only done
+    /// once per physical disk and only by this method, never by [`Disks::refresh`].
+    ///
     /// ```no_run
     /// use sysinfo::Disks;
     ///
@@ -2664,8 +5803,127 @@ impl Disks {
     /// disks.refresh_list();
     /// ```
     pub fn refresh_list(&mut self) {
+        self.inner.list_timeout_runner.reset();
         self.inner.refresh_list();
     }
+
+    /// Same as [`Disks::refresh_list`], but gives up if it takes more than `timeout` (listing
+    /// the disks can block on an unresponsive network mount). In this case, the previous list is
+    /// kept and `false` is returned.
+    ///
+    /// The list is retrieved on a separate thread which is left running if it times out. In this
+    /// case, the next calls wait for this thread to complete instead of starting a new one.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use sysinfo::Disks;
+    ///
+    /// let mut disks = Disks::new();
+    /// if !disks.refresh_list_with_timeout(Duration::from_secs(1)) {
+    ///     println!("the disks list couldn't be retrieved in time");
+    /// }
+    /// ```
+    pub fn refresh_list_with_timeout(&mut self, timeout: Duration) -> bool {
+        let disks = self.inner.list_timeout_runner.run(timeout, || {
+            let mut disks = Disks::new();
+            disks.refresh_list();
+            Vec::from(disks)
+        });
+        match disks {
+            Some(disks) => {
+                self.inner.disks = disks;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Same as [`Disks::refresh_list`] but returns the mount points of the disks which were
+    /// mounted and unmounted since the previous list was retrieved.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// // We wait some time...?
+    /// let diff = disks.refresh_list_diff();
+    /// for mount_point in &diff.added {
+    ///     println!("new disk mounted on {mount_point:?}");
+    /// }
+    /// for mount_point in &diff.removed {
+    ///     println!("disk mounted on {mount_point:?} was removed");
+    /// }
+    /// ```
+    pub fn refresh_list_diff(&mut self) -> DiskListDiff {
+        let previous = self
+            .list()
+            .iter()
+            .map(|disk| disk.mount_point().to_path_buf())
+            .collect::<HashSet<_>>();
+        self.refresh_list();
+        let current = self
+            .list()
+            .iter()
+            .map(|disk| disk.mount_point().to_path_buf())
+            .collect::<HashSet<_>>();
+        DiskListDiff {
+            added: current.difference(&previous).cloned().collect(),
+            removed: previous.difference(&current).cloned().collect(),
+        }
+    }
+
+    /// Returns the information of the disks as a flat list of named metrics, like
+    /// [`System::export_metrics`].
+    ///
+    /// The metrics are named as follows (`<mount point>` being the mount point of the disk in
+    /// which every character other than an ASCII alphanumeric or `-` is replaced with `_`,
+    /// without the leading and trailing `_`, like `mnt_data` for `/mnt/data` and `root` for
+    /// `/`):
+    ///
+    /// | Name                                  | Value                               |
+    /// |---------------------------------------|-------------------------------------|
+    /// | `disk.<mount point>.total`            | [`Disk::total_space`], in bytes     |
+    /// | `disk.<mount point>.available`        | [`Disk::available_space`], in bytes |
+    /// | `disk.<mount point>.inodes_total`     | [`Disk::total_inodes`]              |
+    /// | `disk.<mount point>.inodes_available` | [`Disk::available_inodes`]          |
+    ///
+    /// If two mount points end up with the same name (like `/mnt/data` and `/mnt_data`), a `_2`
+    /// (then `_3`, ...) suffix is added to the name of the following ones.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for (name, value) in disks.export_metrics() {
+    ///     println!("{name}:{value}|g");
+    /// }
+    /// ```
+    pub fn export_metrics(&self) -> Vec<(String, f64)> {
+        let mut metrics = Vec::with_capacity(self.list().len() * 4);
+        let mut names = crate::utils::MetricNameComponents::default();
+        for disk in self.list() {
+            let mount_point = names.get(&disk.mount_point().to_string_lossy());
+            metrics.extend([
+                (
+                    format!("disk.{mount_point}.total"),
+                    disk.total_space() as f64,
+                ),
+                (
+                    format!("disk.{mount_point}.available"),
+                    disk.available_space() as f64,
+                ),
+                (
+                    format!("disk.{mount_point}.inodes_total"),
+                    disk.total_inodes() as f64,
+                ),
+                (
+                    format!("disk.{mount_point}.inodes_available"),
+                    disk.available_inodes() as f64,
+                ),
+            ]);
+        }
+        metrics
+    }
 }
 
 impl std::ops::Deref for Disks {
@@ -2714,6 +5972,33 @@ impl fmt::Display for DiskKind {
     }
 }
 
+/// Tells if a value crossed a threshold between two refreshes.
+///
+/// It is returned by [`Disk::available_space_crossing`].
+///
+/// ```no_run
+/// use sysinfo::{Disks, ThresholdCrossing};
+///
+/// let mut disks = Disks::new_with_refreshed_list();
+/// disks.refresh();
+/// for disk in disks.list() {
+///     match disk.available_space_crossing(10_000_000_000) {
+///         ThresholdCrossing::CrossedBelow => println!("{:?} is getting full", disk.name()),
+///         ThresholdCrossing::CrossedAbove => println!("{:?} has room again", disk.name()),
+///         ThresholdCrossing::NoChange => {}
+///     }
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ThresholdCrossing {
+    /// The value stayed on the same side of the threshold.
+    NoChange,
+    /// The value went from above (or equal to) the threshold to below it.
+    CrossedBelow,
+    /// The value went from below the threshold to above (or equal to) it.
+    CrossedAbove,
+}
+
 /// Interacting with users.
 ///
 /// ```no_run
@@ -3153,32 +6438,244 @@ pub struct CGroupLimits {
     pub free_swap: u64,
 }
 
-/// A struct representing system load average value.
+/// A file (or device) used to store the SWAP.
+///
+/// It is returned by [`System::swap_files`][crate::System::swap_files].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new();
+/// for swap_file in s.swap_files() {
+///     println!("{:?}: {} bytes", swap_file.path, swap_file.size);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwapFile {
+    /// Path of the swap file (or device).
+    pub path: PathBuf,
+    /// Size (in bytes) of the swap file (or device).
+    pub size: u64,
+}
+
+/// Number of errors detected by the ECC memory controller since boot.
+///
+/// It is returned by [`System::memory_ecc_errors`][crate::System::memory_ecc_errors].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// if let Some(errors) = System::memory_ecc_errors() {
+///     if errors.uncorrectable != 0 {
+///         println!("{} uncorrectable memory errors!", errors.uncorrectable);
+///     }
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryEccErrors {
+    /// Number of errors which were corrected by the ECC.
+    pub correctable: u64,
+    /// Number of errors which couldn't be corrected.
+    pub uncorrectable: u64,
+}
+
+/// A struct representing system load average value.
+///
+/// It is returned by [`System::load_average`][crate::System::load_average].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let load_avg = System::load_average();
+/// println!(
+///     "one minute: {}%, five minutes: {}%, fifteen minutes: {}%",
+///     load_avg.one,
+///     load_avg.five,
+///     load_avg.fifteen,
+/// );
+/// ```
+#[repr(C)]
+#[derive(Default, Debug, Clone)]
+pub struct LoadAvg {
+    /// Average load within one minute.
+    pub one: f64,
+    /// Average load within five minutes.
+    pub five: f64,
+    /// Average load within fifteen minutes.
+    pub fifteen: f64,
+}
+
+/// Thermal pressure of the system, from the least to the most severe.
+///
+/// It is returned by [`System::thermal_pressure`][crate::System::thermal_pressure].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// println!("thermal pressure: {:?}", System::thermal_pressure());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ThermalPressure {
+    /// The system isn't under any thermal pressure.
+    Nominal,
+    /// The thermal pressure is slightly elevated.
+    Fair,
+    /// The thermal pressure is high, the system is reducing its performance.
+    Serious,
+    /// The thermal pressure is significantly impacting the performance of the system.
+    Critical,
+}
+
+/// Memory pressure of the system, from the least to the most severe.
+///
+/// It is given to the callback of
+/// [`System::subscribe_memory_pressure`][crate::System::subscribe_memory_pressure].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let _subscription = System::subscribe_memory_pressure(|pressure| {
+///     println!("memory pressure: {pressure:?}");
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MemoryPressure {
+    /// The system has enough memory available.
+    Normal,
+    /// The system is running low on memory and is starting to reclaim it.
+    Warn,
+    /// The system is critically low on memory, processes should free as much memory as
+    /// possible.
+    Critical,
+}
+
+/// Keeps the callback given to
+/// [`System::subscribe_memory_pressure`][crate::System::subscribe_memory_pressure] registered.
+///
+/// The callback won't be called anymore once this is dropped.
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let subscription = System::subscribe_memory_pressure(|pressure| {
+///     println!("memory pressure: {pressure:?}");
+/// });
+/// // Stop receiving the notifications.
+/// drop(subscription);
+/// ```
+// Its `inner` field only exists on Apple targets, so this prevents it from being built outside of
+// this crate on the other ones.
+#[non_exhaustive]
+pub struct MemoryPressureSubscription {
+    #[cfg(all(
+        any(target_os = "macos", target_os = "ios"),
+        not(feature = "unknown-ci")
+    ))]
+    #[allow(dead_code)]
+    inner: crate::sys::system::MemoryPressureSubscriptionInner,
+}
+
+/// Source the system is drawing its power from.
 ///
-/// It is returned by [`System::load_average`][crate::System::load_average].
+/// It is returned by [`System::power_source`][crate::System::power_source].
 ///
 /// ```no_run
 /// use sysinfo::System;
 ///
-/// let load_avg = System::load_average();
-/// println!(
-///     "one minute: {}%, five minutes: {}%, fifteen minutes: {}%",
-///     load_avg.one,
-///     load_avg.five,
-///     load_avg.fifteen,
-/// );
+/// println!("power source: {:?}", System::power_source());
 /// ```
-#[repr(C)]
-#[derive(Default, Debug, Clone)]
-pub struct LoadAvg {
-    /// Average load within one minute.
-    pub one: f64,
-    /// Average load within five minutes.
-    pub five: f64,
-    /// Average load within fifteen minutes.
-    pub fifteen: f64,
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PowerSource {
+    /// The system is plugged in.
+    Ac,
+    /// The system is running on its battery.
+    Battery,
+    /// The system is running on an uninterruptible power supply.
+    Ups,
+    /// The power source couldn't be determined.
+    Unknown,
+}
+
+/// System subsystem which can fail to be initialized.
+///
+/// It is listed by [`InitError::failed_subsystems`].
+///
+/// ```no_run
+/// use sysinfo::{RefreshKind, Subsystem, System};
+///
+/// if let Err(error) = System::try_new_with_specifics(RefreshKind::new()) {
+///     if error.failed_subsystems().contains(&Subsystem::HostPort) {
+///         eprintln!("no memory nor CPU information");
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Subsystem {
+    /// The host port (`mach_host_self`) couldn't be retrieved, so the memory and CPU information
+    /// won't be available.
+    HostPort,
+    /// The CPU ticks (`host_processor_info`) couldn't be retrieved, so the processes CPU usage
+    /// will be computed with a less accurate fallback method.
+    CpuTimeInfo,
+    /// The connection to the SMC (System Management Controller) couldn't be opened, so the
+    /// temperatures (on Intel CPUs) and the power consumption won't be available.
+    Smc,
+    /// The IOKit registry couldn't be queried, so the information retrieved from it (like the
+    /// disks kind or the GPU usage) won't be available.
+    IoKit,
+}
+
+impl fmt::Display for Subsystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::HostPort => "host port",
+            Self::CpuTimeInfo => "CPU ticks information",
+            Self::Smc => "SMC connection",
+            Self::IoKit => "IOKit registry",
+        })
+    }
+}
+
+/// Error returned by [`System::try_new_with_specifics`][crate::System::try_new_with_specifics]
+/// when some system subsystems failed to be initialized.
+///
+/// ```no_run
+/// use sysinfo::{RefreshKind, System};
+///
+/// if let Err(error) = System::try_new_with_specifics(RefreshKind::new()) {
+///     for subsystem in error.failed_subsystems() {
+///         eprintln!("failed to initialize: {subsystem}");
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitError {
+    pub(crate) failed_subsystems: Vec<Subsystem>,
 }
 
+impl InitError {
+    /// Returns the subsystems which failed to be initialized. It is never empty.
+    pub fn failed_subsystems(&self) -> &[Subsystem] {
+        &self.failed_subsystems
+    }
+}
+
+impl fmt::Display for InitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("failed to initialize the ")?;
+        for (pos, subsystem) in self.failed_subsystems.iter().enumerate() {
+            if pos != 0 {
+                f.write_str(", ")?;
+            }
+            subsystem.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InitError {}
+
 macro_rules! xid {
     ($(#[$outer:meta])+ $name:ident, $type:ty $(, $trait:ty)?) => {
         $(#[$outer])+
@@ -3370,110 +6867,425 @@ impl User {
     pub fn groups(&self) -> Vec<Group> {
         self.inner.groups()
     }
+
+    /// Returns the home directory of the user, or `None` if it isn't set.
+    ///
+    /// ⚠️ This method always returns `None` on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     println!("{}: {:?}", user.name(), user.home_directory());
+    /// }
+    /// ```
+    pub fn home_directory(&self) -> Option<&Path> {
+        self.inner.home_directory()
+    }
+
+    /// Returns the login shell of the user, or `None` if it isn't set.
+    ///
+    /// ⚠️ This method always returns `None` on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System, Users};
+    ///
+    /// let s = System::new_all();
+    /// let users = Users::new_with_refreshed_list();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(user) = process.user_id().and_then(|id| users.get_user_by_id(id)) {
+    ///         println!("{:?}", user.shell());
+    ///     }
+    /// }
+    /// ```
+    pub fn shell(&self) -> Option<&Path> {
+        self.inner.shell()
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub(crate) struct GroupInner {
+    pub(crate) id: Gid,
+    pub(crate) name: String,
+}
+
+/// Type containing group information.
+///
+/// It is returned by [`User::groups`] or [`Groups::list`].
+///
+/// ```no_run
+/// use sysinfo::Users;
+///
+/// let mut users = Users::new_with_refreshed_list();
+///
+/// for user in users.list() {
+///     println!(
+///         "user: (ID: {:?}, group ID: {:?}, name: {:?})",
+///         user.id(),
+///         user.group_id(),
+///         user.name(),
+///     );
+///     for group in user.groups() {
+///         println!("group: (ID: {:?}, name: {:?})", group.id(), group.name());
+///     }
+/// }
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Group {
+    pub(crate) inner: GroupInner,
+}
+
+impl Group {
+    /// Returns the ID of the group.
+    ///
+    /// ⚠️ This information is not set on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let mut users = Users::new_with_refreshed_list();
+    ///
+    /// for user in users.list() {
+    ///     for group in user.groups() {
+    ///         println!("{:?}", group.id());
+    ///     }
+    /// }
+    /// ```
+    pub fn id(&self) -> &Gid {
+        self.inner.id()
+    }
+
+    /// Returns the name of the group.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let mut users = Users::new_with_refreshed_list();
+    ///
+    /// for user in users.list() {
+    ///     for group in user.groups() {
+    ///         println!("{}", group.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn name(&self) -> &str {
+        self.inner.name()
+    }
+}
+
+/// Type containing read and written bytes.
+///
+/// It is returned by [`Process::disk_usage`][crate::Process::disk_usage].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// for (pid, process) in s.processes() {
+///     let disk_usage = process.disk_usage();
+///     println!("[{}] read bytes   : new/total => {}/{} B",
+///         pid,
+///         disk_usage.read_bytes,
+///         disk_usage.total_read_bytes,
+///     );
+///     println!("[{}] written bytes: new/total => {}/{} B",
+///         pid,
+///         disk_usage.written_bytes,
+///         disk_usage.total_written_bytes,
+///     );
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd)]
+pub struct DiskUsage {
+    /// Total number of written bytes since the process was started.
+    pub total_written_bytes: u64,
+    /// Number of written bytes since the last refresh.
+    pub written_bytes: u64,
+    /// Total number of read bytes since the process was started.
+    pub total_read_bytes: u64,
+    /// Number of read bytes since the last refresh.
+    pub read_bytes: u64,
+}
+
+/// Transport protocol of a [`Connection`].
+///
+/// ```no_run
+/// use sysinfo::{ConnectionProtocol, Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     for connection in process.connections() {
+///         if connection.protocol == ConnectionProtocol::Tcp {
+///             println!("{:?}", connection.local_address);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionProtocol {
+    /// Transmission Control Protocol.
+    Tcp,
+    /// User Datagram Protocol.
+    Udp,
+}
+
+/// Socket opened by a process.
+///
+/// It is returned by [`Process::connections`][crate::Process::connections].
+///
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     for connection in process.connections() {
+///         println!("{:?} -> {:?}", connection.local_address, connection.remote_address);
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Connection {
+    /// Protocol used by the socket.
+    pub protocol: ConnectionProtocol,
+    /// Local address of the socket.
+    pub local_address: SocketAddr,
+    /// Remote address of the socket, `None` if it isn't connected (like listening sockets).
+    pub remote_address: Option<SocketAddr>,
+    /// `true` if it is an established TCP connection.
+    pub is_established: bool,
+}
+
+/// Number of file descriptors opened by a process, by type.
+///
+/// It is returned by [`Process::fd_breakdown`][crate::Process::fd_breakdown].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// for (pid, process) in s.processes() {
+///     let fds = process.fd_breakdown();
+///     println!("[{pid}] files: {}, sockets: {}", fds.files, fds.sockets);
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FdBreakdown {
+    /// Number of files, directories and devices.
+    pub files: u32,
+    /// Number of sockets.
+    pub sockets: u32,
+    /// Number of pipes.
+    pub pipes: u32,
+    /// Number of kernel event queues.
+    pub kqueues: u32,
+    /// Number of file descriptors of any other type (like shared memory or semaphores).
+    pub others: u32,
+}
+
+impl FdBreakdown {
+    /// Returns the total number of file descriptors.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{} file descriptors", process.fd_breakdown().total());
+    /// }
+    /// ```
+    pub fn total(&self) -> u32 {
+        self.files
+            .saturating_add(self.sockets)
+            .saturating_add(self.pipes)
+            .saturating_add(self.kqueues)
+            .saturating_add(self.others)
+    }
+}
+
+/// Number of times a process woke up the CPU.
+///
+/// It is returned by [`Process::wakeups`][crate::Process::wakeups].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// for (pid, process) in s.processes() {
+///     if let Some(wakeups) = process.wakeups() {
+///         println!("[{pid}] idle wakeups: new/total => {}/{}", wakeups.idle, wakeups.total_idle);
+///     }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Wakeups {
+    /// Total number of wakeups from the package idle state.
+    pub total_idle: u64,
+    /// Number of wakeups from the package idle state since the last refresh.
+    pub idle: u64,
+    /// Total number of wakeups caused by interrupts.
+    pub total_interrupt: u64,
+    /// Number of wakeups caused by interrupts since the last refresh.
+    pub interrupt: u64,
+}
+
+/// Number of page faults of a process.
+///
+/// It is returned by [`Process::page_faults`][crate::Process::page_faults].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// for (pid, process) in s.processes() {
+///     if let Some(faults) = process.page_faults() {
+///         println!("[{pid}] major faults: new/total => {}/{}", faults.major, faults.total_major);
+///     }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PageFaults {
+    /// Total number of minor page faults (which didn't require to read from the disk).
+    pub total_minor: u64,
+    /// Number of minor page faults since the last refresh.
+    pub minor: u64,
+    /// Total number of major page faults (which required to read from the disk).
+    pub total_major: u64,
+    /// Number of major page faults since the last refresh.
+    pub major: u64,
+}
+
+impl PageFaults {
+    /// Creates a new `PageFaults` from the new totals, computing the differences with the
+    /// `previous` ones.
+    #[cfg(feature = "processes")]
+    #[cfg_attr(
+        any(
+            feature = "unknown-ci",
+            not(any(
+                target_os = "macos",
+                target_os = "linux",
+                target_os = "android",
+                target_os = "freebsd",
+                windows,
+            )),
+            all(target_os = "macos", feature = "apple-sandbox"),
+        ),
+        allow(dead_code)
+    )]
+    pub(crate) fn new(previous: Option<Self>, total_minor: u64, total_major: u64) -> Self {
+        let previous = previous.unwrap_or_default();
+        Self {
+            total_minor,
+            minor: total_minor.saturating_sub(previous.total_minor),
+            total_major,
+            major: total_major.saturating_sub(previous.total_major),
+        }
+    }
 }
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
-pub(crate) struct GroupInner {
-    pub(crate) id: Gid,
-    pub(crate) name: String,
+/// Processes which appeared and exited between two refreshes.
+///
+/// It is returned by [`System::refresh_processes_diff`][crate::System::refresh_processes_diff].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let mut s = System::new_all();
+/// let diff = s.refresh_processes_diff();
+/// println!("{} new processes, {} exited", diff.added.len(), diff.exited.len());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ProcessDiff {
+    /// PIDs of the processes which appeared since the last refresh.
+    pub added: Vec<Pid>,
+    /// Processes which exited since the last refresh, as they were before being removed.
+    ///
+    /// [`Process::first_seen`] and [`Process::last_seen`] tell for how long they were observed.
+    pub exited: Vec<Process>,
 }
 
-/// Type containing group information.
+/// Disks which were mounted and unmounted between two retrievals of the disk list.
 ///
-/// It is returned by [`User::groups`] or [`Groups::list`].
+/// It is returned by [`Disks::refresh_list_diff`][crate::Disks::refresh_list_diff].
 ///
 /// ```no_run
-/// use sysinfo::Users;
+/// use sysinfo::Disks;
 ///
-/// let mut users = Users::new_with_refreshed_list();
+/// let mut disks = Disks::new_with_refreshed_list();
+/// let diff = disks.refresh_list_diff();
+/// println!("{} new disks, {} removed", diff.added.len(), diff.removed.len());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DiskListDiff {
+    /// Mount points of the disks which appeared since the previous list.
+    pub added: Vec<PathBuf>,
+    /// Mount points of the disks which are not in the list anymore.
+    pub removed: Vec<PathBuf>,
+}
+
+/// Breakdown of the memory used by a process.
 ///
-/// for user in users.list() {
-///     println!(
-///         "user: (ID: {:?}, group ID: {:?}, name: {:?})",
-///         user.id(),
-///         user.group_id(),
-///         user.name(),
-///     );
-///     for group in user.groups() {
-///         println!("group: (ID: {:?}, name: {:?})", group.id(), group.name());
+/// It is returned by [`Process::memory_regions`][crate::Process::memory_regions].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// for (pid, process) in s.processes() {
+///     if let Some(regions) = process.memory_regions() {
+///         println!(
+///             "[{pid}] resident: {} B, private: {} B, shared: {} B, compressed: {} B",
+///             regions.resident,
+///             regions.private,
+///             regions.shared,
+///             regions.compressed,
+///         );
 ///     }
 /// }
 /// ```
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
-pub struct Group {
-    pub(crate) inner: GroupInner,
-}
-
-impl Group {
-    /// Returns the ID of the group.
-    ///
-    /// ⚠️ This information is not set on Windows.
-    ///
-    /// ```no_run
-    /// use sysinfo::Users;
-    ///
-    /// let mut users = Users::new_with_refreshed_list();
-    ///
-    /// for user in users.list() {
-    ///     for group in user.groups() {
-    ///         println!("{:?}", group.id());
-    ///     }
-    /// }
-    /// ```
-    pub fn id(&self) -> &Gid {
-        self.inner.id()
-    }
-
-    /// Returns the name of the group.
-    ///
-    /// ```no_run
-    /// use sysinfo::Users;
-    ///
-    /// let mut users = Users::new_with_refreshed_list();
-    ///
-    /// for user in users.list() {
-    ///     for group in user.groups() {
-    ///         println!("{}", group.name());
-    ///     }
-    /// }
-    /// ```
-    pub fn name(&self) -> &str {
-        self.inner.name()
-    }
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryRegions {
+    /// Memory currently resident in RAM.
+    pub resident: u64,
+    /// Memory backed by files, which can be shared with other processes (like the mapped
+    /// frameworks and libraries).
+    pub shared: u64,
+    /// Anonymous memory only owned by this process.
+    pub private: u64,
+    /// Memory which was compressed by the system.
+    pub compressed: u64,
 }
 
-/// Type containing read and written bytes.
+/// Disk I/O policy of a process, returned by [`Process::io_policy`].
 ///
-/// It is returned by [`Process::disk_usage`][crate::Process::disk_usage].
+/// The processes with a lower policy get throttled when they contend for the disk with
+/// processes with a higher one.
 ///
 /// ```no_run
-/// use sysinfo::System;
+/// use sysinfo::{get_current_pid, IoPolicy, System};
 ///
 /// let s = System::new_all();
-/// for (pid, process) in s.processes() {
-///     let disk_usage = process.disk_usage();
-///     println!("[{}] read bytes   : new/total => {}/{} B",
-///         pid,
-///         disk_usage.read_bytes,
-///         disk_usage.total_read_bytes,
-///     );
-///     println!("[{}] written bytes: new/total => {}/{} B",
-///         pid,
-///         disk_usage.written_bytes,
-///         disk_usage.total_written_bytes,
-///     );
+/// if let Some(process) = s.process(get_current_pid().unwrap()) {
+///     if process.io_policy() == Some(IoPolicy::Throttle) {
+///         println!("the disk I/O of this process are throttled");
+///     }
 /// }
 /// ```
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd)]
-pub struct DiskUsage {
-    /// Total number of written bytes.
-    pub total_written_bytes: u64,
-    /// Number of written bytes since the last refresh.
-    pub written_bytes: u64,
-    /// Total number of read bytes.
-    pub total_read_bytes: u64,
-    /// Number of read bytes since the last refresh.
-    pub read_bytes: u64,
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IoPolicy {
+    /// The default policy, inherited from the parent process or the thread.
+    Default,
+    /// The I/O are important and are never throttled.
+    Important,
+    /// The I/O don't count when throttling the other processes, but can be throttled.
+    Passive,
+    /// The I/O are throttled when other processes need the disk.
+    Throttle,
+    /// The I/O are done for a long running task the user is aware of, and might be throttled.
+    Utility,
+    /// The I/O are done for a task the user is waiting for.
+    Standard,
 }
 
 /// Enum describing the different status of a process.
@@ -3694,6 +7506,51 @@ impl fmt::Display for IpNetwork {
     }
 }
 
+/// Address family used to filter the Ip networks.
+///
+/// It is used by [`NetworkData::ip_networks_by_family`][crate::NetworkData::ip_networks_by_family].
+///
+/// ```no_run
+/// use sysinfo::{AddressFamily, Networks};
+///
+/// let networks = Networks::new_with_refreshed_list();
+/// for (interface_name, network) in &networks {
+///     let ipv4_count = network.ip_networks_by_family(AddressFamily::Ipv4).count();
+///     println!("{interface_name}: {ipv4_count} IPv4 networks");
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AddressFamily {
+    /// IPv4 addresses only (`AF_INET`).
+    Ipv4,
+    /// IPv6 addresses only (`AF_INET6`).
+    Ipv6,
+    /// Both IPv4 and IPv6 addresses.
+    #[default]
+    Both,
+}
+
+impl AddressFamily {
+    /// Returns `true` if `addr` belongs to this address family.
+    ///
+    /// ```
+    /// use sysinfo::AddressFamily;
+    /// use std::net::{IpAddr, Ipv4Addr};
+    ///
+    /// let addr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    /// assert!(AddressFamily::Ipv4.contains(&addr));
+    /// assert!(!AddressFamily::Ipv6.contains(&addr));
+    /// assert!(AddressFamily::Both.contains(&addr));
+    /// ```
+    pub fn contains(&self, addr: &IpAddr) -> bool {
+        match self {
+            Self::Ipv4 => addr.is_ipv4(),
+            Self::Ipv6 => addr.is_ipv6(),
+            Self::Both => true,
+        }
+    }
+}
+
 /// Interacting with components.
 ///
 /// ```no_run
@@ -3853,8 +7710,42 @@ impl Components {
         into_iter_mut(self.list_mut()).for_each(|component| component.refresh());
     }
 
+    /// Returns the CPU temperature (in celsius degree), computed as the average of the CPU
+    /// sensors' temperatures, or `None` if no CPU sensor was found.
+    ///
+    /// ⚠️ Windows doesn't provide a CPU specific sensor so this method always returns `None`
+    /// there.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// if let Some(temperature) = components.cpu_temperature() {
+    ///     println!("CPU: {temperature}°C");
+    /// }
+    /// ```
+    pub fn cpu_temperature(&self) -> Option<f32> {
+        let (sum, count) = self
+            .list()
+            .iter()
+            .filter(|component| component.inner.is_cpu())
+            .map(|component| component.temperature())
+            .filter(|temperature| temperature.is_finite())
+            .fold((0., 0), |(sum, count), temperature| {
+                (sum + temperature, count + 1)
+            });
+        if count == 0 {
+            None
+        } else {
+            Some(sum / count as f32)
+        }
+    }
+
     /// The component list will be emptied then completely recomputed.
     ///
+    /// Call this method to discover components which appeared after the last call (like a
+    /// hot-plugged external GPU) or to remove the ones which disappeared.
+    ///
     /// ```no_run
     /// use sysinfo::Components;
     ///
@@ -3864,6 +7755,41 @@ impl Components {
     pub fn refresh_list(&mut self) {
         self.inner.refresh_list()
     }
+
+    /// Registers an additional SMC (System Management Controller) temperature key (like
+    /// `b"TC0P"`) to be read with the default ones, under the given `label`.
+    ///
+    /// It allows to read sensors specific to some Mac models. Like the default ones, the key is
+    /// only read if the sensor exists and provides a `sp78` value. Registering an already
+    /// registered key only updates its label.
+    ///
+    /// ⚠️ The sensor is only listed after the next call to [`Components::refresh_list`].
+    ///
+    /// ⚠️ This method does nothing on other platforms than macOS on Intel (Apple Silicon Macs
+    /// don't use the SMC for their temperature sensors).
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let mut components = Components::new();
+    /// // Memory proximity sensor.
+    /// components.add_temperature_sensor(*b"TM0P", "Memory Proximity");
+    /// components.refresh_list();
+    /// ```
+    pub fn add_temperature_sensor(&mut self, key: [u8; 4], label: &str) {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                target_os = "macos",
+                any(target_arch = "x86", target_arch = "x86_64"),
+                not(feature = "apple-sandbox"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.add_temperature_sensor(key, label);
+            } else {
+                let _ = (key, label);
+            }
+        }
+    }
 }
 
 /// Getting a component temperature information.
@@ -3999,6 +7925,10 @@ impl Component {
 ///     println!("{}%", cpu.cpu_usage());
 /// }
 /// ```
+///
+/// Like [`Process`], cloning a `Cpu` gives you a snapshot which isn't updated by later
+/// refreshes.
+#[derive(Clone)]
 pub struct Cpu {
     pub(crate) inner: CpuInner,
 }
@@ -4029,6 +7959,31 @@ impl Cpu {
         self.inner.cpu_usage()
     }
 
+    /// Returns the part of this CPU's usage (in %) spent running niced (low priority) processes.
+    /// It is already included in [`Cpu::cpu_usage`].
+    ///
+    /// Like [`Cpu::cpu_usage`], it is computed from the diff between two refreshes.
+    ///
+    /// ⚠️ Windows has no such concept so it always returns `0` there.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let mut s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    ///
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// // Refresh CPUs again.
+    /// s.refresh_cpu_all();
+    ///
+    /// println!("{}% spent in niced processes", s.global_cpu_info().nice_usage());
+    /// ```
+    pub fn nice_usage(&self) -> f32 {
+        self.inner.nice_usage()
+    }
+
     /// Returns this CPU's name.
     ///
     /// ```no_run
@@ -4092,4 +8047,302 @@ impl Cpu {
     pub fn frequency(&self) -> u64 {
         self.inner.frequency()
     }
+
+    /// Returns the CPU's nominal maximum frequency (in MHz).
+    ///
+    /// Returns `0` if the information isn't available. On Apple Silicon, it is the frequency of
+    /// the highest performance state of the CPU (only available on macOS outside of the
+    /// `apple-sandbox` feature).
+    ///
+    /// ⚠️ This method always returns `0` on other platforms than macOS and iOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::new().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{}", cpu.max_frequency());
+    /// }
+    /// ```
+    pub fn max_frequency(&self) -> u64 {
+        cfg_if::cfg_if! {
+            if #[cfg(all(
+                any(target_os = "macos", target_os = "ios"),
+                not(feature = "unknown-ci"),
+            ))] {
+                self.inner.max_frequency()
+            } else {
+                0
+            }
+        }
+    }
+}
+
+/// Cumulative CPU times, returned by [`System::cpu_times`].
+///
+/// It is used to compute the CPU usage over the period between the moment it was retrieved and
+/// the last CPU refresh with [`System::cpu_usage_since`] and
+/// [`System::global_cpu_usage_since`]. Since it doesn't depend on the previous refresh of the
+/// [`System`], each consumer can keep its own `CpuTimes` to measure the usage over its own
+/// period.
+#[derive(Clone, Debug)]
+pub struct CpuTimes {
+    // The time spent working and the total time of each CPU.
+    global: (u64, u64),
+    cpus: Vec<(u64, u64)>,
+}
+
+impl CpuTimes {
+    fn usage_between(old: (u64, u64), new: (u64, u64)) -> f32 {
+        let total = new.1.saturating_sub(old.1);
+        if total == 0 {
+            return 0.;
+        }
+        let work = new.0.saturating_sub(old.0);
+        // The times can be big so `f64` is used to keep the ratio exact.
+        ((work as f64 / total as f64 * 100.) as f32).min(100.)
+    }
+}
+
+/// Plain data copy of the information of a [`System`], returned by [`System::snapshot`].
+///
+/// It doesn't hold any handle to the system, so it can be cloned and shared between threads
+/// freely. Since disks and networks are not handled by [`System`], they can be added with
+/// [`Snapshot::with_disks`] and [`Snapshot::with_networks`].
+///
+/// ```no_run
+/// use sysinfo::{Disks, Networks, System};
+///
+/// let s = System::new_all();
+/// let snapshot = s
+///     .snapshot()
+///     .with_disks(&Disks::new_with_refreshed_list())
+///     .with_networks(&Networks::new_with_refreshed_list());
+/// println!("{} processes, {} disks", snapshot.processes.len(), snapshot.disks.len());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    /// Value returned by [`System::total_memory`].
+    pub total_memory: u64,
+    /// Value returned by [`System::free_memory`].
+    pub free_memory: u64,
+    /// Value returned by [`System::available_memory`].
+    pub available_memory: u64,
+    /// Value returned by [`System::used_memory`].
+    pub used_memory: u64,
+    /// Value returned by [`System::total_swap`].
+    pub total_swap: u64,
+    /// Value returned by [`System::free_swap`].
+    pub free_swap: u64,
+    /// Value returned by [`System::used_swap`].
+    pub used_swap: u64,
+    /// Copy of [`System::global_cpu_info`].
+    pub global_cpu_info: CpuSnapshot,
+    /// Copy of [`System::cpus`].
+    pub cpus: Vec<CpuSnapshot>,
+    /// Copy of [`System::processes`].
+    pub processes: HashMap<Pid, ProcessSnapshot>,
+    /// Copy of the disks added with [`Snapshot::with_disks`].
+    pub disks: Vec<DiskSnapshot>,
+    /// Copy of the network interfaces added with [`Snapshot::with_networks`].
+    pub networks: HashMap<String, NetworkDataSnapshot>,
+}
+
+impl Snapshot {
+    /// Adds a copy of the given `disks` to the snapshot, replacing the previous ones if any.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Disks, System};
+    ///
+    /// let s = System::new();
+    /// let snapshot = s.snapshot().with_disks(&Disks::new_with_refreshed_list());
+    /// for disk in &snapshot.disks {
+    ///     println!("{:?}: {} bytes available", disk.name, disk.available_space);
+    /// }
+    /// ```
+    pub fn with_disks(mut self, disks: &Disks) -> Self {
+        self.disks = disks.list().iter().map(DiskSnapshot::new).collect();
+        self
+    }
+
+    /// Adds a copy of the given `networks` to the snapshot, replacing the previous ones if any.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Networks, System};
+    ///
+    /// let s = System::new();
+    /// let snapshot = s.snapshot().with_networks(&Networks::new_with_refreshed_list());
+    /// for (interface_name, data) in &snapshot.networks {
+    ///     println!("{interface_name}: {} B received", data.total_received);
+    /// }
+    /// ```
+    pub fn with_networks(mut self, networks: &Networks) -> Self {
+        self.networks = networks
+            .list()
+            .iter()
+            .map(|(name, data)| (name.clone(), NetworkDataSnapshot::new(data)))
+            .collect();
+        self
+    }
+}
+
+/// Plain data copy of a [`Cpu`], stored in a [`Snapshot`].
+#[derive(Clone, Debug)]
+pub struct CpuSnapshot {
+    /// Value returned by [`Cpu::name`].
+    pub name: String,
+    /// Value returned by [`Cpu::vendor_id`].
+    pub vendor_id: String,
+    /// Value returned by [`Cpu::brand`].
+    pub brand: String,
+    /// Value returned by [`Cpu::frequency`].
+    pub frequency: u64,
+    /// Value returned by [`Cpu::cpu_usage`].
+    pub cpu_usage: f32,
+}
+
+impl CpuSnapshot {
+    fn new(cpu: &Cpu) -> Self {
+        Self {
+            name: cpu.name().to_owned(),
+            vendor_id: cpu.vendor_id().to_owned(),
+            brand: cpu.brand().to_owned(),
+            frequency: cpu.frequency(),
+            cpu_usage: cpu.cpu_usage(),
+        }
+    }
+}
+
+/// Plain data copy of a [`Process`], stored in a [`Snapshot`].
+#[derive(Clone, Debug)]
+pub struct ProcessSnapshot {
+    /// Value returned by [`Process::pid`].
+    pub pid: Pid,
+    /// Value returned by [`Process::parent`].
+    pub parent: Option<Pid>,
+    /// Value returned by [`Process::name`].
+    pub name: OsString,
+    /// Value returned by [`Process::cmd`].
+    pub cmd: Vec<OsString>,
+    /// Value returned by [`Process::exe`].
+    pub exe: Option<PathBuf>,
+    /// Value returned by [`Process::status`].
+    pub status: ProcessStatus,
+    /// Value returned by [`Process::memory`].
+    pub memory: u64,
+    /// Value returned by [`Process::virtual_memory`].
+    pub virtual_memory: u64,
+    /// Value returned by [`Process::cpu_usage`].
+    pub cpu_usage: f32,
+    /// Value returned by [`Process::disk_usage`].
+    pub disk_usage: DiskUsage,
+    /// Value returned by [`Process::start_time`].
+    pub start_time: u64,
+    /// Value returned by [`Process::run_time`].
+    pub run_time: u64,
+    /// Value returned by [`Process::user_id`].
+    pub user_id: Option<Uid>,
+}
+
+impl ProcessSnapshot {
+    fn new(process: &Process) -> Self {
+        Self {
+            pid: process.pid(),
+            parent: process.parent(),
+            name: process.name().to_owned(),
+            cmd: process.cmd().to_vec(),
+            exe: process.exe().map(Path::to_path_buf),
+            status: process.status(),
+            memory: process.memory(),
+            virtual_memory: process.virtual_memory(),
+            cpu_usage: process.cpu_usage(),
+            disk_usage: process.disk_usage(),
+            start_time: process.start_time(),
+            run_time: process.run_time(),
+            user_id: process.user_id().cloned(),
+        }
+    }
+}
+
+/// Plain data copy of a [`Disk`], stored in a [`Snapshot`].
+#[derive(Clone, Debug)]
+pub struct DiskSnapshot {
+    /// Value returned by [`Disk::kind`].
+    pub kind: DiskKind,
+    /// Value returned by [`Disk::name`].
+    pub name: OsString,
+    /// Value returned by [`Disk::file_system`].
+    pub file_system: OsString,
+    /// Value returned by [`Disk::mount_point`].
+    pub mount_point: PathBuf,
+    /// Value returned by [`Disk::total_space`].
+    pub total_space: u64,
+    /// Value returned by [`Disk::available_space`].
+    pub available_space: u64,
+    /// Value returned by [`Disk::total_inodes`].
+    pub total_inodes: u64,
+    /// Value returned by [`Disk::available_inodes`].
+    pub available_inodes: u64,
+    /// Value returned by [`Disk::is_removable`].
+    pub is_removable: bool,
+}
+
+impl DiskSnapshot {
+    fn new(disk: &Disk) -> Self {
+        Self {
+            kind: disk.kind(),
+            name: disk.name().to_owned(),
+            file_system: disk.file_system().to_owned(),
+            mount_point: disk.mount_point().to_path_buf(),
+            total_space: disk.total_space(),
+            available_space: disk.available_space(),
+            total_inodes: disk.total_inodes(),
+            available_inodes: disk.available_inodes(),
+            is_removable: disk.is_removable(),
+        }
+    }
+}
+
+/// Plain data copy of a [`NetworkData`], stored in a [`Snapshot`].
+#[derive(Clone, Debug)]
+pub struct NetworkDataSnapshot {
+    /// Value returned by [`NetworkData::received`].
+    pub received: u64,
+    /// Value returned by [`NetworkData::total_received`].
+    pub total_received: u64,
+    /// Value returned by [`NetworkData::transmitted`].
+    pub transmitted: u64,
+    /// Value returned by [`NetworkData::total_transmitted`].
+    pub total_transmitted: u64,
+    /// Value returned by [`NetworkData::packets_received`].
+    pub packets_received: u64,
+    /// Value returned by [`NetworkData::total_packets_received`].
+    pub total_packets_received: u64,
+    /// Value returned by [`NetworkData::packets_transmitted`].
+    pub packets_transmitted: u64,
+    /// Value returned by [`NetworkData::total_packets_transmitted`].
+    pub total_packets_transmitted: u64,
+    /// Value returned by [`NetworkData::mac_address`].
+    pub mac_address: MacAddr,
+    /// Value returned by [`NetworkData::ip_networks`].
+    pub ip_networks: Vec<IpNetwork>,
+}
+
+impl NetworkDataSnapshot {
+    fn new(data: &NetworkData) -> Self {
+        Self {
+            received: data.received(),
+            total_received: data.total_received(),
+            transmitted: data.transmitted(),
+            total_transmitted: data.total_transmitted(),
+            packets_received: data.packets_received(),
+            total_packets_received: data.total_packets_received(),
+            packets_transmitted: data.packets_transmitted(),
+            total_packets_transmitted: data.total_packets_transmitted(),
+            mac_address: data.mac_address(),
+            ip_networks: data.ip_networks().to_vec(),
+        }
+    }
 }
@@ -1,7 +1,8 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::{
-    Component, Components, Cpu, Disk, Disks, NetworkData, Networks, Process, System, User, Users,
+    Component, Components, Cpu, Disk, Disks, Groups, MemoryPressureSubscription, NetworkData,
+    Networks, Process, System, User, Users,
 };
 
 use std::fmt;
@@ -11,6 +12,7 @@ impl fmt::Debug for Cpu {
         f.debug_struct("Cpu")
             .field("name", &self.name())
             .field("CPU usage", &self.cpu_usage())
+            .field("nice usage", &self.nice_usage())
             .field("frequency", &self.frequency())
             .field("vendor ID", &self.vendor_id())
             .field("brand", &self.brand())
@@ -24,15 +26,25 @@ impl fmt::Debug for System {
             .field("global CPU usage", &self.global_cpu_info().cpu_usage())
             .field("load average", &Self::load_average())
             .field("total memory", &self.total_memory())
+            .field("used memory", &self.used_memory())
+            .field("available memory", &self.available_memory())
             .field("free memory", &self.free_memory())
             .field("total swap", &self.total_swap())
             .field("free swap", &self.free_swap())
+            .field("uptime", &Self::uptime())
             .field("nb CPUs", &self.cpus().len())
             .field("nb processes", &self.processes().len())
             .finish()
     }
 }
 
+impl fmt::Debug for MemoryPressureSubscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryPressureSubscription")
+            .finish_non_exhaustive()
+    }
+}
+
 impl fmt::Debug for Disk {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -62,11 +74,15 @@ impl fmt::Debug for Process {
             .field("memory usage", &self.memory())
             .field("virtual memory usage", &self.virtual_memory())
             .field("CPU usage", &self.cpu_usage())
+            .field("start time", &self.start_time())
+            .field("run time", &self.run_time())
             .field("status", &self.status())
             .field("root", &self.root())
             .field("disk_usage", &self.disk_usage())
             .field("user_id", &self.user_id())
             .field("effective_user_id", &self.effective_user_id())
+            .field("group_id", &self.group_id())
+            .field("effective_group_id", &self.effective_group_id())
             .finish()
     }
 }
@@ -137,6 +153,12 @@ impl fmt::Debug for Users {
     }
 }
 
+impl fmt::Debug for Groups {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
 impl fmt::Debug for User {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("User")
@@ -51,10 +51,14 @@ cfg_if::cfg_if! {
 }
 
 pub use crate::common::{
-    get_current_pid, CGroupLimits, Component, Components, Cpu, CpuRefreshKind, Disk, DiskKind,
-    DiskUsage, Disks, Gid, Group, Groups, IpNetwork, LoadAvg, MacAddr, MemoryRefreshKind,
-    NetworkData, Networks, Pid, Process, ProcessRefreshKind, ProcessStatus, RefreshKind, Signal,
-    System, ThreadKind, Uid, UpdateKind, User, Users,
+    get_current_pid, AddressFamily, CGroupLimits, Component, Components, Connection,
+    ConnectionProtocol, Cpu, CpuRefreshKind, CpuSnapshot, CpuTimes, Disk, DiskKind, DiskListDiff,
+    DiskSnapshot, DiskUsage, Disks, FdBreakdown, Gid, Group, Groups, InitError, IoPolicy,
+    IpNetwork, LoadAvg, MacAddr, MemoryEccErrors, MemoryPressure, MemoryPressureSubscription,
+    MemoryRefreshKind, MemoryRegions, NetworkData, NetworkDataSnapshot, NetworkTotals, Networks,
+    PageFaults, Pid, PowerSource, Process, ProcessDiff, ProcessRefreshKind, ProcessSnapshot,
+    ProcessStatus, RefreshKind, Signal, Snapshot, Subsystem, SwapFile, System, ThermalPressure,
+    ThreadKind, ThresholdCrossing, Uid, UpdateKind, User, Users, Wakeups,
 };
 
 pub(crate) use crate::common::GroupInner;
@@ -132,28 +136,6 @@ pub fn set_open_files_limit(mut _new_limit: isize) -> bool {
 // FIXME: Can be removed once negative trait bounds are supported.
 #[cfg(doctest)]
 mod doctest {
-    /// Check that `Process` doesn't implement `Clone`.
-    ///
-    /// First we check that the "basic" code works:
-    ///
-    /// ```no_run
-    /// use sysinfo::{Process, System};
-    ///
-    /// let mut s = System::new_all();
-    /// let p: &Process = s.processes().values().next().unwrap();
-    /// ```
-    ///
-    /// And now we check if it fails when we try to clone it:
-    ///
-    /// ```compile_fail
-    /// use sysinfo::{Process, System};
-    ///
-    /// let mut s = System::new_all();
-    /// let p: &Process = s.processes().values().next().unwrap();
-    /// let p = (*p).clone();
-    /// ```
-    mod process_clone {}
-
     /// Check that `System` doesn't implement `Clone`.
     ///
     /// First we check that the "basic" code works:
@@ -198,6 +180,7 @@ mod test {
         check_minimum_cpu_update_interval(MINIMUM_CPU_UPDATE_INTERVAL);
     }
 
+    #[cfg(feature = "processes")]
     #[test]
     fn check_process_memory_usage() {
         let mut s = System::new();
@@ -248,7 +231,7 @@ mod test {
         }
     }
 
-    #[cfg(target_os = "linux")]
+    #[cfg(all(target_os = "linux", feature = "processes"))]
     #[test]
     fn check_processes_cpu_usage() {
         if !IS_SUPPORTED_SYSTEM {
@@ -328,15 +311,31 @@ mod test {
             }
 
             // And now check that our `get_user_by_id` method works.
-            let s = System::new_with_specifics(
-                RefreshKind::new()
-                    .with_processes(ProcessRefreshKind::new().with_user(UpdateKind::Always)),
-            );
-            assert!(s
-                .processes()
+            #[cfg(feature = "processes")]
+            {
+                let s = System::new_with_specifics(
+                    RefreshKind::new()
+                        .with_processes(ProcessRefreshKind::new().with_user(UpdateKind::Always)),
+                );
+                assert!(s
+                    .processes()
+                    .iter()
+                    .filter_map(|(_, p)| p.user_id())
+                    .any(|uid| users.get_user_by_id(uid).is_some()));
+            }
+        }
+    }
+
+    #[test]
+    fn check_user_home_directory_and_shell() {
+        if IS_SUPPORTED_SYSTEM && cfg!(not(target_os = "windows")) {
+            let users = Users::new_with_refreshed_list();
+            let root = users
                 .iter()
-                .filter_map(|(_, p)| p.user_id())
-                .any(|uid| users.get_user_by_id(uid).is_some()));
+                .find(|u| u.name() == "root")
+                .expect("no root user");
+            assert!(root.home_directory().is_some());
+            assert!(root.shell().is_some());
         }
     }
 
@@ -397,6 +396,7 @@ mod test {
         }
     }
 
+    #[cfg(feature = "processes")]
     #[test]
     fn check_refresh_process_return_value() {
         // We don't want to test on unsupported systems.
@@ -623,4 +623,34 @@ mod test {
         }
         assert!(!Groups::new_with_refreshed_list().is_empty());
     }
+
+    // The `Debug` implementations only use the public getters so they never print the
+    // internal handles or pointers.
+    #[test]
+    fn check_debug_impls() {
+        if !IS_SUPPORTED_SYSTEM || cfg!(not(feature = "processes")) {
+            return;
+        }
+        let mut s = System::new();
+        s.refresh_cpu_all();
+        let pid = get_current_pid().expect("failed to get current pid");
+        s.refresh_process(pid);
+
+        assert!(format!("{s:?}").starts_with("System {"));
+        for cpu in s.cpus() {
+            let debug = format!("{cpu:?}");
+            assert!(
+                debug.contains(&format!("name: {:?}", cpu.name())),
+                "{debug}"
+            );
+        }
+        if let Some(process) = s.process(pid) {
+            let debug = format!("{process:?}");
+            assert!(debug.contains(&format!("pid: Pid({pid})")), "{debug}");
+            assert!(debug.contains("memory usage: "), "{debug}");
+            assert!(debug.contains("run time: "), "{debug}");
+        } else {
+            assert!(!IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox"));
+        }
+    }
 }
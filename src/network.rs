@@ -2,9 +2,19 @@
 
 use std::collections::HashMap;
 
-use crate::network_helper::{get_interface_address, get_interface_ip_networks};
+use crate::network_helper::{
+    get_interface_address, get_interface_flags, get_interface_ip_networks,
+};
 use crate::NetworkData;
 
+/// State of a network interface.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct InterfaceFlags {
+    pub(crate) is_up: bool,
+    pub(crate) is_loopback: bool,
+    pub(crate) is_running: bool,
+}
+
 /// Interface addresses are OS-independent
 pub(crate) fn refresh_networks_addresses(interfaces: &mut HashMap<String, NetworkData>) {
     let interface_networks = unsafe { get_interface_ip_networks() };
@@ -13,6 +23,11 @@ pub(crate) fn refresh_networks_addresses(interfaces: &mut HashMap<String, Networ
             interface.inner.ip_networks = ip_networks.into_iter().collect::<Vec<_>>();
         }
     }
+    for (interface_name, flags) in unsafe { get_interface_flags() } {
+        if let Some(interface) = interfaces.get_mut(&interface_name) {
+            interface.inner.flags = flags;
+        }
+    }
     match unsafe { get_interface_address() } {
         Ok(ifa_iterator) => {
             for (name, ifa) in ifa_iterator {
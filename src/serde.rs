@@ -7,8 +7,8 @@ impl Serialize for crate::Disk {
     where
         S: Serializer,
     {
-        // `7` corresponds to the (maximum) number of fields.
-        let mut state = serializer.serialize_struct("Disk", 7)?;
+        // `9` corresponds to the (maximum) number of fields.
+        let mut state = serializer.serialize_struct("Disk", 9)?;
 
         state.serialize_field("DiskKind", &self.kind())?;
         if let Some(s) = self.name().to_str() {
@@ -18,6 +18,8 @@ impl Serialize for crate::Disk {
         state.serialize_field("mount_point", &self.mount_point())?;
         state.serialize_field("total_space", &self.total_space())?;
         state.serialize_field("available_space", &self.available_space())?;
+        state.serialize_field("total_inodes", &self.total_inodes())?;
+        state.serialize_field("available_inodes", &self.available_inodes())?;
         state.serialize_field("is_removable", &self.is_removable())?;
 
         state.end()
@@ -87,10 +89,11 @@ impl Serialize for crate::Cpu {
     where
         S: Serializer,
     {
-        // `5` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("Cpu", 5)?;
+        // `6` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Cpu", 6)?;
 
         state.serialize_field("cpu_usage", &self.cpu_usage())?;
+        state.serialize_field("nice_usage", &self.nice_usage())?;
         state.serialize_field("name", &self.name())?;
         state.serialize_field("vendor_id", &self.vendor_id())?;
         state.serialize_field("brand", &self.brand())?;
@@ -259,13 +262,106 @@ impl Serialize for crate::LoadAvg {
     }
 }
 
+impl Serialize for crate::MemoryEccErrors {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("MemoryEccErrors", 2)?;
+
+        state.serialize_field("correctable", &self.correctable)?;
+        state.serialize_field("uncorrectable", &self.uncorrectable)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::SwapFile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("SwapFile", 2)?;
+
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("size", &self.size)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::ThermalPressure {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Nominal => (0, "Nominal"),
+            Self::Fair => (1, "Fair"),
+            Self::Serious => (2, "Serious"),
+            Self::Critical => (3, "Critical"),
+        };
+
+        serializer.serialize_unit_variant("ThermalPressure", index, variant)
+    }
+}
+
+impl Serialize for crate::MemoryPressure {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Normal => (0, "Normal"),
+            Self::Warn => (1, "Warn"),
+            Self::Critical => (2, "Critical"),
+        };
+
+        serializer.serialize_unit_variant("MemoryPressure", index, variant)
+    }
+}
+
+impl Serialize for crate::IoPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Default => (0, "Default"),
+            Self::Important => (1, "Important"),
+            Self::Passive => (2, "Passive"),
+            Self::Throttle => (3, "Throttle"),
+            Self::Utility => (4, "Utility"),
+            Self::Standard => (5, "Standard"),
+        };
+
+        serializer.serialize_unit_variant("IoPolicy", index, variant)
+    }
+}
+
+impl Serialize for crate::PowerSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Ac => (0, "Ac"),
+            Self::Battery => (1, "Battery"),
+            Self::Ups => (2, "Ups"),
+            Self::Unknown => (3, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("PowerSource", index, variant)
+    }
+}
+
 impl Serialize for crate::NetworkData {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        // `13` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("NetworkData", 13)?;
+        // `19` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("NetworkData", 19)?;
 
         state.serialize_field("received", &self.received())?;
         state.serialize_field("total_received", &self.total_received())?;
@@ -287,11 +383,34 @@ impl Serialize for crate::NetworkData {
         )?;
         state.serialize_field("mac_address", &self.mac_address())?;
         state.serialize_field("ip_networks", &self.ip_networks())?;
+        state.serialize_field("is_up", &self.is_up())?;
+        state.serialize_field("is_loopback", &self.is_loopback())?;
+        state.serialize_field("is_running", &self.is_running())?;
+        state.serialize_field("mtu", &self.mtu())?;
+        state.serialize_field("link_speed", &self.link_speed())?;
 
         state.end()
     }
 }
 
+impl Serialize for crate::NetworkTotals {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `6` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("NetworkTotals", 6)?;
+
+        state.serialize_field("received", &self.received)?;
+        state.serialize_field("total_received", &self.total_received)?;
+        state.serialize_field("received_rate", &self.received_rate)?;
+        state.serialize_field("transmitted", &self.transmitted)?;
+        state.serialize_field("total_transmitted", &self.total_transmitted)?;
+        state.serialize_field("transmitted_rate", &self.transmitted_rate)?;
+        state.end()
+    }
+}
+
 impl Serialize for crate::Component {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -360,6 +479,20 @@ impl Serialize for crate::DiskKind {
     }
 }
 
+impl Serialize for crate::ThresholdCrossing {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::NoChange => (0, "NoChange"),
+            Self::CrossedBelow => (1, "CrossedBelow"),
+            Self::CrossedAbove => (2, "CrossedAbove"),
+        };
+        serializer.serialize_unit_variant("ThresholdCrossing", index, variant)
+    }
+}
+
 impl Serialize for crate::ProcessStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -389,6 +522,135 @@ impl Serialize for crate::ProcessStatus {
     }
 }
 
+impl Serialize for crate::ProcessDiff {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ProcessDiff", 2)?;
+
+        state.serialize_field("added", &self.added)?;
+        state.serialize_field("exited", &self.exited)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::DiskListDiff {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("DiskListDiff", 2)?;
+
+        state.serialize_field("added", &self.added)?;
+        state.serialize_field("removed", &self.removed)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::ConnectionProtocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Tcp => (0, "Tcp"),
+            Self::Udp => (1, "Udp"),
+        };
+
+        serializer.serialize_unit_variant("ConnectionProtocol", index, variant)
+    }
+}
+
+impl Serialize for crate::Connection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Connection", 4)?;
+
+        state.serialize_field("protocol", &self.protocol)?;
+        state.serialize_field("local_address", &self.local_address)?;
+        state.serialize_field("remote_address", &self.remote_address)?;
+        state.serialize_field("is_established", &self.is_established)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::FdBreakdown {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("FdBreakdown", 5)?;
+
+        state.serialize_field("files", &self.files)?;
+        state.serialize_field("sockets", &self.sockets)?;
+        state.serialize_field("pipes", &self.pipes)?;
+        state.serialize_field("kqueues", &self.kqueues)?;
+        state.serialize_field("others", &self.others)?;
+        state.end()
+    }
+}
+
+impl Serialize for crate::Wakeups {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Wakeups", 4)?;
+
+        state.serialize_field("total_idle", &self.total_idle)?;
+        state.serialize_field("idle", &self.idle)?;
+        state.serialize_field("total_interrupt", &self.total_interrupt)?;
+        state.serialize_field("interrupt", &self.interrupt)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::PageFaults {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("PageFaults", 4)?;
+
+        state.serialize_field("total_minor", &self.total_minor)?;
+        state.serialize_field("minor", &self.minor)?;
+        state.serialize_field("total_major", &self.total_major)?;
+        state.serialize_field("major", &self.major)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::MemoryRegions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("MemoryRegions", 4)?;
+
+        state.serialize_field("resident", &self.resident)?;
+        state.serialize_field("shared", &self.shared)?;
+        state.serialize_field("private", &self.private)?;
+        state.serialize_field("compressed", &self.compressed)?;
+
+        state.end()
+    }
+}
+
 impl Serialize for crate::DiskUsage {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -415,6 +677,21 @@ impl Serialize for crate::MacAddr {
     }
 }
 
+impl Serialize for crate::AddressFamily {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Ipv4 => (0, "Ipv4"),
+            Self::Ipv6 => (1, "Ipv6"),
+            Self::Both => (2, "Both"),
+        };
+
+        serializer.serialize_unit_variant("AddressFamily", index, variant)
+    }
+}
+
 impl Serialize for crate::IpNetwork {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -428,3 +705,117 @@ impl Serialize for crate::IpNetwork {
         state.end()
     }
 }
+
+impl Serialize for crate::Snapshot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `12` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Snapshot", 12)?;
+
+        state.serialize_field("total_memory", &self.total_memory)?;
+        state.serialize_field("free_memory", &self.free_memory)?;
+        state.serialize_field("available_memory", &self.available_memory)?;
+        state.serialize_field("used_memory", &self.used_memory)?;
+        state.serialize_field("total_swap", &self.total_swap)?;
+        state.serialize_field("free_swap", &self.free_swap)?;
+        state.serialize_field("used_swap", &self.used_swap)?;
+        state.serialize_field("global_cpu_info", &self.global_cpu_info)?;
+        state.serialize_field("cpus", &self.cpus)?;
+        state.serialize_field("processes", &self.processes)?;
+        state.serialize_field("disks", &self.disks)?;
+        state.serialize_field("networks", &self.networks)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::CpuSnapshot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CpuSnapshot", 5)?;
+
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("vendor_id", &self.vendor_id)?;
+        state.serialize_field("brand", &self.brand)?;
+        state.serialize_field("frequency", &self.frequency)?;
+        state.serialize_field("cpu_usage", &self.cpu_usage)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::ProcessSnapshot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `13` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ProcessSnapshot", 13)?;
+
+        state.serialize_field("pid", &self.pid)?;
+        state.serialize_field("parent", &self.parent)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("cmd", &self.cmd)?;
+        state.serialize_field("exe", &self.exe)?;
+        state.serialize_field("status", &self.status)?;
+        state.serialize_field("memory", &self.memory)?;
+        state.serialize_field("virtual_memory", &self.virtual_memory)?;
+        state.serialize_field("cpu_usage", &self.cpu_usage)?;
+        state.serialize_field("disk_usage", &self.disk_usage)?;
+        state.serialize_field("start_time", &self.start_time)?;
+        state.serialize_field("run_time", &self.run_time)?;
+        state.serialize_field("user_id", &self.user_id)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::DiskSnapshot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `9` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("DiskSnapshot", 9)?;
+
+        state.serialize_field("kind", &self.kind)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("file_system", &self.file_system)?;
+        state.serialize_field("mount_point", &self.mount_point)?;
+        state.serialize_field("total_space", &self.total_space)?;
+        state.serialize_field("available_space", &self.available_space)?;
+        state.serialize_field("total_inodes", &self.total_inodes)?;
+        state.serialize_field("available_inodes", &self.available_inodes)?;
+        state.serialize_field("is_removable", &self.is_removable)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for crate::NetworkDataSnapshot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `10` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("NetworkDataSnapshot", 10)?;
+
+        state.serialize_field("received", &self.received)?;
+        state.serialize_field("total_received", &self.total_received)?;
+        state.serialize_field("transmitted", &self.transmitted)?;
+        state.serialize_field("total_transmitted", &self.total_transmitted)?;
+        state.serialize_field("packets_received", &self.packets_received)?;
+        state.serialize_field("total_packets_received", &self.total_packets_received)?;
+        state.serialize_field("packets_transmitted", &self.packets_transmitted)?;
+        state.serialize_field("total_packets_transmitted", &self.total_packets_transmitted)?;
+        state.serialize_field("mac_address", &self.mac_address)?;
+        state.serialize_field("ip_networks", &self.ip_networks)?;
+
+        state.end()
+    }
+}
@@ -3,8 +3,9 @@
 use std::ffi::{OsStr, OsString};
 use std::path::Path;
 
-use crate::{DiskUsage, Gid, Pid, ProcessStatus, Signal, Uid};
+use crate::{DiskUsage, Gid, PageFaults, Pid, ProcessStatus, Signal, Uid};
 
+#[derive(Clone)]
 pub(crate) struct ProcessInner;
 
 impl ProcessInner {
@@ -64,6 +65,14 @@ impl ProcessInner {
         0
     }
 
+    pub(crate) fn accumulated_cpu_time(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn page_faults(&self) -> Option<PageFaults> {
+        None
+    }
+
     pub(crate) fn cpu_usage(&self) -> f32 {
         0.0
     }
@@ -93,4 +102,12 @@ impl ProcessInner {
     pub(crate) fn session_id(&self) -> Option<Pid> {
         None
     }
+
+    pub(crate) fn is_being_traced(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn swapped_memory(&self) -> u64 {
+        0
+    }
 }
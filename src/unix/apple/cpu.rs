@@ -1,12 +1,13 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::sys::utils::{get_sys_value, get_sys_value_by_name};
+use crate::sys::utils::{get_sys_value, get_sys_value_by_name, ErrorReporter};
+use crate::utils::smooth_cpu_usage;
 use crate::{Cpu, CpuRefreshKind};
 
 use libc::{c_char, c_void, host_processor_info, mach_port_t, mach_task_self};
 use std::mem;
 use std::ops::Deref;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use std::time::Instant;
 
 pub(crate) struct CpusWrapper {
@@ -15,6 +16,10 @@ pub(crate) struct CpusWrapper {
     pub(crate) got_cpu_frequency: bool,
     /// This field is needed to prevent updating when not enough time passed since last update.
     last_update: Option<Instant>,
+    /// Ticks of the whole system at the last `refresh_global_cpu_usage` call.
+    global_ticks: Option<[i32; libc::CPU_STATE_MAX as usize]>,
+    /// Smoothing factor applied to the CPU usage (see `System::set_cpu_smoothing`).
+    pub(crate) cpu_smoothing: f32,
 }
 
 impl CpusWrapper {
@@ -32,17 +37,24 @@ impl CpusWrapper {
             cpus: Vec::new(),
             got_cpu_frequency: false,
             last_update: None,
+            global_ticks: None,
+            cpu_smoothing: 1.,
         }
     }
 
-    pub(crate) fn refresh(&mut self, refresh_kind: CpuRefreshKind, port: mach_port_t) {
+    pub(crate) fn refresh(
+        &mut self,
+        refresh_kind: CpuRefreshKind,
+        port: mach_port_t,
+        errors: &ErrorReporter,
+    ) {
         let need_cpu_usage_update = self
             .last_update
             .is_some_and(|last_update| last_update.elapsed() > crate::MINIMUM_CPU_UPDATE_INTERVAL);
 
         let cpus = &mut self.cpus;
         if cpus.is_empty() {
-            init_cpus(port, cpus, &mut self.global_cpu, refresh_kind);
+            init_cpus(port, cpus, &mut self.global_cpu, refresh_kind, errors);
             self.last_update = Some(Instant::now());
             self.got_cpu_frequency = refresh_kind.frequency();
             return;
@@ -56,20 +68,72 @@ impl CpusWrapper {
         }
         if refresh_kind.cpu_usage() && need_cpu_usage_update {
             self.last_update = Some(Instant::now());
-            update_cpu_usage(port, &mut self.global_cpu, |proc_data, cpu_info| {
-                let mut percentage = 0f32;
-                let mut offset = 0;
-                for proc_ in cpus.iter_mut() {
-                    let cpu_usage = compute_usage_of_cpu(proc_, cpu_info, offset);
-                    proc_.inner.update(cpu_usage, Arc::clone(&proc_data));
-                    percentage += proc_.inner.cpu_usage();
-
-                    offset += libc::CPU_STATE_MAX as isize;
-                }
-                (percentage, cpus.len())
-            });
+            let alpha = self.cpu_smoothing;
+            update_cpu_usage(
+                port,
+                &mut self.global_cpu,
+                alpha,
+                errors,
+                |proc_data, cpu_info| {
+                    let mut percentage = 0f32;
+                    let mut nice_percentage = 0f32;
+                    let mut offset = 0;
+                    for proc_ in cpus.iter_mut() {
+                        let (cpu_usage, nice_usage) = compute_usage_of_cpu(proc_, cpu_info, offset);
+                        proc_.inner.update(
+                            smooth_cpu_usage(proc_.inner.cpu_usage(), cpu_usage, alpha),
+                            nice_usage,
+                            Arc::clone(&proc_data),
+                        );
+                        proc_.inner.times = get_times(cpu_info, offset);
+                        // The global CPU usage is smoothed on its own in `update_cpu_usage`.
+                        percentage += cpu_usage;
+                        nice_percentage += nice_usage;
+
+                        offset += libc::CPU_STATE_MAX as isize;
+                    }
+                    (percentage, nice_percentage, cpus.len())
+                },
+            );
+            self.global_cpu.inner.times = sum_times(cpus);
         }
     }
+
+    /// Updates the global CPU usage with `host_statistics`, which returns the ticks of the whole
+    /// system, so the CPUs aren't updated.
+    pub(crate) fn refresh_global_cpu_usage(&mut self, port: mach_port_t, errors: &ErrorReporter) {
+        // There is nothing to blend the CPU usage with before the first refresh.
+        let alpha = if self.cpus.is_empty() {
+            self.refresh(CpuRefreshKind::new().with_cpu_usage(), port, errors);
+            1.
+        } else {
+            self.cpu_smoothing
+        };
+        let mut ticks = [0i32; libc::CPU_STATE_MAX as usize];
+        let mut count = libc::HOST_CPU_LOAD_INFO_COUNT;
+        unsafe {
+            let err = libc::host_statistics(
+                port,
+                libc::HOST_CPU_LOAD_INFO,
+                ticks.as_mut_ptr(),
+                &mut count,
+            );
+            if err != libc::KERN_SUCCESS {
+                errors.report("host_statistics", err);
+                return;
+            }
+        }
+        // At the first call, there is no "old value" so the usage since boot is computed.
+        let old_ticks = match self.global_ticks.as_mut() {
+            Some(old_ticks) => old_ticks.as_mut_ptr(),
+            None => ticks.as_mut_ptr(),
+        };
+        let (cpu_usage, nice_usage) = compute_usage(old_ticks, ticks.as_mut_ptr(), 0);
+        let cpu_usage = smooth_cpu_usage(self.global_cpu.inner.cpu_usage(), cpu_usage, alpha);
+        self.global_cpu.inner.set_cpu_usage(cpu_usage, nice_usage);
+        self.global_cpu.inner.times = get_times(ticks.as_mut_ptr(), 0);
+        self.global_ticks = Some(ticks);
+    }
 }
 
 pub(crate) struct UnsafePtr<T>(*mut T);
@@ -115,9 +179,14 @@ impl Drop for CpuData {
     }
 }
 
+// `CpuData` is never modified once created (a refresh replaces it with a new one), so clones
+// can safely share it.
+#[derive(Clone)]
 pub(crate) struct CpuInner {
     name: String,
     cpu_usage: f32,
+    nice_usage: f32,
+    times: (u64, u64),
     cpu_data: Arc<CpuData>,
     frequency: u64,
     vendor_id: String,
@@ -135,6 +204,8 @@ impl CpuInner {
         Self {
             name,
             cpu_usage: 0f32,
+            nice_usage: 0f32,
+            times: (0, 0),
             cpu_data,
             frequency,
             vendor_id,
@@ -142,12 +213,14 @@ impl CpuInner {
         }
     }
 
-    pub(crate) fn set_cpu_usage(&mut self, cpu_usage: f32) {
+    pub(crate) fn set_cpu_usage(&mut self, cpu_usage: f32, nice_usage: f32) {
         self.cpu_usage = cpu_usage;
+        self.nice_usage = nice_usage;
     }
 
-    pub(crate) fn update(&mut self, cpu_usage: f32, cpu_data: Arc<CpuData>) {
+    pub(crate) fn update(&mut self, cpu_usage: f32, nice_usage: f32, cpu_data: Arc<CpuData>) {
         self.cpu_usage = cpu_usage;
+        self.nice_usage = nice_usage;
         self.cpu_data = cpu_data;
     }
 
@@ -163,6 +236,15 @@ impl CpuInner {
         self.cpu_usage
     }
 
+    pub(crate) fn nice_usage(&self) -> f32 {
+        self.nice_usage
+    }
+
+    /// Returns the time spent working and the total time since boot, in ticks.
+    pub(crate) fn times(&self) -> (u64, u64) {
+        self.times
+    }
+
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
@@ -171,6 +253,13 @@ impl CpuInner {
         self.frequency
     }
 
+    pub(crate) fn max_frequency(&self) -> u64 {
+        // The nominal maximum frequency never changes so it's only queried once.
+        static MAX_FREQUENCY: OnceLock<u64> = OnceLock::new();
+
+        *MAX_FREQUENCY.get_or_init(|| unsafe { get_cpu_max_frequency() })
+    }
+
     pub(crate) fn vendor_id(&self) -> &str {
         &self.vendor_id
     }
@@ -204,6 +293,59 @@ pub(crate) unsafe fn get_cpu_frequency() -> u64 {
     }
 }
 
+unsafe fn get_cpu_max_frequency() -> u64 {
+    let mut speed: u64 = 0;
+    let mut len = std::mem::size_of::<u64>();
+    // `hw.cpufrequency_max` only exists on Intel CPUs.
+    if libc::sysctlbyname(
+        b"hw.cpufrequency_max\0".as_ptr() as *const _,
+        &mut speed as *mut _ as _,
+        &mut len,
+        std::ptr::null_mut(),
+        0,
+    ) == 0
+    {
+        return speed / 1_000_000;
+    }
+    // On Apple Silicon, `get_cpu_frequency` returns the frequency of the highest performance
+    // state (the last of the `pmgr` voltage states).
+    get_cpu_frequency()
+}
+
+pub(crate) fn logical_cpu_count() -> Option<usize> {
+    let mut logical_cpu_count: u32 = 0;
+
+    unsafe {
+        if get_sys_value_by_name(
+            b"hw.logicalcpu\0",
+            &mut mem::size_of::<u32>(),
+            &mut logical_cpu_count as *mut u32 as *mut c_void,
+        ) {
+            Some(logical_cpu_count as _)
+        } else {
+            None
+        }
+    }
+}
+
+pub(crate) fn online_cpu_count() -> Option<usize> {
+    let mut online_cpu_count: u32 = 0;
+
+    unsafe {
+        // `hw.activecpu` is the number of CPUs which are currently available to the scheduler
+        // whereas `hw.logicalcpu` is the number of CPUs available in the current power state.
+        if get_sys_value_by_name(
+            b"hw.activecpu\0",
+            &mut mem::size_of::<u32>(),
+            &mut online_cpu_count as *mut u32 as *mut c_void,
+        ) {
+            Some(online_cpu_count as _)
+        } else {
+            logical_cpu_count()
+        }
+    }
+}
+
 pub(crate) fn physical_core_count() -> Option<usize> {
     let mut physical_core_count = 0;
 
@@ -220,6 +362,31 @@ pub(crate) fn physical_core_count() -> Option<usize> {
     }
 }
 
+pub(crate) fn get_cache_size(level: u8) -> Option<u64> {
+    // The `hw.l*cachesize` sysctls only exist on Intel CPUs. On Apple Silicon, the first
+    // performance level is the one of the performance cores (which have no L3 cache).
+    let names: &[&[u8]] = match level {
+        1 => &[b"hw.l1dcachesize\0", b"hw.perflevel0.l1dcachesize\0"],
+        2 => &[b"hw.l2cachesize\0", b"hw.perflevel0.l2cachesize\0"],
+        3 => &[b"hw.l3cachesize\0"],
+        _ => return None,
+    };
+    for name in names {
+        let mut size: u64 = 0;
+        unsafe {
+            if get_sys_value_by_name(
+                name,
+                &mut mem::size_of::<u64>(),
+                &mut size as *mut u64 as *mut c_void,
+            ) && size != 0
+            {
+                return Some(size);
+            }
+        }
+    }
+    None
+}
+
 #[inline]
 fn get_in_use(cpu_info: *mut i32, offset: isize) -> i64 {
     unsafe {
@@ -236,15 +403,44 @@ fn get_idle(cpu_info: *mut i32, offset: isize) -> i32 {
     unsafe { *cpu_info.offset(offset + libc::CPU_STATE_IDLE as isize) }
 }
 
-pub(crate) fn compute_usage_of_cpu(proc_: &Cpu, cpu_info: *mut i32, offset: isize) -> f32 {
-    let old_cpu_info = proc_.inner.data().cpu_info.0;
+#[inline]
+fn get_nice(cpu_info: *mut i32, offset: isize) -> i32 {
+    unsafe { *cpu_info.offset(offset + libc::CPU_STATE_NICE as isize) }
+}
+
+/// Returns the time spent working and the total time of the CPU at `offset`, in ticks.
+fn get_times(cpu_info: *mut i32, offset: isize) -> (u64, u64) {
+    let in_use = get_in_use(cpu_info, offset).max(0) as u64;
+    let idle = get_idle(cpu_info, offset).max(0) as u64;
+    (in_use, in_use.saturating_add(idle))
+}
+
+fn sum_times(cpus: &[Cpu]) -> (u64, u64) {
+    cpus.iter().fold((0, 0), |(work, total), cpu| {
+        (
+            work.saturating_add(cpu.inner.times.0),
+            total.saturating_add(cpu.inner.times.1),
+        )
+    })
+}
+
+/// Returns the CPU usage and the part of it spent running niced processes.
+pub(crate) fn compute_usage_of_cpu(proc_: &Cpu, cpu_info: *mut i32, offset: isize) -> (f32, f32) {
+    compute_usage(proc_.inner.data().cpu_info.0, cpu_info, offset)
+}
+
+/// Returns the CPU usage and the part of it spent running niced processes between the
+/// `old_cpu_info` and `cpu_info` ticks. If they're the same, the usage since boot is returned.
+fn compute_usage(old_cpu_info: *mut i32, cpu_info: *mut i32, offset: isize) -> (f32, f32) {
     let in_use;
     let idle;
+    let nice;
 
     // In case we are initializing cpus, there is no "old value" yet.
     if old_cpu_info == cpu_info {
         in_use = get_in_use(cpu_info, offset);
         idle = get_idle(cpu_info, offset);
+        nice = get_nice(cpu_info, offset);
     } else {
         let new_in_use = get_in_use(cpu_info, offset);
         let old_in_use = get_in_use(old_cpu_info, offset);
@@ -252,22 +448,31 @@ pub(crate) fn compute_usage_of_cpu(proc_: &Cpu, cpu_info: *mut i32, offset: isiz
         let new_idle = get_idle(cpu_info, offset);
         let old_idle = get_idle(old_cpu_info, offset);
 
+        let new_nice = get_nice(cpu_info, offset);
+        let old_nice = get_nice(old_cpu_info, offset);
+
         in_use = new_in_use.saturating_sub(old_in_use);
         idle = new_idle.saturating_sub(old_idle) as _;
+        nice = new_nice.saturating_sub(old_nice);
     }
     let total = in_use.saturating_add(idle as _);
-    let usage = (in_use as f32 / total as f32) * 100.;
-    if usage.is_nan() {
-        // If divided by zero, avoid returning a NaN
-        0.
-    } else {
-        usage
-    }
+    let percentage = |value: i64| {
+        let usage = ((value as f64 / total as f64) * 100.) as f32;
+        if usage.is_nan() {
+            // If divided by zero, avoid returning a NaN
+            0.
+        } else {
+            usage
+        }
+    };
+    (percentage(in_use), percentage(nice as _))
 }
 
-pub(crate) fn update_cpu_usage<F: FnOnce(Arc<CpuData>, *mut i32) -> (f32, usize)>(
+pub(crate) fn update_cpu_usage<F: FnOnce(Arc<CpuData>, *mut i32) -> (f32, f32, usize)>(
     port: libc::mach_port_t,
     global_cpu: &mut Cpu,
+    alpha: f32,
+    errors: &ErrorReporter,
     f: F,
 ) {
     let mut num_cpu_u = 0u32;
@@ -275,21 +480,29 @@ pub(crate) fn update_cpu_usage<F: FnOnce(Arc<CpuData>, *mut i32) -> (f32, usize)
     let mut num_cpu_info = 0u32;
 
     let mut total_cpu_usage = 0f32;
+    let mut total_nice_usage = 0f32;
 
     unsafe {
-        if host_processor_info(
+        match host_processor_info(
             port,
             libc::PROCESSOR_CPU_LOAD_INFO,
             &mut num_cpu_u as *mut u32,
             &mut cpu_info as *mut *mut i32,
             &mut num_cpu_info as *mut u32,
-        ) == libc::KERN_SUCCESS
-        {
-            let (total_percentage, len) =
-                f(Arc::new(CpuData::new(cpu_info, num_cpu_info)), cpu_info);
-            total_cpu_usage = total_percentage / len as f32;
+        ) {
+            libc::KERN_SUCCESS => {
+                let (total_percentage, total_nice_percentage, len) =
+                    f(Arc::new(CpuData::new(cpu_info, num_cpu_info)), cpu_info);
+                total_cpu_usage = total_percentage / len as f32;
+                total_nice_usage = total_nice_percentage / len as f32;
+            }
+            err => errors.report("host_processor_info", err),
         }
-        global_cpu.inner.set_cpu_usage(total_cpu_usage);
+        let total_cpu_usage =
+            smooth_cpu_usage(global_cpu.inner.cpu_usage(), total_cpu_usage, alpha);
+        global_cpu
+            .inner
+            .set_cpu_usage(total_cpu_usage, total_nice_usage);
     }
 }
 
@@ -298,6 +511,7 @@ pub(crate) fn init_cpus(
     cpus: &mut Vec<Cpu>,
     global_cpu: &mut Cpu,
     refresh_kind: CpuRefreshKind,
+    errors: &ErrorReporter,
 ) {
     let mut num_cpu = 0;
     let mut mib = [libc::CTL_HW as _, libc::HW_NCPU as _];
@@ -315,11 +529,13 @@ pub(crate) fn init_cpus(
             &mut num_cpu as *mut _ as *mut _,
             &mut mib,
         ) {
+            errors.report_errno("sysctl(hw.ncpu)");
             num_cpu = 1;
         }
     }
-    update_cpu_usage(port, global_cpu, |proc_data, cpu_info| {
+    update_cpu_usage(port, global_cpu, 1., errors, |proc_data, cpu_info| {
         let mut percentage = 0f32;
+        let mut nice_percentage = 0f32;
         let mut offset = 0;
         for i in 0..num_cpu {
             let mut cpu = Cpu {
@@ -331,17 +547,20 @@ pub(crate) fn init_cpus(
                     brand.clone(),
                 ),
             };
+            cpu.inner.times = get_times(cpu_info, offset);
             if refresh_kind.cpu_usage() {
-                let cpu_usage = compute_usage_of_cpu(&cpu, cpu_info, offset);
-                cpu.inner.set_cpu_usage(cpu_usage);
+                let (cpu_usage, nice_usage) = compute_usage_of_cpu(&cpu, cpu_info, offset);
+                cpu.inner.set_cpu_usage(cpu_usage, nice_usage);
                 percentage += cpu.cpu_usage();
+                nice_percentage += cpu.nice_usage();
             }
             cpus.push(cpu);
 
             offset += libc::CPU_STATE_MAX as isize;
         }
-        (percentage, cpus.len())
+        (percentage, nice_percentage, cpus.len())
     });
+    global_cpu.inner.times = sum_times(cpus);
 }
 
 fn get_sysctl_str(s: &[u8]) -> String {
@@ -4,30 +4,49 @@ use crate::sys::{
     ffi,
     utils::{self, CFReleaser},
 };
+use crate::unix::utils::is_root_mount_point;
+use crate::utils::TimeoutRunner;
 use crate::{Disk, DiskKind};
 
 use core_foundation_sys::array::CFArrayCreate;
-use core_foundation_sys::base::kCFAllocatorDefault;
+use core_foundation_sys::base::{kCFAllocatorDefault, CFRetain};
 use core_foundation_sys::dictionary::{CFDictionaryGetValueIfPresent, CFDictionaryRef};
 use core_foundation_sys::number::{kCFBooleanTrue, CFBooleanRef, CFNumberGetValue};
 use core_foundation_sys::string::{self as cfs, CFStringRef};
 
 use libc::c_void;
 
-use std::ffi::{CStr, OsStr, OsString};
+use std::ffi::{CStr, CString, OsStr, OsString};
 use std::os::unix::ffi::{OsStrExt, OsStringExt};
 use std::path::{Path, PathBuf};
 use std::ptr;
+use std::time::Instant;
 
 pub(crate) struct DiskInner {
     pub(crate) type_: DiskKind,
     pub(crate) name: OsString,
+    device_name: Option<OsString>,
     pub(crate) file_system: OsString,
     pub(crate) mount_point: PathBuf,
     volume_url: RetainedCFURL,
     pub(crate) total_space: u64,
     pub(crate) available_space: u64,
+    /// Available space before the last refresh.
+    previous_available_space: u64,
+    total_inodes: u64,
+    available_inodes: u64,
     pub(crate) is_removable: bool,
+    is_network: bool,
+    is_boot_disk: bool,
+    is_read_only: bool,
+    is_nosuid: bool,
+    is_noexec: bool,
+    is_encrypted: Option<bool>,
+    is_journaled: Option<bool>,
+    container_id: Option<OsString>,
+    is_snapshot: Option<bool>,
+    is_sealed: Option<bool>,
+    timeout_runner: TimeoutRunner<(Option<u64>, Option<u64>)>,
 }
 
 impl DiskInner {
@@ -39,6 +58,10 @@ impl DiskInner {
         &self.name
     }
 
+    pub(crate) fn device_name(&self) -> Option<&OsStr> {
+        self.device_name.as_deref()
+    }
+
     pub(crate) fn file_system(&self) -> &OsStr {
         &self.file_system
     }
@@ -55,29 +78,139 @@ impl DiskInner {
         self.available_space
     }
 
+    pub(crate) fn previous_available_space(&self) -> u64 {
+        self.previous_available_space
+    }
+
+    pub(crate) fn total_inodes(&self) -> u64 {
+        self.total_inodes
+    }
+
+    pub(crate) fn available_inodes(&self) -> u64 {
+        self.available_inodes
+    }
+
     pub(crate) fn is_removable(&self) -> bool {
         self.is_removable
     }
 
+    pub(crate) fn is_network(&self) -> bool {
+        self.is_network
+    }
+
+    pub(crate) fn is_boot_disk(&self) -> bool {
+        self.is_boot_disk
+    }
+
+    pub(crate) fn is_read_only(&self) -> bool {
+        self.is_read_only
+    }
+
+    pub(crate) fn is_nosuid(&self) -> bool {
+        self.is_nosuid
+    }
+
+    pub(crate) fn is_noexec(&self) -> bool {
+        self.is_noexec
+    }
+
+    pub(crate) fn is_encrypted(&self) -> Option<bool> {
+        self.is_encrypted
+    }
+
+    pub(crate) fn is_journaled(&self) -> Option<bool> {
+        self.is_journaled
+    }
+
+    pub(crate) fn container_id(&self) -> Option<&OsStr> {
+        self.container_id.as_deref()
+    }
+
+    pub(crate) fn is_snapshot(&self) -> Option<bool> {
+        self.is_snapshot
+    }
+
+    pub(crate) fn is_sealed(&self) -> Option<bool> {
+        self.is_sealed
+    }
+
+    pub(crate) fn is_timed_out(&self) -> bool {
+        self.timeout_runner.is_timed_out()
+    }
+
     pub(crate) fn refresh(&mut self) -> bool {
-        unsafe {
-            if let Some(requested_properties) = build_requested_properties(&[
-                ffi::kCFURLVolumeAvailableCapacityKey,
-                ffi::kCFURLVolumeAvailableCapacityForImportantUsageKey,
-            ]) {
-                match get_disk_properties(&self.volume_url, &requested_properties) {
-                    Some(disk_props) => {
-                        self.available_space = get_available_volume_space(&disk_props);
-                        true
-                    }
-                    None => {
-                        sysinfo_debug!("Failed to get disk properties");
-                        false
-                    }
-                }
-            } else {
-                sysinfo_debug!("failed to create volume key list, skipping refresh");
-                false
+        self.timeout_runner.reset();
+        let available_space = refresh_available_space(&self.volume_url);
+        let available_inodes = get_available_inodes(&self.mount_point);
+        self.update_available(available_space, available_inodes)
+    }
+
+    pub(crate) fn start_refresh(&mut self) {
+        // The URL is retained so the thread can keep using it if we stop waiting for it.
+        let volume_url: Option<RetainedCFURL> =
+            CFReleaser::new(unsafe { CFRetain(self.volume_url.inner().cast()) }.cast());
+        let Some(volume_url) = volume_url else {
+            return;
+        };
+        let mount_point = self.mount_point.clone();
+        self.timeout_runner.start(move || {
+            (
+                refresh_available_space(&volume_url),
+                get_available_inodes(&mount_point),
+            )
+        });
+    }
+
+    pub(crate) fn wait_refresh(&mut self, deadline: Instant) -> bool {
+        let (available_space, available_inodes) =
+            self.timeout_runner.wait(deadline).unwrap_or_default();
+        self.update_available(available_space, available_inodes)
+    }
+
+    fn update_available(
+        &mut self,
+        available_space: Option<u64>,
+        available_inodes: Option<u64>,
+    ) -> bool {
+        if let Some(available_inodes) = available_inodes {
+            self.available_inodes = available_inodes;
+        }
+        if let Some(available_space) = available_space {
+            self.previous_available_space = self.available_space;
+            self.available_space = available_space;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+fn get_available_inodes(mount_point: &Path) -> Option<u64> {
+    let mount_point = CString::new(mount_point.as_os_str().as_bytes()).ok()?;
+    unsafe {
+        let mut stat: libc::statfs = std::mem::zeroed();
+        if libc::statfs(mount_point.as_ptr(), &mut stat) == 0 {
+            Some(stat.f_ffree)
+        } else {
+            None
+        }
+    }
+}
+
+fn refresh_available_space(volume_url: &RetainedCFURL) -> Option<u64> {
+    unsafe {
+        let Some(requested_properties) = build_requested_properties(&[
+            ffi::kCFURLVolumeAvailableCapacityKey,
+            ffi::kCFURLVolumeAvailableCapacityForImportantUsageKey,
+        ]) else {
+            sysinfo_debug!("failed to create volume key list, skipping refresh");
+            return None;
+        };
+        match get_disk_properties(volume_url, &requested_properties) {
+            Some(disk_props) => Some(get_available_volume_space(&disk_props)),
+            None => {
+                sysinfo_debug!("Failed to get disk properties");
+                None
             }
         }
     }
@@ -87,6 +220,7 @@ impl crate::DisksInner {
     pub(crate) fn new() -> Self {
         Self {
             disks: Vec::with_capacity(2),
+            list_timeout_runner: TimeoutRunner::default(),
         }
     }
 
@@ -137,6 +271,7 @@ unsafe fn get_list(container: &mut Vec<Disk>) {
         ffi::kCFURLVolumeNameKey,
         ffi::kCFURLVolumeIsBrowsableKey,
         ffi::kCFURLVolumeIsLocalKey,
+        ffi::kCFURLVolumeIsEncryptedKey,
     ]) {
         Some(properties) => properties,
         None => {
@@ -145,6 +280,11 @@ unsafe fn get_list(container: &mut Vec<Disk>) {
         }
     };
 
+    // Many volumes (like the APFS ones) share the same physical disk, so its kind is only
+    // retrieved once.
+    #[cfg(target_os = "macos")]
+    let mut disk_types = crate::sys::inner::disk::DiskTypes::new();
+
     for c_disk in raw_disks {
         let volume_url = match CFReleaser::new(
             core_foundation_sys::url::CFURLCreateFromFileSystemRepresentation(
@@ -203,12 +343,74 @@ unsafe fn get_list(container: &mut Vec<Disk>) {
             CStr::from_ptr(c_disk.f_mntonname.as_ptr()).to_bytes(),
         ));
 
-        if let Some(disk) = new_disk(mount_point, volume_url, c_disk, &prop_dict) {
+        // IOKit is not available on any but the most recent (16+) iOS and iPadOS versions.
+        // Due to this, we can't query the medium type. All iOS devices use flash-based storage
+        // so we just assume the disk type is an SSD until Rust has a way to conditionally link to
+        // IOKit in more recent deployment versions.
+        #[cfg(target_os = "macos")]
+        let type_ = crate::sys::inner::disk::get_disk_type(&c_disk, &mut disk_types)
+            .unwrap_or(DiskKind::Unknown(-1));
+        #[cfg(not(target_os = "macos"))]
+        let type_ = DiskKind::SSD;
+
+        if let Some(disk) = new_disk(mount_point, volume_url, type_, c_disk, &prop_dict) {
             container.push(disk);
         }
     }
 }
 
+/// Returns the BSD name of the whole disk the `bsd_name` partition belongs to (for example,
+/// `disk3s1s1` returns `disk3`).
+pub(crate) fn whole_disk_name(bsd_name: &[u8]) -> &[u8] {
+    let Some(number) = bsd_name.strip_prefix(b"disk") else {
+        return bsd_name;
+    };
+    let digits = number.iter().take_while(|c| c.is_ascii_digit()).count();
+    &bsd_name[..b"disk".len() + digits]
+}
+
+/// Returns whether the volume is sealed (its content is cryptographically verified), or `None` if
+/// it couldn't be retrieved.
+fn is_sealed_volume(disk: &libc::statfs) -> Option<bool> {
+    let mut attrs = libc::attrlist {
+        bitmapcount: libc::ATTR_BIT_MAP_COUNT,
+        reserved: 0,
+        commonattr: 0,
+        volattr: libc::ATTR_VOL_INFO | libc::ATTR_VOL_CAPABILITIES,
+        dirattr: 0,
+        fileattr: 0,
+        forkattr: 0,
+    };
+    let mut buf = std::mem::MaybeUninit::<ffi::VolCapabilitiesAttrBuf>::zeroed();
+    unsafe {
+        if libc::getattrlist(
+            disk.f_mntonname.as_ptr(),
+            &mut attrs as *mut libc::attrlist as *mut c_void,
+            buf.as_mut_ptr() as *mut c_void,
+            std::mem::size_of::<ffi::VolCapabilitiesAttrBuf>(),
+            0,
+        ) != 0
+        {
+            sysinfo_debug!("getattrlist failed");
+            return None;
+        }
+        let caps = buf.assume_init().capabilities;
+        let format = libc::VOL_CAPABILITIES_FORMAT;
+        if caps.valid[format] & ffi::VOL_CAP_FMT_SEALED == 0 {
+            // The system doesn't know about sealed volumes.
+            return None;
+        }
+        Some(caps.capabilities[format] & ffi::VOL_CAP_FMT_SEALED != 0)
+    }
+}
+
+/// Returns the BSD name of the device the disk is mounted from (for example, `disk3s1s1`).
+pub(crate) fn bsd_name(disk: &libc::statfs) -> Option<&[u8]> {
+    unsafe { CStr::from_ptr(disk.f_mntfromname.as_ptr()) }
+        .to_bytes()
+        .strip_prefix(b"/dev/")
+}
+
 type RetainedCFArray = CFReleaser<core_foundation_sys::array::__CFArray>;
 type RetainedCFDictionary = CFReleaser<core_foundation_sys::dictionary::__CFDictionary>;
 type RetainedCFURL = CFReleaser<core_foundation_sys::url::__CFURL>;
@@ -262,7 +464,7 @@ pub(super) enum DictKey {
     Defined(&'static str),
 }
 
-unsafe fn get_dict_value<T, F: FnOnce(*const c_void) -> Option<T>>(
+pub(super) unsafe fn get_dict_value<T, F: FnOnce(*const c_void) -> Option<T>>(
     dict: CFDictionaryRef,
     key: DictKey,
     callback: F,
@@ -297,30 +499,7 @@ unsafe fn get_dict_value<T, F: FnOnce(*const c_void) -> Option<T>>(
 
 pub(super) unsafe fn get_str_value(dict: CFDictionaryRef, key: DictKey) -> Option<String> {
     get_dict_value(dict, key, |v| {
-        let v = v as cfs::CFStringRef;
-
-        let len_utf16 = cfs::CFStringGetLength(v) as usize;
-        let len_bytes = len_utf16 * 2; // Two bytes per UTF-16 codepoint.
-
-        let v_ptr = cfs::CFStringGetCStringPtr(v, cfs::kCFStringEncodingUTF8);
-        if v_ptr.is_null() {
-            // Fallback on CFStringGetString to read the underlying bytes from the CFString.
-            let mut buf = vec![0; len_bytes];
-            let success = cfs::CFStringGetCString(
-                v,
-                buf.as_mut_ptr(),
-                len_bytes as _,
-                cfs::kCFStringEncodingUTF8,
-            );
-
-            if success != 0 {
-                utils::vec_to_rust(buf)
-            } else {
-                None
-            }
-        } else {
-            crate::unix::utils::cstr_to_rust_with_size(v_ptr, Some(len_bytes))
-        }
+        utils::cfstring_to_string(v as cfs::CFStringRef)
     })
 }
 
@@ -328,7 +507,7 @@ unsafe fn get_bool_value(dict: CFDictionaryRef, key: DictKey) -> Option<bool> {
     get_dict_value(dict, key, |v| Some(v as CFBooleanRef == kCFBooleanTrue))
 }
 
-unsafe fn get_int_value(dict: CFDictionaryRef, key: DictKey) -> Option<i64> {
+pub(super) unsafe fn get_int_value(dict: CFDictionaryRef, key: DictKey) -> Option<i64> {
     get_dict_value(dict, key, |v| {
         let mut val: i64 = 0;
         if CFNumberGetValue(
@@ -346,18 +525,10 @@ unsafe fn get_int_value(dict: CFDictionaryRef, key: DictKey) -> Option<i64> {
 unsafe fn new_disk(
     mount_point: PathBuf,
     volume_url: RetainedCFURL,
+    type_: DiskKind,
     c_disk: libc::statfs,
     disk_props: &RetainedCFDictionary,
 ) -> Option<Disk> {
-    // IOKit is not available on any but the most recent (16+) iOS and iPadOS versions.
-    // Due to this, we can't query the medium type. All iOS devices use flash-based storage
-    // so we just assume the disk type is an SSD until Rust has a way to conditionally link to
-    // IOKit in more recent deployment versions.
-    #[cfg(target_os = "macos")]
-    let type_ = crate::sys::inner::disk::get_disk_type(&c_disk).unwrap_or(DiskKind::Unknown(-1));
-    #[cfg(not(target_os = "macos"))]
-    let type_ = DiskKind::SSD;
-
     // Note: Since we requested these properties from the system, we don't expect
     // these property retrievals to fail.
 
@@ -404,6 +575,13 @@ unsafe fn new_disk(
 
     let available_space = get_available_volume_space(disk_props);
 
+    let is_encrypted = get_bool_value(
+        disk_props.inner(),
+        DictKey::Extern(ffi::kCFURLVolumeIsEncryptedKey),
+    );
+    let is_journaled = Some(c_disk.f_flags & libc::MNT_JOURNALED as u32 != 0);
+    let is_local = c_disk.f_flags & libc::MNT_LOCAL as u32 != 0;
+
     let file_system = {
         let len = c_disk
             .f_fstypename
@@ -418,16 +596,65 @@ unsafe fn new_disk(
         )
     };
 
+    let is_network = !is_local || matches!(file_system.as_bytes(), b"nfs" | b"smbfs" | b"afpfs");
+    // The APFS volumes are partitions of their container's synthesized whole disk, so its BSD
+    // name identifies the container.
+    let is_apfs = file_system.as_bytes() == b"apfs";
+    let container_id = if is_apfs {
+        bsd_name(&c_disk).map(|name| OsStr::from_bytes(whole_disk_name(name)).to_owned())
+    } else {
+        None
+    };
+    // Since macOS 11, the system volume is booted from a (sealed) snapshot of the volume.
+    let (is_snapshot, is_sealed) = if is_apfs {
+        (
+            Some(c_disk.f_flags & libc::MNT_SNAPSHOT as u32 != 0),
+            is_sealed_volume(&c_disk),
+        )
+    } else {
+        (None, None)
+    };
+    // The sealed APFS system volume is the one mounted at `/`.
+    let is_boot_disk = !is_network && is_root_mount_point(&mount_point);
+
     Some(Disk {
         inner: DiskInner {
             type_,
             name,
+            device_name: bsd_name(&c_disk).map(|name| OsStr::from_bytes(name).to_owned()),
             file_system,
             mount_point,
             volume_url,
             total_space,
             available_space,
+            previous_available_space: available_space,
+            total_inodes: c_disk.f_files,
+            available_inodes: c_disk.f_ffree,
             is_removable,
+            is_network,
+            is_boot_disk,
+            is_read_only: c_disk.f_flags & libc::MNT_RDONLY as u32 != 0,
+            is_nosuid: c_disk.f_flags & libc::MNT_NOSUID as u32 != 0,
+            is_noexec: c_disk.f_flags & libc::MNT_NOEXEC as u32 != 0,
+            is_encrypted,
+            is_journaled,
+            container_id,
+            is_snapshot,
+            is_sealed,
+            timeout_runner: TimeoutRunner::default(),
         },
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::whole_disk_name;
+
+    #[test]
+    fn check_whole_disk_name() {
+        assert_eq!(whole_disk_name(b"disk3"), b"disk3");
+        assert_eq!(whole_disk_name(b"disk3s1"), b"disk3");
+        assert_eq!(whole_disk_name(b"disk12s1s1"), b"disk12");
+        assert_eq!(whole_disk_name(b"something"), b"something");
+    }
+}
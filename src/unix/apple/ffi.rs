@@ -26,6 +26,65 @@ extern "C" {
     pub static kCFURLVolumeIsLocalKey: CFStringRef;
     pub static kCFURLVolumeIsInternalKey: CFStringRef;
     pub static kCFURLVolumeIsBrowsableKey: CFStringRef;
+    pub static kCFURLVolumeIsEncryptedKey: CFStringRef;
+}
+
+// Defined in `sys/attr.h`, not available in `libc`.
+pub const VOL_CAP_FMT_SEALED: libc::attrgroup_t = 0x02000000;
+
+/// Buffer filled by `getattrlist` when only `ATTR_VOL_CAPABILITIES` is requested.
+#[repr(C)]
+pub struct VolCapabilitiesAttrBuf {
+    pub length: u32,
+    pub capabilities: libc::vol_capabilities_attr_t,
+}
+
+// Defined in `notify.h`.
+pub const NOTIFY_STATUS_OK: u32 = 0;
+
+extern "C" {
+    pub fn notify_register_check(name: *const libc::c_char, out_token: *mut libc::c_int) -> u32;
+    pub fn notify_get_state(token: libc::c_int, state: *mut u64) -> u32;
+    pub fn notify_cancel(token: libc::c_int) -> u32;
+}
+
+// Defined in `dispatch/source.h`.
+#[allow(non_camel_case_types)]
+pub type dispatch_object_t = *mut libc::c_void;
+#[allow(non_camel_case_types)]
+pub type dispatch_function_t = extern "C" fn(context: *mut libc::c_void);
+
+pub const DISPATCH_MEMORYPRESSURE_NORMAL: libc::uintptr_t = 0x01;
+pub const DISPATCH_MEMORYPRESSURE_WARN: libc::uintptr_t = 0x02;
+pub const DISPATCH_MEMORYPRESSURE_CRITICAL: libc::uintptr_t = 0x04;
+
+extern "C" {
+    // `DISPATCH_SOURCE_TYPE_MEMORYPRESSURE` is a pointer to this symbol.
+    pub static _dispatch_source_type_memorypressure: libc::c_void;
+
+    pub fn dispatch_get_global_queue(
+        identifier: libc::intptr_t,
+        flags: libc::uintptr_t,
+    ) -> dispatch_object_t;
+    pub fn dispatch_source_create(
+        type_: *const libc::c_void,
+        handle: libc::uintptr_t,
+        mask: libc::uintptr_t,
+        queue: dispatch_object_t,
+    ) -> dispatch_object_t;
+    pub fn dispatch_set_context(object: dispatch_object_t, context: *mut libc::c_void);
+    pub fn dispatch_source_set_event_handler_f(
+        source: dispatch_object_t,
+        handler: dispatch_function_t,
+    );
+    pub fn dispatch_source_set_cancel_handler_f(
+        source: dispatch_object_t,
+        handler: dispatch_function_t,
+    );
+    pub fn dispatch_source_get_data(source: dispatch_object_t) -> libc::uintptr_t;
+    pub fn dispatch_source_cancel(source: dispatch_object_t);
+    pub fn dispatch_resume(object: dispatch_object_t);
+    pub fn dispatch_release(object: dispatch_object_t);
 }
 
 #[cfg_attr(feature = "debug", derive(Eq, Hash, PartialEq))]
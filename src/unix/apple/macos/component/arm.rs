@@ -165,6 +165,11 @@ impl ComponentInner {
         &self.label
     }
 
+    pub(crate) fn is_cpu(&self) -> bool {
+        // The die sensors and the performance/efficiency cores clusters (`pACC`/`eACC`) ones.
+        self.label.starts_with("PMU tdie") || self.label.contains("ACC MTR")
+    }
+
     pub(crate) fn refresh(&mut self) {
         unsafe {
             let event = match CFReleaser::new(IOHIDServiceClientCopyEvent(
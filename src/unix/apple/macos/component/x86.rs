@@ -1,12 +1,9 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::sys::{ffi, macos::utils::IOReleaser};
+use crate::sys::ffi;
+use crate::sys::macos::smc::{get_key_size, read_key, IoService};
 use crate::Component;
 
-use libc::{c_char, c_int, c_void};
-
-use std::mem;
-
 const COMPONENTS_TEMPERATURE_IDS: &[(&str, &[i8])] = &[
     ("PECI CPU", &['T' as i8, 'C' as i8, 'X' as i8, 'C' as i8]), // PECI CPU "TCXC"
     ("PECI CPU", &['T' as i8, 'C' as i8, 'X' as i8, 'c' as i8]), // PECI CPU "TCXc"
@@ -48,6 +45,9 @@ impl ComponentFFI {
 pub(crate) struct ComponentsInner {
     components: Vec<Component>,
     connection: Option<IoService>,
+    /// SMC keys registered with `Components::add_temperature_sensor`, read in addition to
+    /// `COMPONENTS_TEMPERATURE_IDS`.
+    extra_temperature_ids: Vec<(String, [i8; 4])>,
 }
 
 impl ComponentsInner {
@@ -55,6 +55,7 @@ impl ComponentsInner {
         Self {
             components: Vec::with_capacity(2),
             connection: IoService::new_connection(),
+            extra_temperature_ids: Vec::new(),
         }
     }
 
@@ -62,6 +63,7 @@ impl ComponentsInner {
         Self {
             components,
             connection: IoService::new_connection(),
+            extra_temperature_ids: Vec::new(),
         }
     }
 
@@ -77,6 +79,18 @@ impl ComponentsInner {
         &mut self.components
     }
 
+    pub(crate) fn add_temperature_sensor(&mut self, key: [u8; 4], label: &str) {
+        let key = key.map(|c| c as i8);
+        match self
+            .extra_temperature_ids
+            .iter_mut()
+            .find(|(_, k)| *k == key)
+        {
+            Some((old_label, _)) => *old_label = label.to_owned(),
+            None => self.extra_temperature_ids.push((label.to_owned(), key)),
+        }
+    }
+
     pub(crate) fn refresh_list(&mut self) {
         if let Some(ref connection) = self.connection {
             let connection = connection.inner();
@@ -85,9 +99,13 @@ impl ComponentsInner {
             let critical_temp =
                 get_temperature(connection, &['T' as i8, 'C' as i8, '0' as i8, 'D' as i8, 0]);
 
-            for (id, v) in COMPONENTS_TEMPERATURE_IDS.iter() {
+            let extra_ids = self
+                .extra_temperature_ids
+                .iter()
+                .map(|(id, v)| (id.as_str(), v.as_slice()));
+            for (id, v) in COMPONENTS_TEMPERATURE_IDS.iter().copied().chain(extra_ids) {
                 if let Some(c) =
-                    ComponentInner::new((*id).to_owned(), None, critical_temp, v, connection)
+                    ComponentInner::new(id.to_owned(), None, critical_temp, v, connection)
                 {
                     self.components.push(Component { inner: c });
                 }
@@ -139,6 +157,10 @@ impl ComponentInner {
         &self.label
     }
 
+    pub(crate) fn is_cpu(&self) -> bool {
+        self.label.contains("CPU")
+    }
+
     pub(crate) fn refresh(&mut self) {
         if let Some(temp) = self.ffi_part.temperature() {
             self.temperature = temp;
@@ -149,100 +171,6 @@ impl ComponentInner {
     }
 }
 
-unsafe fn perform_call(
-    conn: ffi::io_connect_t,
-    index: c_int,
-    input_structure: *const ffi::KeyData_t,
-    output_structure: *mut ffi::KeyData_t,
-) -> i32 {
-    let mut structure_output_size = mem::size_of::<ffi::KeyData_t>();
-
-    ffi::IOConnectCallStructMethod(
-        conn,
-        index as u32,
-        input_structure,
-        mem::size_of::<ffi::KeyData_t>(),
-        output_structure,
-        &mut structure_output_size,
-    )
-}
-
-// Adapted from https://github.com/lavoiesl/osx-cpu-temp/blob/master/smc.c#L28
-#[inline]
-fn strtoul(s: &[i8]) -> u32 {
-    unsafe {
-        ((*s.get_unchecked(0) as u32) << (3u32 << 3))
-            + ((*s.get_unchecked(1) as u32) << (2u32 << 3))
-            + ((*s.get_unchecked(2) as u32) << (1u32 << 3))
-            + (*s.get_unchecked(3) as u32)
-    }
-}
-
-#[inline]
-unsafe fn ultostr(s: *mut c_char, val: u32) {
-    *s.offset(0) = ((val >> 24) % 128) as i8;
-    *s.offset(1) = ((val >> 16) % 128) as i8;
-    *s.offset(2) = ((val >> 8) % 128) as i8;
-    *s.offset(3) = (val % 128) as i8;
-    *s.offset(4) = 0;
-}
-
-unsafe fn get_key_size(
-    con: ffi::io_connect_t,
-    key: &[i8],
-) -> Result<(ffi::KeyData_t, ffi::Val_t), i32> {
-    let mut input_structure: ffi::KeyData_t = mem::zeroed::<ffi::KeyData_t>();
-    let mut output_structure: ffi::KeyData_t = mem::zeroed::<ffi::KeyData_t>();
-    let mut val: ffi::Val_t = mem::zeroed::<ffi::Val_t>();
-
-    input_structure.key = strtoul(key);
-    input_structure.data8 = ffi::SMC_CMD_READ_KEYINFO;
-
-    let result = perform_call(
-        con,
-        ffi::KERNEL_INDEX_SMC,
-        &input_structure,
-        &mut output_structure,
-    );
-    if result != ffi::KIO_RETURN_SUCCESS {
-        return Err(result);
-    }
-
-    val.data_size = output_structure.key_info.data_size;
-    ultostr(
-        val.data_type.as_mut_ptr(),
-        output_structure.key_info.data_type,
-    );
-    input_structure.key_info.data_size = val.data_size;
-    input_structure.data8 = ffi::SMC_CMD_READ_BYTES;
-    Ok((input_structure, val))
-}
-
-unsafe fn read_key(
-    con: ffi::io_connect_t,
-    input_structure: &ffi::KeyData_t,
-    mut val: ffi::Val_t,
-) -> Result<ffi::Val_t, i32> {
-    let mut output_structure: ffi::KeyData_t = mem::zeroed::<ffi::KeyData_t>();
-
-    match perform_call(
-        con,
-        ffi::KERNEL_INDEX_SMC,
-        input_structure,
-        &mut output_structure,
-    ) {
-        ffi::KIO_RETURN_SUCCESS => {
-            libc::memcpy(
-                val.bytes.as_mut_ptr() as *mut c_void,
-                output_structure.bytes.as_mut_ptr() as *mut c_void,
-                mem::size_of::<[u8; 32]>(),
-            );
-            Ok(val)
-        }
-        result => Err(result),
-    }
-}
-
 fn get_temperature_inner(
     con: ffi::io_connect_t,
     input_structure: &ffi::KeyData_t,
@@ -268,79 +196,3 @@ fn get_temperature(con: ffi::io_connect_t, key: &[i8]) -> Option<f32> {
         get_temperature_inner(con, &input_structure, &val)
     }
 }
-
-pub(crate) struct IoService(ffi::io_connect_t);
-
-impl IoService {
-    fn new(obj: ffi::io_connect_t) -> Option<Self> {
-        if obj == 0 {
-            None
-        } else {
-            Some(Self(obj))
-        }
-    }
-
-    pub(crate) fn inner(&self) -> ffi::io_connect_t {
-        self.0
-    }
-
-    // code from https://github.com/Chris911/iStats
-    // Not supported on iOS, or in the default macOS
-    pub(crate) fn new_connection() -> Option<Self> {
-        let mut iterator: ffi::io_iterator_t = 0;
-
-        unsafe {
-            let matching_dictionary = ffi::IOServiceMatching(b"AppleSMC\0".as_ptr() as *const i8);
-            if matching_dictionary.is_null() {
-                sysinfo_debug!("IOServiceMatching call failed, `AppleSMC` not found");
-                return None;
-            }
-            let result = ffi::IOServiceGetMatchingServices(
-                ffi::kIOMasterPortDefault,
-                matching_dictionary,
-                &mut iterator,
-            );
-            if result != ffi::KIO_RETURN_SUCCESS {
-                sysinfo_debug!("Error: IOServiceGetMatchingServices() = {}", result);
-                return None;
-            }
-            let iterator = match IOReleaser::new(iterator) {
-                Some(i) => i,
-                None => {
-                    sysinfo_debug!("Error: IOServiceGetMatchingServices() succeeded but returned invalid descriptor");
-                    return None;
-                }
-            };
-
-            let device = match IOReleaser::new(ffi::IOIteratorNext(iterator.inner())) {
-                Some(d) => d,
-                None => {
-                    sysinfo_debug!("Error: no SMC found");
-                    return None;
-                }
-            };
-
-            let mut conn = 0;
-            let result = ffi::IOServiceOpen(device.inner(), libc::mach_task_self(), 0, &mut conn);
-            if result != ffi::KIO_RETURN_SUCCESS {
-                sysinfo_debug!("Error: IOServiceOpen() = {}", result);
-                return None;
-            }
-            let conn = IoService::new(conn);
-            if conn.is_none() {
-                sysinfo_debug!(
-                    "Error: IOServiceOpen() succeeded but returned invalid descriptor..."
-                );
-            }
-            conn
-        }
-    }
-}
-
-impl Drop for IoService {
-    fn drop(&mut self) {
-        unsafe {
-            ffi::IOServiceClose(self.0);
-        }
-    }
-}
@@ -2,7 +2,7 @@
 
 use crate::sys::ffi;
 use crate::sys::{
-    disk::{get_str_value, DictKey},
+    disk::{bsd_name, get_str_value, whole_disk_name, DictKey},
     macos::utils::IOReleaser,
     utils::CFReleaser,
 };
@@ -11,9 +11,35 @@ use crate::DiskKind;
 use core_foundation_sys::base::{kCFAllocatorDefault, kCFAllocatorNull};
 use core_foundation_sys::string as cfs;
 
-use std::ffi::CStr;
+use std::collections::HashMap;
+
+/// Cache of the disk kinds, indexed by the BSD name of the whole disk (like `disk3`).
+///
+/// It only lives for the duration of a disk list refresh.
+pub(crate) type DiskTypes = HashMap<Vec<u8>, Option<DiskKind>>;
+
+/// Returns the kind of the disk, using the `disk_types` cache if possible.
+///
+/// Walking the IOKit registry tree from the media up to the device which holds the storage
+/// characteristics costs a few IOKit calls per level. To prevent doing it for every APFS volume
+/// and snapshot, it is only done once per whole disk (the "Whole" `IOMedia` object) and the
+/// result is shared by all its partitions.
+pub(crate) fn get_disk_type(disk: &libc::statfs, disk_types: &mut DiskTypes) -> Option<DiskKind> {
+    let bsd_name = bsd_name(disk).or_else(|| {
+        sysinfo_debug!("unknown disk mount path format");
+        None
+    })?;
+    let whole_disk = whole_disk_name(bsd_name);
+
+    if let Some(disk_type) = disk_types.get(whole_disk) {
+        return *disk_type;
+    }
+    let disk_type = get_whole_disk_type(whole_disk);
+    disk_types.insert(whole_disk.to_vec(), disk_type);
+    disk_type
+}
 
-pub(crate) fn get_disk_type(disk: &libc::statfs) -> Option<DiskKind> {
+fn get_whole_disk_type(bsd_name: &[u8]) -> Option<DiskKind> {
     let characteristics_string = unsafe {
         CFReleaser::new(cfs::CFStringCreateWithBytesNoCopy(
             kCFAllocatorDefault,
@@ -25,16 +51,8 @@ pub(crate) fn get_disk_type(disk: &libc::statfs) -> Option<DiskKind> {
         ))?
     };
 
-    // Removes `/dev/` from the value.
-    let bsd_name = unsafe {
-        CStr::from_ptr(disk.f_mntfromname.as_ptr())
-            .to_bytes()
-            .strip_prefix(b"/dev/")
-            .or_else(|| {
-                sysinfo_debug!("unknown disk mount path format");
-                None
-            })?
-    };
+    // The name needs to be NUL terminated.
+    let bsd_name = [bsd_name, b"\0"].concat();
 
     // We don't need to wrap this in an auto-releaser because the following call to `IOServiceGetMatchingServices`
     // will take ownership of one retain reference.
@@ -1,5 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+#[cfg(not(feature = "apple-sandbox"))]
+use core_foundation_sys::base::CFTypeRef;
 use core_foundation_sys::base::{mach_port_t, CFAllocatorRef};
 use core_foundation_sys::dictionary::{CFDictionaryRef, CFMutableDictionaryRef};
 use core_foundation_sys::string::CFStringRef;
@@ -34,6 +36,24 @@ pub const kIOPropertyMediumTypeKey: &str = "Medium Type";
 pub const kIOPropertyMediumTypeSolidStateKey: &str = "Solid State";
 #[allow(non_upper_case_globals)]
 pub const kIOPropertyMediumTypeRotationalKey: &str = "Rotational";
+#[cfg(not(feature = "apple-sandbox"))]
+#[allow(non_upper_case_globals)]
+pub const kIOAcceleratorPerformanceStatisticsKey: &str = "PerformanceStatistics";
+#[cfg(not(feature = "apple-sandbox"))]
+#[allow(non_upper_case_globals)]
+pub const kIOAcceleratorDeviceUtilizationKey: &str = "Device Utilization %";
+#[cfg(all(not(feature = "apple-sandbox"), feature = "processes"))]
+#[allow(non_upper_case_globals)]
+pub const kIOAcceleratorAppUsageKey: &str = "AppUsage";
+#[cfg(all(not(feature = "apple-sandbox"), feature = "processes"))]
+#[allow(non_upper_case_globals)]
+pub const kIOAcceleratorAccumulatedGPUTimeKey: &str = "accumulatedGPUTime";
+#[cfg(all(not(feature = "apple-sandbox"), feature = "processes"))]
+#[allow(non_upper_case_globals)]
+pub const kIOUserClientCreatorKey: &str = "IOUserClientCreator";
+#[cfg(not(feature = "apple-sandbox"))]
+#[allow(non_upper_case_globals)]
+pub const kIOPlatformUUIDKey: &str = "IOPlatformUUID";
 
 // Based on https://github.com/libusb/libusb/blob/bed8d3034eac74a6e1ba123b5c270ea63cb6cf1a/libusb/os/darwin_usb.c#L54-L55,
 // we can simply set it to 0 (and is the same value as its replacement `kIOMainPortDefault`).
@@ -50,6 +70,11 @@ extern "C" {
     ) -> kern_return_t;
     #[allow(dead_code)]
     pub fn IOServiceMatching(a: *const c_char) -> CFMutableDictionaryRef;
+    #[allow(dead_code)]
+    pub fn IOServiceGetMatchingService(
+        mainPort: mach_port_t,
+        matching: CFMutableDictionaryRef,
+    ) -> io_object_t;
 
     pub fn IOIteratorNext(iterator: io_iterator_t) -> io_object_t;
 
@@ -66,6 +91,12 @@ extern "C" {
         plane: io_name_t,
         parent: *mut io_registry_entry_t,
     ) -> kern_return_t;
+    #[cfg(all(not(feature = "apple-sandbox"), feature = "processes"))]
+    pub fn IORegistryEntryGetChildIterator(
+        entry: io_registry_entry_t,
+        plane: io_name_t,
+        iterator: *mut io_iterator_t,
+    ) -> kern_return_t;
     #[allow(dead_code)]
     pub fn IORegistryEntryGetName(entry: io_registry_entry_t, name: io_name_t) -> kern_return_t;
 
@@ -78,6 +109,327 @@ extern "C" {
 #[allow(dead_code)]
 pub const KIO_RETURN_SUCCESS: i32 = 0;
 
+// Defined in `IOKit/ps/IOPSKeys.h`.
+#[cfg(not(feature = "apple-sandbox"))]
+#[allow(non_upper_case_globals)]
+pub const kIOPMACPowerKey: &str = "AC Power";
+#[cfg(not(feature = "apple-sandbox"))]
+#[allow(non_upper_case_globals)]
+pub const kIOPMBatteryPowerKey: &str = "Battery Power";
+#[cfg(not(feature = "apple-sandbox"))]
+#[allow(non_upper_case_globals)]
+pub const kIOPMUPSPowerKey: &str = "UPS Power";
+
+#[cfg(not(feature = "apple-sandbox"))]
+#[link(name = "IOKit", kind = "framework")]
+extern "C" {
+    pub fn IOPSCopyPowerSourcesInfo() -> CFTypeRef;
+    pub fn IOPSGetProvidingPowerSourceType(snapshot: CFTypeRef) -> CFStringRef;
+    // Only defined in `IOKit/pwr_mgt/IOPMLibPrivate.h`, it's used by `pmset`.
+    pub fn IOPMCopyActivePMPreferences() -> CFDictionaryRef;
+    pub fn IOPMCopyCPUPowerStatus(cpu_power_status: *mut CFDictionaryRef) -> i32;
+}
+
+// Key of the CPU power status, defined in `IOKit/pwr_mgt/IOPMLib.h`.
+#[cfg(not(feature = "apple-sandbox"))]
+#[allow(non_upper_case_globals)]
+pub const kIOPMCPUPowerLimitProcessorSpeedKey: &str = "CPU_Speed_Limit";
+
+// Key of the power management settings, defined in `IOKit/pwr_mgt/IOPMPrivate.h`.
+#[cfg(not(feature = "apple-sandbox"))]
+#[allow(non_upper_case_globals)]
+pub const kIOPMLowPowerModeKey: &str = "LowPowerMode";
+
+// Defined in `sys/proc_info.h`.
+#[cfg(not(feature = "apple-sandbox"))]
+pub const PROC_FLAG_TRACED: u32 = 2;
+
+// Defined in `sys/resource.h`.
+#[cfg(not(feature = "apple-sandbox"))]
+pub const IOPOL_TYPE_DISK: libc::c_int = 0;
+#[cfg(not(feature = "apple-sandbox"))]
+pub const IOPOL_SCOPE_PROCESS: libc::c_int = 0;
+#[cfg(not(feature = "apple-sandbox"))]
+pub const IOPOL_DEFAULT: libc::c_int = 0;
+#[cfg(not(feature = "apple-sandbox"))]
+pub const IOPOL_IMPORTANT: libc::c_int = 1;
+#[cfg(not(feature = "apple-sandbox"))]
+pub const IOPOL_PASSIVE: libc::c_int = 2;
+#[cfg(not(feature = "apple-sandbox"))]
+pub const IOPOL_THROTTLE: libc::c_int = 3;
+#[cfg(not(feature = "apple-sandbox"))]
+pub const IOPOL_UTILITY: libc::c_int = 4;
+#[cfg(not(feature = "apple-sandbox"))]
+pub const IOPOL_STANDARD: libc::c_int = 5;
+
+#[cfg(not(feature = "apple-sandbox"))]
+extern "C" {
+    pub fn getiopolicy_np(iotype: libc::c_int, scope: libc::c_int) -> libc::c_int;
+}
+
+// Defined in `sys/codesign.h`.
+#[cfg(not(feature = "apple-sandbox"))]
+pub const CS_OPS_IDENTITY: u32 = 11;
+
+#[cfg(not(feature = "apple-sandbox"))]
+extern "C" {
+    pub fn csops(
+        pid: libc::pid_t,
+        ops: u32,
+        useraddr: *mut libc::c_void,
+        usersize: libc::size_t,
+    ) -> libc::c_int;
+}
+
+// Defined in `mach/task_info.h`. Only the fields up to `phys_footprint` (`TASK_VM_INFO_REV1`)
+// are declared since we don't need the others.
+#[cfg(all(not(feature = "apple-sandbox"), feature = "processes"))]
+pub const TASK_VM_INFO: libc::task_flavor_t = 22;
+
+#[cfg(all(not(feature = "apple-sandbox"), feature = "processes"))]
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct task_vm_info {
+    pub virtual_size: u64,
+    pub region_count: libc::integer_t,
+    pub page_size: libc::integer_t,
+    pub resident_size: u64,
+    pub resident_size_peak: u64,
+    pub device: u64,
+    pub device_peak: u64,
+    pub internal: u64,
+    pub internal_peak: u64,
+    pub external: u64,
+    pub external_peak: u64,
+    pub reusable: u64,
+    pub reusable_peak: u64,
+    pub purgeable_volatile_pmap: u64,
+    pub purgeable_volatile_resident: u64,
+    pub purgeable_volatile_virtual: u64,
+    pub compressed: u64,
+    pub compressed_peak: u64,
+    pub compressed_lifetime: u64,
+    pub phys_footprint: u64,
+}
+
+// Defined in `mach/mach_port.h` and `mach_debug/ipc_info.h`.
+#[cfg(all(not(feature = "apple-sandbox"), feature = "processes"))]
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct ipc_info_space_basic {
+    pub iisb_genno_mask: libc::natural_t,
+    pub iisb_table_size: libc::natural_t,
+    pub iisb_table_next: libc::natural_t,
+    pub iisb_table_inuse: libc::natural_t,
+    pub iisb_reserved: [libc::natural_t; 2],
+}
+
+#[cfg(all(not(feature = "apple-sandbox"), feature = "processes"))]
+extern "C" {
+    pub fn mach_port_space_basic_info(
+        task: libc::mach_port_t,
+        basic_info: *mut ipc_info_space_basic,
+    ) -> libc::kern_return_t;
+}
+
+// Private API from `libquarantine` (part of `libSystem`), used by Activity Monitor among others.
+#[cfg(not(feature = "apple-sandbox"))]
+extern "C" {
+    pub fn responsibility_get_pid_responsible_for_pid(pid: libc::pid_t) -> libc::pid_t;
+}
+
+// Defined in `sys/csr.h`. Only the flag needed to know if SIP is enabled is declared.
+#[cfg(not(feature = "apple-sandbox"))]
+pub const CSR_ALLOW_UNRESTRICTED_FS: u32 = 1 << 1;
+
+#[cfg(not(feature = "apple-sandbox"))]
+extern "C" {
+    pub fn csr_get_active_config(config: *mut u32) -> libc::c_int;
+}
+
+// Defined in `CoreGraphics/CGWindow.h`.
+#[cfg(not(feature = "apple-sandbox"))]
+#[link(name = "CoreGraphics", kind = "framework")]
+extern "C" {
+    pub fn CGWindowListCopyWindowInfo(
+        option: u32,
+        relative_to_window: u32,
+    ) -> core_foundation_sys::array::CFArrayRef;
+}
+#[cfg(not(feature = "apple-sandbox"))]
+#[allow(non_upper_case_globals)]
+pub const kCGWindowListOptionOnScreenOnly: u32 = 1 << 0;
+#[cfg(not(feature = "apple-sandbox"))]
+#[allow(non_upper_case_globals)]
+pub const kCGWindowListExcludeDesktopElements: u32 = 1 << 4;
+#[cfg(not(feature = "apple-sandbox"))]
+#[allow(non_upper_case_globals)]
+pub const kCGNullWindowID: u32 = 0;
+#[cfg(not(feature = "apple-sandbox"))]
+#[allow(non_upper_case_globals)]
+pub const kCGWindowOwnerPID: &str = "kCGWindowOwnerPID";
+
+// The following types and constants come from `sys/proc_info.h`. Only the ones needed to get
+// the file descriptors and sockets information are declared.
+#[cfg(not(feature = "apple-sandbox"))]
+mod proc_info {
+    #![allow(non_camel_case_types)]
+
+    pub const PROC_PIDLISTFDS: libc::c_int = 1;
+    pub const PROC_PIDFDSOCKETINFO: libc::c_int = 3;
+    pub const PROX_FDTYPE_VNODE: u32 = 1;
+    pub const PROX_FDTYPE_SOCKET: u32 = 2;
+    pub const PROX_FDTYPE_KQUEUE: u32 = 5;
+    pub const PROX_FDTYPE_PIPE: u32 = 6;
+    pub const SOCKINFO_IN: libc::c_int = 1;
+    pub const SOCKINFO_TCP: libc::c_int = 2;
+    pub const INI_IPV4: u8 = 0x1;
+    pub const INI_IPV6: u8 = 0x2;
+    pub const TSI_S_ESTABLISHED: libc::c_int = 4;
+
+    #[repr(C)]
+    pub struct proc_fdinfo {
+        pub proc_fd: i32,
+        pub proc_fdtype: u32,
+    }
+
+    #[repr(C)]
+    pub struct proc_fileinfo {
+        pub fi_openflags: u32,
+        pub fi_status: u32,
+        pub fi_offset: libc::off_t,
+        pub fi_type: i32,
+        pub fi_guardflags: u32,
+    }
+
+    #[repr(C)]
+    pub struct vinfo_stat {
+        pub vst_dev: u32,
+        pub vst_mode: u16,
+        pub vst_nlink: u16,
+        pub vst_ino: u64,
+        pub vst_uid: libc::uid_t,
+        pub vst_gid: libc::gid_t,
+        pub vst_atime: i64,
+        pub vst_atimensec: i64,
+        pub vst_mtime: i64,
+        pub vst_mtimensec: i64,
+        pub vst_ctime: i64,
+        pub vst_ctimensec: i64,
+        pub vst_birthtime: i64,
+        pub vst_birthtimensec: i64,
+        pub vst_size: libc::off_t,
+        pub vst_blocks: i64,
+        pub vst_blksize: i32,
+        pub vst_flags: u32,
+        pub vst_gen: u32,
+        pub vst_rdev: u32,
+        pub vst_qspare: [i64; 2],
+    }
+
+    #[repr(C)]
+    pub struct sockbuf_info {
+        pub sbi_cc: u32,
+        pub sbi_hiwat: u32,
+        pub sbi_mbcnt: u32,
+        pub sbi_mbmax: u32,
+        pub sbi_lowat: u32,
+        pub sbi_flags: libc::c_short,
+        pub sbi_timeo: libc::c_short,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct in4in6_addr {
+        pub i46a_pad32: [u32; 3],
+        pub i46a_addr4: libc::in_addr,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub union in_sockinfo_addr {
+        pub ina_46: in4in6_addr,
+        pub ina_6: libc::in6_addr,
+    }
+
+    #[repr(C)]
+    pub struct in_sockinfo {
+        pub insi_fport: libc::c_int,
+        pub insi_lport: libc::c_int,
+        pub insi_gencnt: u64,
+        pub insi_flags: u32,
+        pub insi_flow: u32,
+        pub insi_vflag: u8,
+        pub insi_ip_ttl: u8,
+        pub rfu_1: u32,
+        pub insi_faddr: in_sockinfo_addr,
+        pub insi_laddr: in_sockinfo_addr,
+        pub insi_v4: u8,
+        pub insi_v6: [i32; 3],
+    }
+
+    #[repr(C)]
+    pub struct tcp_sockinfo {
+        pub tcpsi_ini: in_sockinfo,
+        pub tcpsi_state: libc::c_int,
+        pub tcpsi_timer: [libc::c_int; 4],
+        pub tcpsi_mss: libc::c_int,
+        pub tcpsi_flags: u32,
+        pub rfu_1: u32,
+        pub tcpsi_tp: u64,
+    }
+
+    #[repr(C)]
+    pub union socket_info_proto {
+        pub pri_in: std::mem::ManuallyDrop<in_sockinfo>,
+        pub pri_tcp: std::mem::ManuallyDrop<tcp_sockinfo>,
+        // The biggest member of this union is `un_sockinfo` (528 bytes).
+        pub _padding: [u64; 66],
+    }
+
+    #[repr(C)]
+    pub struct socket_info {
+        pub soi_stat: vinfo_stat,
+        pub soi_so: u64,
+        pub soi_pcb: u64,
+        pub soi_type: libc::c_int,
+        pub soi_protocol: libc::c_int,
+        pub soi_family: libc::c_int,
+        pub soi_options: libc::c_short,
+        pub soi_linger: libc::c_short,
+        pub soi_state: libc::c_short,
+        pub soi_qlen: libc::c_short,
+        pub soi_incqlen: libc::c_short,
+        pub soi_qlimit: libc::c_short,
+        pub soi_timeo: libc::c_short,
+        pub soi_error: libc::c_ushort,
+        pub soi_oobmark: u32,
+        pub soi_rcv: sockbuf_info,
+        pub soi_snd: sockbuf_info,
+        pub soi_kind: libc::c_int,
+        pub rfu_1: u32,
+        pub soi_proto: socket_info_proto,
+    }
+
+    #[repr(C)]
+    pub struct socket_fdinfo {
+        pub pfi: proc_fileinfo,
+        pub psi: socket_info,
+    }
+}
+
+#[cfg(not(feature = "apple-sandbox"))]
+pub use proc_info::*;
+
+#[cfg(all(not(feature = "apple-sandbox"), feature = "processes"))]
+extern "C" {
+    pub fn devname(dev: libc::dev_t, type_: libc::mode_t) -> *mut c_char;
+    pub fn mach_port_deallocate(
+        task: libc::mach_port_t,
+        name: libc::mach_port_t,
+    ) -> libc::kern_return_t;
+}
+
 extern "C" {
     // FIXME: to be removed once higher version than core_foundation_sys 0.8.4 is released.
     #[allow(dead_code)]
@@ -89,11 +441,9 @@ extern "C" {
     ) -> CFStringRef;
 }
 
-#[cfg(all(
-    not(feature = "apple-sandbox"),
-    any(target_arch = "x86", target_arch = "x86_64")
-))]
-mod io_service {
+// The SMC is used for the temperatures on x86 and for the power consumption.
+#[cfg(not(feature = "apple-sandbox"))]
+mod smc {
     use super::{io_object_t, mach_port_t};
     use libc::{kern_return_t, size_t, task_t};
 
@@ -182,11 +532,8 @@ mod io_service {
 #[cfg(feature = "apple-sandbox")]
 mod io_service {}
 
-#[cfg(all(
-    not(feature = "apple-sandbox"),
-    any(target_arch = "x86", target_arch = "x86_64")
-))]
-pub use io_service::*;
+#[cfg(not(feature = "apple-sandbox"))]
+pub use smc::*;
 
 #[cfg(all(not(feature = "apple-sandbox"), target_arch = "aarch64"))]
 mod io_service {
@@ -7,6 +7,9 @@ pub(crate) mod utils;
 #[cfg(not(feature = "apple-sandbox"))]
 pub(crate) mod cpu;
 
+#[cfg(not(feature = "apple-sandbox"))]
+pub(crate) mod smc;
+
 #[cfg(not(feature = "apple-sandbox"))]
 pub mod system;
 
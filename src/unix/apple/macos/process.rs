@@ -1,18 +1,47 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use std::ffi::{OsStr, OsString};
-use std::mem::{self, MaybeUninit};
-use std::os::unix::ffi::{OsStrExt, OsStringExt};
+use std::mem;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::os::unix::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
 
-use libc::{c_int, c_void, kill};
+use core_foundation_sys::array::{CFArrayGetCount, CFArrayGetValueAtIndex};
+use core_foundation_sys::dictionary::CFDictionaryRef;
+use libc::{c_void, kill};
 
-use crate::{DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, Signal, Uid};
+use crate::{
+    Connection, ConnectionProtocol, DiskUsage, FdBreakdown, Gid, IoPolicy, MemoryRegions,
+    PageFaults, Pid, ProcessStatus, Signal, Uid, Wakeups,
+};
 
+use crate::sys::disk::{get_int_value, DictKey};
+use crate::sys::ffi::{self, csops, CS_OPS_IDENTITY};
 use crate::sys::process::ThreadStatus;
+use crate::sys::utils::{cfstring_to_string, CFReleaser};
+
+#[cfg(feature = "processes")]
+use std::mem::MaybeUninit;
+#[cfg(feature = "processes")]
+use std::os::unix::ffi::OsStringExt;
+#[cfg(feature = "processes")]
+use std::time::Instant;
+
+#[cfg(feature = "processes")]
+use libc::c_int;
+
+#[cfg(feature = "processes")]
+use crate::sys::macos::utils::mach_time_to_nanos;
+#[cfg(feature = "processes")]
 use crate::sys::system::Wrap;
+#[cfg(feature = "processes")]
 use crate::unix::utils::cstr_to_rust_with_size;
+#[cfg(feature = "processes")]
+use crate::utils::RunTimeClock;
+#[cfg(feature = "processes")]
+use crate::{Process, ProcessRefreshKind};
 
+#[derive(Clone)]
 pub(crate) struct ProcessInner {
     pub(crate) name: OsString,
     pub(crate) cmd: Vec<OsString>,
@@ -24,10 +53,15 @@ pub(crate) struct ProcessInner {
     pub(crate) root: Option<PathBuf>,
     pub(crate) memory: u64,
     pub(crate) virtual_memory: u64,
+    #[cfg(feature = "processes")]
     old_utime: u64,
+    #[cfg(feature = "processes")]
     old_stime: u64,
+    accumulated_cpu_time: u64,
+    page_faults: Option<PageFaults>,
     start_time: u64,
     run_time: u64,
+    #[cfg(feature = "processes")]
     pub(crate) updated: bool,
     cpu_usage: f32,
     user_id: Option<Uid>,
@@ -44,9 +78,20 @@ pub(crate) struct ProcessInner {
     pub(crate) old_written_bytes: u64,
     pub(crate) read_bytes: u64,
     pub(crate) written_bytes: u64,
+    memory_regions: Option<MemoryRegions>,
+    memory_region_count: Option<u32>,
+    mach_port_count: Option<u32>,
+    tty: Option<PathBuf>,
+    wakeups: Option<Wakeups>,
+    total_pageins: u64,
+    /// Accumulated GPU time (in nanoseconds) and when it was retrieved.
+    #[cfg(feature = "processes")]
+    gpu_time: Option<(u64, Instant)>,
+    gpu_usage: Option<f32>,
 }
 
 impl ProcessInner {
+    #[cfg(feature = "processes")]
     pub(crate) fn new_empty(pid: Pid) -> Self {
         Self {
             name: OsString::new(),
@@ -62,6 +107,8 @@ impl ProcessInner {
             cpu_usage: 0.,
             old_utime: 0,
             old_stime: 0,
+            accumulated_cpu_time: 0,
+            page_faults: None,
             updated: true,
             start_time: 0,
             run_time: 0,
@@ -75,9 +122,18 @@ impl ProcessInner {
             old_written_bytes: 0,
             read_bytes: 0,
             written_bytes: 0,
+            memory_regions: None,
+            memory_region_count: None,
+            mach_port_count: None,
+            tty: None,
+            wakeups: None,
+            total_pageins: 0,
+            gpu_time: None,
+            gpu_usage: None,
         }
     }
 
+    #[cfg(feature = "processes")]
     pub(crate) fn new(pid: Pid, parent: Option<Pid>, start_time: u64, run_time: u64) -> Self {
         Self {
             name: OsString::new(),
@@ -93,6 +149,8 @@ impl ProcessInner {
             cpu_usage: 0.,
             old_utime: 0,
             old_stime: 0,
+            accumulated_cpu_time: 0,
+            page_faults: None,
             updated: true,
             start_time,
             run_time,
@@ -106,6 +164,14 @@ impl ProcessInner {
             old_written_bytes: 0,
             read_bytes: 0,
             written_bytes: 0,
+            memory_regions: None,
+            memory_region_count: None,
+            mach_port_count: None,
+            tty: None,
+            wakeups: None,
+            total_pageins: 0,
+            gpu_time: None,
+            gpu_usage: None,
         }
     }
 
@@ -173,6 +239,14 @@ impl ProcessInner {
         self.run_time
     }
 
+    pub(crate) fn accumulated_cpu_time(&self) -> u64 {
+        self.accumulated_cpu_time
+    }
+
+    pub(crate) fn page_faults(&self) -> Option<PageFaults> {
+        self.page_faults
+    }
+
     pub(crate) fn cpu_usage(&self) -> f32 {
         self.cpu_usage
     }
@@ -226,14 +300,179 @@ impl ProcessInner {
             }
         }
     }
+
+    pub(crate) fn responsible_pid(&self) -> Option<Pid> {
+        let pid = unsafe { ffi::responsibility_get_pid_responsible_for_pid(self.pid.0) };
+        if pid <= 0 {
+            None
+        } else {
+            Some(Pid(pid))
+        }
+    }
+
+    pub(crate) fn window_count(&self) -> u32 {
+        unsafe {
+            let Some(windows) = CFReleaser::new(ffi::CGWindowListCopyWindowInfo(
+                ffi::kCGWindowListOptionOnScreenOnly | ffi::kCGWindowListExcludeDesktopElements,
+                ffi::kCGNullWindowID,
+            )) else {
+                return 0;
+            };
+            let mut count = 0;
+            for i in 0..CFArrayGetCount(windows.inner()) {
+                let window = CFArrayGetValueAtIndex(windows.inner(), i) as CFDictionaryRef;
+                if get_int_value(window, DictKey::Defined(ffi::kCGWindowOwnerPID))
+                    == Some(self.pid.0 as _)
+                {
+                    count += 1;
+                }
+            }
+            count
+        }
+    }
+
+    pub(crate) fn is_being_traced(&self) -> Option<bool> {
+        unsafe { get_bsd_info(self.pid).map(|info| info.pbi_flags & ffi::PROC_FLAG_TRACED != 0) }
+    }
+
+    pub(crate) fn swapped_memory(&self) -> u64 {
+        // The compressor is where macOS pages out memory before writing it to the swap files.
+        self.memory_regions.map_or(0, |regions| regions.compressed)
+    }
+
+    pub(crate) fn memory_regions(&self) -> Option<MemoryRegions> {
+        self.memory_regions
+    }
+
+    pub(crate) fn memory_region_count(&self) -> Option<u32> {
+        self.memory_region_count
+    }
+
+    pub(crate) fn mach_port_count(&self) -> Option<u32> {
+        self.mach_port_count
+    }
+
+    pub(crate) fn io_policy(&self) -> Option<IoPolicy> {
+        // `getiopolicy_np` can only query the calling process.
+        if self.pid.0 != unsafe { libc::getpid() } {
+            return None;
+        }
+        match unsafe { ffi::getiopolicy_np(ffi::IOPOL_TYPE_DISK, ffi::IOPOL_SCOPE_PROCESS) } {
+            ffi::IOPOL_DEFAULT => Some(IoPolicy::Default),
+            ffi::IOPOL_IMPORTANT => Some(IoPolicy::Important),
+            ffi::IOPOL_PASSIVE => Some(IoPolicy::Passive),
+            ffi::IOPOL_THROTTLE => Some(IoPolicy::Throttle),
+            ffi::IOPOL_UTILITY => Some(IoPolicy::Utility),
+            ffi::IOPOL_STANDARD => Some(IoPolicy::Standard),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn connections(&self) -> Vec<Connection> {
+        unsafe { get_connections(self.pid) }
+    }
+
+    pub(crate) fn fd_breakdown(&self) -> FdBreakdown {
+        unsafe { get_fd_breakdown(self.pid) }
+    }
+
+    pub(crate) fn wakeups(&self) -> Option<Wakeups> {
+        self.wakeups
+    }
+
+    pub(crate) fn total_pageins(&self) -> u64 {
+        self.total_pageins
+    }
+
+    pub(crate) fn gpu_usage(&self) -> Option<f32> {
+        self.gpu_usage
+    }
+
+    /// Computes the GPU usage from the difference with the previously retrieved GPU time.
+    #[cfg(feature = "processes")]
+    pub(crate) fn update_gpu_usage(&mut self, gpu_time: Option<u64>, now: Instant) {
+        let Some(gpu_time) = gpu_time else {
+            self.gpu_time = None;
+            self.gpu_usage = None;
+            return;
+        };
+        self.gpu_usage = self.gpu_time.and_then(|(old_gpu_time, old_now)| {
+            let elapsed = now.duration_since(old_now).as_nanos();
+            if elapsed == 0 {
+                return None;
+            }
+            Some((gpu_time.saturating_sub(old_gpu_time) as f64 / elapsed as f64 * 100.) as f32)
+        });
+        self.gpu_time = Some((gpu_time, now));
+    }
+
+    pub(crate) fn tty(&self) -> Option<&Path> {
+        self.tty.as_deref()
+    }
+
+    pub(crate) fn bundle_id(&self) -> Option<String> {
+        use core_foundation_sys::base::kCFAllocatorDefault;
+        use core_foundation_sys::bundle::{CFBundleCreate, CFBundleGetIdentifier};
+        use core_foundation_sys::url::CFURLCreateFromFileSystemRepresentation;
+
+        // The executable of an application is located in `[name].app/Contents/MacOS/`.
+        let bundle_path = self
+            .exe
+            .as_deref()?
+            .ancestors()
+            .find(|path| path.extension().is_some_and(|ext| ext == "app"))?
+            .as_os_str()
+            .as_bytes();
+
+        unsafe {
+            let url = CFReleaser::new(CFURLCreateFromFileSystemRepresentation(
+                kCFAllocatorDefault,
+                bundle_path.as_ptr(),
+                bundle_path.len() as _,
+                true as _,
+            ))?;
+            let bundle = CFReleaser::new(CFBundleCreate(kCFAllocatorDefault, url.inner()))?;
+            // The returned string is owned by the bundle so we must not release it.
+            let identifier = CFBundleGetIdentifier(bundle.inner() as _);
+            if identifier.is_null() {
+                return None;
+            }
+            cfstring_to_string(identifier)
+        }
+    }
+
+    pub(crate) fn code_signing_id(&self) -> Option<String> {
+        // The identity is returned as a blob starting with a big-endian magic number and a
+        // big-endian length (which includes this 8 bytes header), followed by the NUL-terminated
+        // identifier.
+        let mut buf = [0u8; libc::PATH_MAX as usize + 8];
+        unsafe {
+            if csops(
+                self.pid.0,
+                CS_OPS_IDENTITY,
+                buf.as_mut_ptr() as *mut c_void,
+                buf.len(),
+            ) != 0
+            {
+                return None;
+            }
+        }
+        let len = u32::from_be_bytes([buf[4], buf[5], buf[6], buf[7]]) as usize;
+        let identity = buf.get(8..len.min(buf.len()))?;
+        let identity = identity.split(|c| *c == 0).next()?;
+        if identity.is_empty() {
+            None
+        } else {
+            String::from_utf8(identity.to_vec()).ok()
+        }
+    }
 }
 
 #[allow(deprecated)] // Because of libc::mach_absolute_time.
+#[cfg(feature = "processes")]
 pub(crate) fn compute_cpu_usage(
     p: &mut ProcessInner,
     task_info: libc::proc_taskinfo,
-    system_time: u64,
-    user_time: u64,
     time_interval: Option<f64>,
 ) {
     if let Some(time_interval) = time_interval {
@@ -244,7 +483,10 @@ pub(crate) fn compute_cpu_usage(
                 .pti_total_system
                 .saturating_add(task_info.pti_total_user);
 
-            let total_time_diff = total_current_time.saturating_sub(total_existing_time);
+            // The tasks CPU times are in mach absolute time units whereas the interval is in
+            // nanoseconds.
+            let total_time_diff =
+                mach_time_to_nanos(total_current_time.saturating_sub(total_existing_time));
             if total_time_diff > 0 {
                 p.cpu_usage = (total_time_diff as f64 / time_interval * 100.) as f32;
                 updated_cpu_usage = true;
@@ -258,10 +500,12 @@ pub(crate) fn compute_cpu_usage(
     } else {
         unsafe {
             // This is the "backup way" of CPU computation.
+            //
+            // The `pti_total_*` times already contain the times of all the threads of the process
+            // (including the ones which exited), so nothing needs to be added to them.
             let time = libc::mach_absolute_time();
-            let task_time = user_time
-                .saturating_add(system_time)
-                .saturating_add(task_info.pti_total_user)
+            let task_time = task_info
+                .pti_total_user
                 .saturating_add(task_info.pti_total_system);
 
             let system_time_delta = if task_time < p.old_utime {
@@ -285,6 +529,25 @@ pub(crate) fn compute_cpu_usage(
     }
 }
 
+/// Returns the CPU time (in milliseconds) used by the process since it started.
+#[cfg(feature = "processes")]
+fn get_accumulated_cpu_time(task_info: &libc::proc_taskinfo) -> u64 {
+    let total = task_info
+        .pti_total_user
+        .saturating_add(task_info.pti_total_system);
+    mach_time_to_nanos(total) / 1_000_000
+}
+
+#[cfg(feature = "processes")]
+fn update_page_faults(p: &mut ProcessInner, task_info: &libc::proc_taskinfo) {
+    // `pti_faults` counts all the page faults whereas `pti_pageins` only counts the ones which
+    // required to read from the disk (the major ones).
+    let total_major = task_info.pti_pageins.max(0) as u64;
+    let total_minor = (task_info.pti_faults.max(0) as u64).saturating_sub(total_major);
+    p.page_faults = Some(PageFaults::new(p.page_faults, total_minor, total_major));
+}
+
+#[cfg(feature = "processes")]
 unsafe fn get_task_info(pid: Pid) -> libc::proc_taskinfo {
     let mut task_info = mem::zeroed::<libc::proc_taskinfo>();
     // If it doesn't work, we just don't have memory information for this process
@@ -300,6 +563,7 @@ unsafe fn get_task_info(pid: Pid) -> libc::proc_taskinfo {
 }
 
 #[inline]
+#[cfg(feature = "processes")]
 fn check_if_pid_is_alive(pid: Pid, check_if_alive: bool) -> bool {
     // In case we are iterating all pids we got from `proc_listallpids`, then
     // there is no point checking if the process is alive since it was returned
@@ -318,6 +582,11 @@ fn check_if_pid_is_alive(pid: Pid, check_if_alive: bool) -> bool {
     }
 }
 
+#[cfg(feature = "processes")]
+pub(crate) fn get_process_real_user_id(pid: Pid) -> Option<Uid> {
+    unsafe { get_bsd_info(pid).map(|info| Uid(info.pbi_ruid)) }
+}
+
 unsafe fn get_bsd_info(pid: Pid) -> Option<libc::proc_bsdinfo> {
     let mut info = mem::zeroed::<libc::proc_bsdinfo>();
 
@@ -335,6 +604,23 @@ unsafe fn get_bsd_info(pid: Pid) -> Option<libc::proc_bsdinfo> {
     }
 }
 
+/// Returns the path of the controlling terminal device (like `/dev/ttys003`) of the process.
+#[cfg(feature = "processes")]
+fn get_tty(info: &libc::proc_bsdinfo) -> Option<PathBuf> {
+    // `NODEV` means that the process has no controlling terminal.
+    if info.e_tdev == u32::MAX {
+        return None;
+    }
+    unsafe {
+        let name = ffi::devname(info.e_tdev as libc::dev_t, libc::S_IFCHR);
+        if name.is_null() {
+            return None;
+        }
+        cstr_to_rust_with_size(name, None).map(|name| Path::new("/dev").join(name))
+    }
+}
+
+#[cfg(feature = "processes")]
 fn get_parent(info: &libc::proc_bsdinfo) -> Option<Pid> {
     match info.pbi_ppid as i32 {
         0 => None,
@@ -342,11 +628,13 @@ fn get_parent(info: &libc::proc_bsdinfo) -> Option<Pid> {
     }
 }
 
+#[cfg(feature = "processes")]
 unsafe fn create_new_process(
     pid: Pid,
-    now: u64,
+    clock: RunTimeClock,
     refresh_kind: ProcessRefreshKind,
     info: Option<libc::proc_bsdinfo>,
+    proc_args: &mut Vec<u8>,
 ) -> Result<Option<Process>, ()> {
     let info = match info {
         Some(info) => info,
@@ -354,7 +642,7 @@ unsafe fn create_new_process(
             let mut p = ProcessInner::new_empty(pid);
             if get_exe_and_name_backup(&mut p, refresh_kind) {
                 get_cwd_root(&mut p, refresh_kind);
-                return Ok(Some(Process { inner: p }));
+                return Ok(Some(Process::new(p)));
             }
             // If we can't even have the name, no point in keeping it.
             return Err(());
@@ -364,19 +652,31 @@ unsafe fn create_new_process(
     let parent = get_parent(&info);
 
     let start_time = info.pbi_start_tvsec;
-    let run_time = now.saturating_sub(start_time);
+    let run_time = clock.run_time(start_time);
 
     let mut p = ProcessInner::new(pid, parent, start_time, run_time);
-    if !get_process_infos(&mut p, refresh_kind) && !get_exe_and_name_backup(&mut p, refresh_kind) {
+    if !get_process_infos(&mut p, refresh_kind, proc_args)
+        && !get_exe_and_name_backup(&mut p, refresh_kind)
+    {
         // If we can't even have the name, no point in keeping it.
         return Err(());
     }
     get_cwd_root(&mut p, refresh_kind);
 
-    if refresh_kind.memory() {
+    if refresh_kind.cpu() || refresh_kind.memory() {
         let task_info = get_task_info(pid);
-        p.memory = task_info.pti_resident_size;
-        p.virtual_memory = task_info.pti_virtual_size;
+
+        if refresh_kind.cpu() {
+            p.accumulated_cpu_time = get_accumulated_cpu_time(&task_info);
+        }
+        if refresh_kind.memory() {
+            p.memory = task_info.pti_resident_size;
+            p.virtual_memory = task_info.pti_virtual_size;
+            update_page_faults(&mut p, &task_info);
+        }
+    }
+    if refresh_kind.memory_regions() {
+        update_task_memory_info(&mut p, pid);
     }
 
     p.user_id = Some(Uid(info.pbi_ruid));
@@ -384,13 +684,15 @@ unsafe fn create_new_process(
     p.group_id = Some(Gid(info.pbi_rgid));
     p.effective_group_id = Some(Gid(info.pbi_gid));
     p.process_status = ProcessStatus::from(info.pbi_status);
-    if refresh_kind.disk_usage() {
-        update_proc_disk_activity(&mut p);
+    p.tty = get_tty(&info);
+    if refresh_kind.disk_usage() || refresh_kind.cpu() || refresh_kind.memory() {
+        update_proc_rusage(&mut p, refresh_kind);
     }
-    Ok(Some(Process { inner: p }))
+    Ok(Some(Process::new(p)))
 }
 
 /// Less efficient way to retrieve `exe` and `name`.
+#[cfg(feature = "processes")]
 unsafe fn get_exe_and_name_backup(
     process: &mut ProcessInner,
     refresh_kind: ProcessRefreshKind,
@@ -423,6 +725,7 @@ unsafe fn get_exe_and_name_backup(
     }
 }
 
+#[cfg(feature = "processes")]
 unsafe fn convert_node_path_info(node: &libc::vnode_info_path) -> Option<PathBuf> {
     if node.vip_vi.vi_stat.vst_dev == 0 {
         return None;
@@ -434,6 +737,7 @@ unsafe fn convert_node_path_info(node: &libc::vnode_info_path) -> Option<PathBuf
     .map(PathBuf::from)
 }
 
+#[cfg(feature = "processes")]
 unsafe fn get_cwd_root(process: &mut ProcessInner, refresh_kind: ProcessRefreshKind) {
     let cwd_needs_update = refresh_kind.cwd().needs_update(|| process.cwd.is_none());
     let root_needs_update = refresh_kind.root().needs_update(|| process.root.is_none());
@@ -460,7 +764,14 @@ unsafe fn get_cwd_root(process: &mut ProcessInner, refresh_kind: ProcessRefreshK
     }
 }
 
-unsafe fn get_process_infos(process: &mut ProcessInner, refresh_kind: ProcessRefreshKind) -> bool {
+// The arguments and environment buffer (`proc_args`) can be as big as `kern.argmax` (usually 1MB),
+// so instead of allocating it for every process, it is reused during a whole refresh.
+#[cfg(feature = "processes")]
+unsafe fn get_process_infos(
+    process: &mut ProcessInner,
+    refresh_kind: ProcessRefreshKind,
+    proc_args: &mut Vec<u8>,
+) -> bool {
     /*
      * /---------------\ 0x00000000
      * | ::::::::::::: |
@@ -510,11 +821,12 @@ unsafe fn get_process_infos(process: &mut ProcessInner, refresh_kind: ProcessRef
         return false; // not enough rights I assume?
     }
 
-    let mut proc_args: Vec<u8> = Vec::with_capacity(arg_max as _);
+    proc_args.clear();
+    proc_args.reserve(arg_max);
     if libc::sysctl(
         mib.as_mut_ptr(),
         mib.len() as _,
-        proc_args.as_mut_slice().as_mut_ptr() as *mut _,
+        proc_args.as_mut_ptr() as *mut _,
         &mut arg_max,
         std::ptr::null_mut(),
         0,
@@ -532,11 +844,20 @@ unsafe fn get_process_infos(process: &mut ProcessInner, refresh_kind: ProcessRef
     if proc_args.is_empty() {
         return false;
     }
+    parse_process_args(process, proc_args, refresh_kind)
+}
+
+#[cfg(feature = "processes")]
+unsafe fn parse_process_args(
+    process: &mut ProcessInner,
+    proc_args: &[u8],
+    refresh_kind: ProcessRefreshKind,
+) -> bool {
     // We copy the number of arguments (`argc`) to `n_args`.
     let mut n_args: c_int = 0;
     libc::memcpy(
         &mut n_args as *mut _ as *mut _,
-        proc_args.as_slice().as_ptr() as *const _,
+        proc_args.as_ptr() as *const _,
         mem::size_of::<c_int>(),
     );
 
@@ -569,12 +890,14 @@ unsafe fn get_process_infos(process: &mut ProcessInner, refresh_kind: ProcessRef
     true
 }
 
+#[cfg(feature = "processes")]
 fn get_exe(data: &[u8]) -> (&Path, &[u8]) {
     let pos = data.iter().position(|c| *c == 0).unwrap_or(data.len());
     let (exe, proc_args) = data.split_at(pos);
     (Path::new(OsStr::from_bytes(exe)), proc_args)
 }
 
+#[cfg(feature = "processes")]
 fn get_arguments<'a>(
     cmd: &mut Vec<OsString>,
     mut data: &'a [u8],
@@ -607,6 +930,7 @@ fn get_arguments<'a>(
     data
 }
 
+#[cfg(feature = "processes")]
 fn get_environ(environ: &mut Vec<OsString>, mut data: &[u8]) {
     environ.clear();
 
@@ -628,13 +952,15 @@ fn get_environ(environ: &mut Vec<OsString>, mut data: &[u8]) {
     }
 }
 
+#[cfg(feature = "processes")]
 pub(crate) fn update_process(
     wrap: &Wrap,
     pid: Pid,
     time_interval: Option<f64>,
-    now: u64,
+    clock: RunTimeClock,
     refresh_kind: ProcessRefreshKind,
     check_if_alive: bool,
+    proc_args: &mut Vec<u8>,
 ) -> Result<Option<Process>, ()> {
     unsafe {
         if let Some(ref mut p) = (*wrap.0.get()).get_mut(&pid) {
@@ -645,26 +971,28 @@ pub(crate) fn update_process(
                     // We don't it to be removed, just replaced.
                     p.updated = true;
                     // The owner of this PID changed.
-                    return create_new_process(pid, now, refresh_kind, Some(info));
+                    return create_new_process(pid, clock, refresh_kind, Some(info), proc_args);
                 }
                 let parent = get_parent(&info);
                 // Update the parent if it changed.
                 if p.parent != parent {
                     p.parent = parent;
                 }
+                p.tty = get_tty(&info);
             }
 
-            if !get_process_infos(p, refresh_kind) {
+            if !get_process_infos(p, refresh_kind, proc_args) {
                 get_exe_and_name_backup(p, refresh_kind);
             }
             get_cwd_root(p, refresh_kind);
 
-            if refresh_kind.disk_usage() {
-                update_proc_disk_activity(p);
+            if refresh_kind.disk_usage() || refresh_kind.cpu() || refresh_kind.memory() {
+                update_proc_rusage(p, refresh_kind);
             }
 
+            // Only the first thread is used to get the status of the process.
             let mut thread_info = mem::zeroed::<libc::proc_threadinfo>();
-            let (user_time, system_time, thread_status) = if libc::proc_pidinfo(
+            let thread_status = if libc::proc_pidinfo(
                 pid.0,
                 libc::PROC_PIDTHREADINFO,
                 0,
@@ -672,45 +1000,89 @@ pub(crate) fn update_process(
                 mem::size_of::<libc::proc_threadinfo>() as _,
             ) != 0
             {
-                (
-                    thread_info.pth_user_time,
-                    thread_info.pth_system_time,
-                    Some(ThreadStatus::from(thread_info.pth_run_state)),
-                )
+                Some(ThreadStatus::from(thread_info.pth_run_state))
             } else {
                 // It very likely means that the process is dead...
                 if check_if_pid_is_alive(pid, check_if_alive) {
-                    (0, 0, Some(ThreadStatus::Running))
+                    Some(ThreadStatus::Running)
                 } else {
                     return Err(());
                 }
             };
             p.status = thread_status;
-            p.run_time = now.saturating_sub(p.start_time);
+            p.run_time = clock.run_time(p.start_time);
 
             if refresh_kind.cpu() || refresh_kind.memory() {
                 let task_info = get_task_info(pid);
 
                 if refresh_kind.cpu() {
-                    compute_cpu_usage(p, task_info, system_time, user_time, time_interval);
+                    p.accumulated_cpu_time = get_accumulated_cpu_time(&task_info);
+                    compute_cpu_usage(p, task_info, time_interval);
                 }
                 if refresh_kind.memory() {
                     p.memory = task_info.pti_resident_size;
                     p.virtual_memory = task_info.pti_virtual_size;
+                    update_page_faults(p, &task_info);
                 }
             }
+            if refresh_kind.memory_regions() {
+                update_task_memory_info(p, pid);
+            }
             p.updated = true;
             Ok(None)
         } else {
-            create_new_process(pid, now, refresh_kind, get_bsd_info(pid))
+            create_new_process(pid, clock, refresh_kind, get_bsd_info(pid), proc_args)
         }
     }
 }
 
-fn update_proc_disk_activity(p: &mut ProcessInner) {
-    p.old_read_bytes = p.read_bytes;
-    p.old_written_bytes = p.written_bytes;
+// Getting the task port of another process requires to have enough rights (being root or the
+// process owner without SIP restrictions), so the information is set to `None` if we can't get
+// it.
+#[cfg(feature = "processes")]
+fn update_task_memory_info(p: &mut ProcessInner, pid: Pid) {
+    p.memory_regions = None;
+    p.memory_region_count = None;
+    p.mach_port_count = None;
+    unsafe {
+        let mut task: libc::mach_port_t = 0;
+        if libc::task_for_pid(libc::mach_task_self(), pid.0, &mut task) != libc::KERN_SUCCESS {
+            return;
+        }
+        let mut info = mem::zeroed::<ffi::task_vm_info>();
+        let mut count = (mem::size_of::<ffi::task_vm_info>() / mem::size_of::<libc::natural_t>())
+            as libc::mach_msg_type_number_t;
+        let ret = libc::task_info(
+            task,
+            ffi::TASK_VM_INFO,
+            &mut info as *mut ffi::task_vm_info as libc::task_info_t,
+            &mut count,
+        );
+        if ret == libc::KERN_SUCCESS {
+            p.memory_regions = Some(MemoryRegions {
+                resident: info.resident_size,
+                shared: info.external,
+                private: info.internal,
+                compressed: info.compressed,
+            });
+            p.memory_region_count = Some(info.region_count as _);
+        } else {
+            sysinfo_debug!("task_info(TASK_VM_INFO) failed: {:?}", ret);
+        }
+        let mut space_info = mem::zeroed::<ffi::ipc_info_space_basic>();
+        let ret = ffi::mach_port_space_basic_info(task, &mut space_info);
+        if ret == libc::KERN_SUCCESS {
+            // It's the number of port names used by the task.
+            p.mach_port_count = Some(space_info.iisb_table_inuse);
+        } else {
+            sysinfo_debug!("mach_port_space_basic_info failed: {:?}", ret);
+        }
+        ffi::mach_port_deallocate(libc::mach_task_self(), task);
+    }
+}
 
+#[cfg(feature = "processes")]
+fn update_proc_rusage(p: &mut ProcessInner, refresh_kind: ProcessRefreshKind) {
     let mut pidrusage = MaybeUninit::<libc::rusage_info_v2>::uninit();
 
     unsafe {
@@ -724,13 +1096,127 @@ fn update_proc_disk_activity(p: &mut ProcessInner) {
             sysinfo_debug!("proc_pid_rusage failed: {:?}", retval);
         } else {
             let pidrusage = pidrusage.assume_init();
-            p.read_bytes = pidrusage.ri_diskio_bytesread;
-            p.written_bytes = pidrusage.ri_diskio_byteswritten;
+            if refresh_kind.disk_usage() {
+                p.old_read_bytes = p.read_bytes;
+                p.old_written_bytes = p.written_bytes;
+                p.read_bytes = pidrusage.ri_diskio_bytesread;
+                p.written_bytes = pidrusage.ri_diskio_byteswritten;
+            }
+            if refresh_kind.cpu() {
+                let old = p.wakeups.unwrap_or_default();
+                p.wakeups = Some(Wakeups {
+                    total_idle: pidrusage.ri_pkg_idle_wkups,
+                    idle: pidrusage.ri_pkg_idle_wkups.saturating_sub(old.total_idle),
+                    total_interrupt: pidrusage.ri_interrupt_wkups,
+                    interrupt: pidrusage
+                        .ri_interrupt_wkups
+                        .saturating_sub(old.total_interrupt),
+                });
+            }
+            if refresh_kind.memory() {
+                p.total_pageins = pidrusage.ri_pageins;
+            }
         }
     }
 }
 
+unsafe fn get_fds(pid: Pid) -> Vec<ffi::proc_fdinfo> {
+    let size = libc::proc_pidinfo(pid.0, ffi::PROC_PIDLISTFDS, 0, std::ptr::null_mut(), 0);
+    if size <= 0 {
+        return Vec::new();
+    }
+    let mut fds: Vec<ffi::proc_fdinfo> =
+        Vec::with_capacity(size as usize / mem::size_of::<ffi::proc_fdinfo>());
+    let size = libc::proc_pidinfo(
+        pid.0,
+        ffi::PROC_PIDLISTFDS,
+        0,
+        fds.as_mut_ptr() as *mut c_void,
+        (fds.capacity() * mem::size_of::<ffi::proc_fdinfo>()) as _,
+    );
+    if size <= 0 {
+        return Vec::new();
+    }
+    fds.set_len(size as usize / mem::size_of::<ffi::proc_fdinfo>());
+    fds
+}
+
+unsafe fn get_fd_breakdown(pid: Pid) -> FdBreakdown {
+    let mut breakdown = FdBreakdown::default();
+    for fd in get_fds(pid) {
+        let count = match fd.proc_fdtype {
+            ffi::PROX_FDTYPE_VNODE => &mut breakdown.files,
+            ffi::PROX_FDTYPE_SOCKET => &mut breakdown.sockets,
+            ffi::PROX_FDTYPE_PIPE => &mut breakdown.pipes,
+            ffi::PROX_FDTYPE_KQUEUE => &mut breakdown.kqueues,
+            _ => &mut breakdown.others,
+        };
+        *count += 1;
+    }
+    breakdown
+}
+
+unsafe fn get_connections(pid: Pid) -> Vec<Connection> {
+    get_fds(pid)
+        .iter()
+        .filter(|fd| fd.proc_fdtype == ffi::PROX_FDTYPE_SOCKET)
+        .filter_map(|fd| {
+            let mut info = mem::zeroed::<ffi::socket_fdinfo>();
+            if libc::proc_pidfdinfo(
+                pid.0,
+                fd.proc_fd,
+                ffi::PROC_PIDFDSOCKETINFO,
+                &mut info as *mut ffi::socket_fdinfo as *mut c_void,
+                mem::size_of::<ffi::socket_fdinfo>() as _,
+            ) <= 0
+            {
+                return None;
+            }
+            socket_to_connection(&info.psi)
+        })
+        .collect()
+}
+
+unsafe fn socket_to_connection(info: &ffi::socket_info) -> Option<Connection> {
+    let (protocol, in_info, is_established) = match (info.soi_kind, info.soi_protocol) {
+        (ffi::SOCKINFO_TCP, libc::IPPROTO_TCP) => {
+            let tcp_info = &info.soi_proto.pri_tcp;
+            (
+                ConnectionProtocol::Tcp,
+                &tcp_info.tcpsi_ini,
+                tcp_info.tcpsi_state == ffi::TSI_S_ESTABLISHED,
+            )
+        }
+        (ffi::SOCKINFO_IN, libc::IPPROTO_UDP) => {
+            (ConnectionProtocol::Udp, &*info.soi_proto.pri_in, false)
+        }
+        _ => return None,
+    };
+    let to_socket_addr = |addr: &ffi::in_sockinfo_addr, port: libc::c_int| {
+        let ip = if in_info.insi_vflag & ffi::INI_IPV4 != 0 {
+            IpAddr::V4(Ipv4Addr::from(u32::from_be(addr.ina_46.i46a_addr4.s_addr)))
+        } else if in_info.insi_vflag & ffi::INI_IPV6 != 0 {
+            IpAddr::V6(Ipv6Addr::from(addr.ina_6.s6_addr))
+        } else {
+            return None;
+        };
+        // The ports are stored in network byte order.
+        Some(SocketAddr::new(ip, u16::from_be(port as u16)))
+    };
+    let local_address = to_socket_addr(&in_info.insi_laddr, in_info.insi_lport)?;
+    let remote_address = to_socket_addr(&in_info.insi_faddr, in_info.insi_fport)
+        .filter(|addr| addr.port() != 0 || !addr.ip().is_unspecified());
+
+    Some(Connection {
+        protocol,
+        local_address,
+        remote_address,
+        is_established,
+    })
+}
+
 #[allow(clippy::uninit_vec)]
+#[cfg(feature = "processes")]
 pub(crate) fn get_proc_list() -> Option<Vec<Pid>> {
     unsafe {
         let count = libc::proc_listallpids(::std::ptr::null_mut(), 0);
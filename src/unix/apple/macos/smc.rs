@@ -0,0 +1,207 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::{ffi, macos::utils::IOReleaser};
+
+use libc::{c_char, c_int, c_void};
+
+use std::mem;
+
+unsafe fn perform_call(
+    conn: ffi::io_connect_t,
+    index: c_int,
+    input_structure: *const ffi::KeyData_t,
+    output_structure: *mut ffi::KeyData_t,
+) -> i32 {
+    let mut structure_output_size = mem::size_of::<ffi::KeyData_t>();
+
+    ffi::IOConnectCallStructMethod(
+        conn,
+        index as u32,
+        input_structure,
+        mem::size_of::<ffi::KeyData_t>(),
+        output_structure,
+        &mut structure_output_size,
+    )
+}
+
+// Adapted from https://github.com/lavoiesl/osx-cpu-temp/blob/master/smc.c#L28
+#[inline]
+fn strtoul(s: &[i8]) -> u32 {
+    unsafe {
+        ((*s.get_unchecked(0) as u32) << (3u32 << 3))
+            + ((*s.get_unchecked(1) as u32) << (2u32 << 3))
+            + ((*s.get_unchecked(2) as u32) << (1u32 << 3))
+            + (*s.get_unchecked(3) as u32)
+    }
+}
+
+#[inline]
+unsafe fn ultostr(s: *mut c_char, val: u32) {
+    *s.offset(0) = ((val >> 24) % 128) as i8;
+    *s.offset(1) = ((val >> 16) % 128) as i8;
+    *s.offset(2) = ((val >> 8) % 128) as i8;
+    *s.offset(3) = (val % 128) as i8;
+    *s.offset(4) = 0;
+}
+
+pub(crate) unsafe fn get_key_size(
+    con: ffi::io_connect_t,
+    key: &[i8],
+) -> Result<(ffi::KeyData_t, ffi::Val_t), i32> {
+    let mut input_structure: ffi::KeyData_t = mem::zeroed::<ffi::KeyData_t>();
+    let mut output_structure: ffi::KeyData_t = mem::zeroed::<ffi::KeyData_t>();
+    let mut val: ffi::Val_t = mem::zeroed::<ffi::Val_t>();
+
+    input_structure.key = strtoul(key);
+    input_structure.data8 = ffi::SMC_CMD_READ_KEYINFO;
+
+    let result = perform_call(
+        con,
+        ffi::KERNEL_INDEX_SMC,
+        &input_structure,
+        &mut output_structure,
+    );
+    if result != ffi::KIO_RETURN_SUCCESS {
+        return Err(result);
+    }
+
+    val.data_size = output_structure.key_info.data_size;
+    ultostr(
+        val.data_type.as_mut_ptr(),
+        output_structure.key_info.data_type,
+    );
+    input_structure.key_info.data_size = val.data_size;
+    input_structure.data8 = ffi::SMC_CMD_READ_BYTES;
+    Ok((input_structure, val))
+}
+
+pub(crate) unsafe fn read_key(
+    con: ffi::io_connect_t,
+    input_structure: &ffi::KeyData_t,
+    mut val: ffi::Val_t,
+) -> Result<ffi::Val_t, i32> {
+    let mut output_structure: ffi::KeyData_t = mem::zeroed::<ffi::KeyData_t>();
+
+    match perform_call(
+        con,
+        ffi::KERNEL_INDEX_SMC,
+        input_structure,
+        &mut output_structure,
+    ) {
+        ffi::KIO_RETURN_SUCCESS => {
+            libc::memcpy(
+                val.bytes.as_mut_ptr() as *mut c_void,
+                output_structure.bytes.as_mut_ptr() as *mut c_void,
+                mem::size_of::<[u8; 32]>(),
+            );
+            Ok(val)
+        }
+        result => Err(result),
+    }
+}
+
+pub(crate) struct IoService(ffi::io_connect_t);
+
+impl IoService {
+    fn new(obj: ffi::io_connect_t) -> Option<Self> {
+        if obj == 0 {
+            None
+        } else {
+            Some(Self(obj))
+        }
+    }
+
+    pub(crate) fn inner(&self) -> ffi::io_connect_t {
+        self.0
+    }
+
+    // code from https://github.com/Chris911/iStats
+    // Not supported on iOS, or in the default macOS
+    pub(crate) fn new_connection() -> Option<Self> {
+        let mut iterator: ffi::io_iterator_t = 0;
+
+        unsafe {
+            let matching_dictionary = ffi::IOServiceMatching(b"AppleSMC\0".as_ptr() as *const i8);
+            if matching_dictionary.is_null() {
+                sysinfo_debug!("IOServiceMatching call failed, `AppleSMC` not found");
+                return None;
+            }
+            let result = ffi::IOServiceGetMatchingServices(
+                ffi::kIOMasterPortDefault,
+                matching_dictionary,
+                &mut iterator,
+            );
+            if result != ffi::KIO_RETURN_SUCCESS {
+                sysinfo_debug!("Error: IOServiceGetMatchingServices() = {}", result);
+                return None;
+            }
+            let iterator = match IOReleaser::new(iterator) {
+                Some(i) => i,
+                None => {
+                    sysinfo_debug!("Error: IOServiceGetMatchingServices() succeeded but returned invalid descriptor");
+                    return None;
+                }
+            };
+
+            let device = match IOReleaser::new(ffi::IOIteratorNext(iterator.inner())) {
+                Some(d) => d,
+                None => {
+                    sysinfo_debug!("Error: no SMC found");
+                    return None;
+                }
+            };
+
+            let mut conn = 0;
+            let result = ffi::IOServiceOpen(device.inner(), libc::mach_task_self(), 0, &mut conn);
+            if result != ffi::KIO_RETURN_SUCCESS {
+                sysinfo_debug!("Error: IOServiceOpen() = {}", result);
+                return None;
+            }
+            let conn = IoService::new(conn);
+            if conn.is_none() {
+                sysinfo_debug!(
+                    "Error: IOServiceOpen() succeeded but returned invalid descriptor..."
+                );
+            }
+            conn
+        }
+    }
+}
+
+impl Drop for IoService {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::IOServiceClose(self.0);
+        }
+    }
+}
+
+/// Returns the value of the SMC `key`, converted from the fixed or floating point formats used
+/// by the power keys.
+unsafe fn read_float(con: ffi::io_connect_t, key: &[i8]) -> Option<f32> {
+    let (input_structure, val) = get_key_size(con, key).ok()?;
+    let val = read_key(con, &input_structure, val).ok()?;
+    if val.data_size == 0 {
+        return None;
+    }
+    let bytes = val.bytes.map(|b| b as u8);
+    match &val.data_type.map(|c| c as u8)[..4] {
+        // Used on Apple Silicon, in the native byte order.
+        b"flt " => Some(f32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])),
+        // Signed, 9 bits of integer part and 6 bits of fractional part.
+        b"sp96" => Some(i16::from_be_bytes([bytes[0], bytes[1]]) as f32 / 64.),
+        // Unsigned, 14 bits of integer part and 2 bits of fractional part.
+        b"fpe2" => Some(u16::from_be_bytes([bytes[0], bytes[1]]) as f32 / 4.),
+        _ => None,
+    }
+}
+
+/// Returns the system power consumption in watts.
+pub(crate) fn get_power_consumption() -> Option<f32> {
+    let connection = IoService::new_connection()?;
+    // `PSTR` is the power consumed by the whole system and `PDTR` the power delivered to it,
+    // which is the same on the models which don't have the former.
+    [*b"PSTR", *b"PDTR"]
+        .into_iter()
+        .find_map(|key| unsafe { read_float(connection.inner(), &key.map(|c| c as i8)) })
+}
@@ -1,35 +1,55 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-#[allow(deprecated)]
-use libc::{mach_timebase_info, mach_timebase_info_data_t};
-
+#[cfg(feature = "processes")]
 use libc::{
     host_processor_info, mach_port_t, munmap, natural_t, processor_cpu_load_info,
-    processor_cpu_load_info_t, sysconf, vm_page_size, PROCESSOR_CPU_LOAD_INFO, _SC_CLK_TCK,
+    processor_cpu_load_info_t, sysconf, vm_page_size, _SC_CLK_TCK, PROCESSOR_CPU_LOAD_INFO,
 };
+#[cfg(feature = "processes")]
+use std::collections::HashMap;
+#[cfg(feature = "processes")]
 use std::ptr::null_mut;
 
+use crate::sys::disk::{get_dict_value, get_int_value, DictKey};
+use crate::sys::ffi;
+use crate::sys::macos::utils::IOReleaser;
+use crate::sys::utils::{cfstring_to_string, CFReleaser, ErrorReporter};
+#[cfg(feature = "processes")]
+use crate::Pid;
+use crate::PowerSource;
+
+#[cfg(feature = "processes")]
+use core_foundation_sys::array::{
+    CFArrayGetCount, CFArrayGetTypeID, CFArrayGetValueAtIndex, CFArrayRef,
+};
+use core_foundation_sys::base::{kCFAllocatorDefault, kCFAllocatorNull, CFGetTypeID};
+use core_foundation_sys::dictionary::CFDictionaryRef;
+use core_foundation_sys::string as cfs;
+
+#[cfg(feature = "processes")]
 struct ProcessorCpuLoadInfo {
     cpu_load: processor_cpu_load_info_t,
     cpu_count: natural_t,
 }
 
+#[cfg(feature = "processes")]
 impl ProcessorCpuLoadInfo {
-    fn new(port: mach_port_t) -> Option<Self> {
+    fn new(port: mach_port_t, errors: &ErrorReporter) -> Option<Self> {
         let mut info_size = std::mem::size_of::<processor_cpu_load_info_t>() as _;
         let mut cpu_count = 0;
         let mut cpu_load: processor_cpu_load_info_t = null_mut();
 
         unsafe {
-            if host_processor_info(
+            let err = host_processor_info(
                 port,
                 PROCESSOR_CPU_LOAD_INFO,
                 &mut cpu_count,
                 &mut cpu_load as *mut _ as *mut _,
                 &mut info_size,
-            ) != 0
-            {
+            );
+            if err != 0 {
                 sysinfo_debug!("host_processor_info failed, not updating CPU ticks usage...");
+                errors.report("host_processor_info", err);
                 None
             } else if cpu_count < 1 || cpu_load.is_null() {
                 None
@@ -43,6 +63,7 @@ impl ProcessorCpuLoadInfo {
     }
 }
 
+#[cfg(feature = "processes")]
 impl Drop for ProcessorCpuLoadInfo {
     fn drop(&mut self) {
         unsafe {
@@ -51,39 +72,26 @@ impl Drop for ProcessorCpuLoadInfo {
     }
 }
 
+#[cfg(feature = "processes")]
 pub(crate) struct SystemTimeInfo {
-    timebase_to_ns: f64,
     clock_per_sec: f64,
     old_cpu_info: ProcessorCpuLoadInfo,
 }
 
+#[cfg(feature = "processes")]
 unsafe impl Send for SystemTimeInfo {}
+#[cfg(feature = "processes")]
 unsafe impl Sync for SystemTimeInfo {}
 
+#[cfg(feature = "processes")]
 impl SystemTimeInfo {
-    #[allow(deprecated)] // Everything related to mach_timebase_info_data_t
     pub fn new(port: mach_port_t) -> Option<Self> {
         unsafe {
             let clock_ticks_per_sec = sysconf(_SC_CLK_TCK);
 
-            // FIXME: Maybe check errno here? Problem is that if errno is not 0 before this call,
-            //        we will get an error which isn't related...
-            // if let Some(er) = std::io::Error::last_os_error().raw_os_error() {
-            //     if err != 0 {
-            //         println!("==> {:?}", er);
-            //         sysinfo_debug!("Failed to get _SC_CLK_TCK value, using old CPU tick measure system");
-            //         return None;
-            //     }
-            // }
-
-            let mut info = mach_timebase_info_data_t { numer: 0, denom: 0 };
-            if mach_timebase_info(&mut info) != libc::KERN_SUCCESS {
-                sysinfo_debug!("mach_timebase_info failed, using default value of 1");
-                info.numer = 1;
-                info.denom = 1;
-            }
-
-            let old_cpu_info = match ProcessorCpuLoadInfo::new(port) {
+            // The error callback can't be set yet when this is called (failures are reported with
+            // `System::try_new_with_specifics` instead).
+            let old_cpu_info = match ProcessorCpuLoadInfo::new(port, &ErrorReporter::default()) {
                 Some(cpu_info) => cpu_info,
                 None => {
                     sysinfo_debug!("host_processor_info failed, using old CPU tick measure system");
@@ -94,16 +102,17 @@ impl SystemTimeInfo {
             let nano_per_seconds = 1_000_000_000.;
             sysinfo_debug!("");
             Some(Self {
-                timebase_to_ns: info.numer as f64 / info.denom as f64,
                 clock_per_sec: nano_per_seconds / clock_ticks_per_sec as f64,
                 old_cpu_info,
             })
         }
     }
 
-    pub fn get_time_interval(&mut self, port: mach_port_t) -> f64 {
+    /// Returns the time (in nanoseconds) the CPUs spent since the last call, averaged over the
+    /// CPUs.
+    pub fn get_time_interval(&mut self, port: mach_port_t, errors: &ErrorReporter) -> f64 {
         let mut total = 0;
-        let new_cpu_info = match ProcessorCpuLoadInfo::new(port) {
+        let new_cpu_info = match ProcessorCpuLoadInfo::new(port, errors) {
             Some(cpu_info) => cpu_info,
             None => return 0.,
         };
@@ -126,16 +135,12 @@ impl SystemTimeInfo {
             // `MINIMUM_CPU_UPDATE_INTERVAL`, we replace it with it instead):
             let base_interval = total as f64 / cpu_count as f64 * self.clock_per_sec;
             let smallest = crate::MINIMUM_CPU_UPDATE_INTERVAL.as_secs_f64() * 1_000_000_000.0;
-            if base_interval < smallest {
-                smallest
-            } else {
-                base_interval / self.timebase_to_ns
-            }
+            base_interval.max(smallest)
         }
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "processes"))]
 mod test {
 
     use super::*;
@@ -149,14 +154,274 @@ mod test {
 
         let port = unsafe { libc::mach_host_self() };
         let mut info = SystemTimeInfo::new(port).unwrap();
-        info.get_time_interval(port);
+        info.get_time_interval(port, &ErrorReporter::default());
 
         std::thread::sleep(crate::MINIMUM_CPU_UPDATE_INTERVAL.saturating_mul(5));
 
-        let val = info.get_time_interval(port);
+        let val = info.get_time_interval(port, &ErrorReporter::default());
         assert_ne!(
             val,
             crate::MINIMUM_CPU_UPDATE_INTERVAL.as_secs_f64() * 1_000_000_000.0
         );
     }
 }
+
+/// Returns an iterator over the GPUs (`IOAccelerator` services).
+unsafe fn get_accelerators(errors: &ErrorReporter) -> Option<IOReleaser> {
+    // We don't need to wrap this in an auto-releaser because the following call to
+    // `IOServiceGetMatchingServices` will take ownership of one retain reference.
+    let matching = ffi::IOServiceMatching(b"IOAccelerator\0".as_ptr() as *const _);
+    if matching.is_null() {
+        sysinfo_debug!("IOServiceMatching call failed, `IOAccelerator` not found");
+        return None;
+    }
+    let mut iterator: ffi::io_iterator_t = 0;
+    let err = ffi::IOServiceGetMatchingServices(ffi::kIOMasterPortDefault, matching, &mut iterator);
+    if err != libc::KERN_SUCCESS {
+        errors.report("IOServiceGetMatchingServices(IOAccelerator)", err);
+        return None;
+    }
+    // Safety: We checked for success, so there is always a valid iterator, even if its empty.
+    Some(IOReleaser::new_unchecked(iterator))
+}
+
+unsafe fn create_key(key: &'static str) -> Option<CFReleaser<cfs::__CFString>> {
+    CFReleaser::new(cfs::CFStringCreateWithBytesNoCopy(
+        kCFAllocatorDefault,
+        key.as_ptr(),
+        key.len() as _,
+        cfs::kCFStringEncodingUTF8,
+        false as _,
+        kCFAllocatorNull,
+    ))
+}
+
+pub(crate) fn get_gpu_usage() -> Option<f32> {
+    unsafe {
+        let iterator = get_accelerators(&ErrorReporter::default())?;
+        let statistics_key = create_key(ffi::kIOAcceleratorPerformanceStatisticsKey)?;
+
+        while let Some(entry) = IOReleaser::new(ffi::IOIteratorNext(iterator.inner())) {
+            let Some(statistics) = CFReleaser::new(ffi::IORegistryEntryCreateCFProperty(
+                entry.inner(),
+                statistics_key.inner(),
+                kCFAllocatorDefault,
+                0,
+            )) else {
+                continue;
+            };
+            if let Some(usage) = get_int_value(
+                statistics.inner(),
+                DictKey::Defined(ffi::kIOAcceleratorDeviceUtilizationKey),
+            ) {
+                return Some(usage as f32);
+            }
+        }
+        None
+    }
+}
+
+/// Returns the GPU time (in nanoseconds) used by each process for which `is_refreshed` returns
+/// `true`.
+///
+/// It is read from the `AppUsage` property of the GPUs user clients, which is only provided by
+/// the Apple GPUs drivers.
+#[cfg(feature = "processes")]
+pub(crate) fn get_processes_gpu_time(
+    errors: &ErrorReporter,
+    is_refreshed: impl Fn(Pid) -> bool,
+) -> HashMap<Pid, u64> {
+    let mut gpu_times = HashMap::new();
+
+    unsafe {
+        let (Some(accelerators), Some(creator_key), Some(usage_key)) = (
+            get_accelerators(errors),
+            create_key(ffi::kIOUserClientCreatorKey),
+            create_key(ffi::kIOAcceleratorAppUsageKey),
+        ) else {
+            return gpu_times;
+        };
+
+        while let Some(accelerator) = IOReleaser::new(ffi::IOIteratorNext(accelerators.inner())) {
+            let mut clients: ffi::io_iterator_t = 0;
+            if ffi::IORegistryEntryGetChildIterator(
+                accelerator.inner(),
+                ffi::kIOServicePlane.as_ptr().cast(),
+                &mut clients,
+            ) != libc::KERN_SUCCESS
+            {
+                continue;
+            }
+            // Safety: We checked for success, so there is always a valid iterator.
+            let clients = IOReleaser::new_unchecked(clients);
+
+            while let Some(client) = IOReleaser::new(ffi::IOIteratorNext(clients.inner())) {
+                let Some(pid) = get_user_client_pid(&client, &creator_key) else {
+                    continue;
+                };
+                if !is_refreshed(pid) {
+                    continue;
+                }
+                let Some(usage) = CFReleaser::new(ffi::IORegistryEntryCreateCFProperty(
+                    client.inner(),
+                    usage_key.inner(),
+                    kCFAllocatorDefault,
+                    0,
+                )) else {
+                    continue;
+                };
+                if CFGetTypeID(usage.inner().cast()) != CFArrayGetTypeID() {
+                    continue;
+                }
+                let usage = usage.inner() as CFArrayRef;
+                let gpu_time = gpu_times.entry(pid).or_insert(0u64);
+                for i in 0..CFArrayGetCount(usage) {
+                    if let Some(time) = get_int_value(
+                        CFArrayGetValueAtIndex(usage, i) as CFDictionaryRef,
+                        DictKey::Defined(ffi::kIOAcceleratorAccumulatedGPUTimeKey),
+                    ) {
+                        *gpu_time = gpu_time.saturating_add(time as u64);
+                    }
+                }
+            }
+        }
+    }
+    gpu_times
+}
+
+/// Returns the PID of the process which created the user client. The creator property looks
+/// like `pid 412, WindowServer`.
+#[cfg(feature = "processes")]
+unsafe fn get_user_client_pid(
+    client: &IOReleaser,
+    creator_key: &CFReleaser<cfs::__CFString>,
+) -> Option<Pid> {
+    let creator = CFReleaser::new(ffi::IORegistryEntryCreateCFProperty(
+        client.inner(),
+        creator_key.inner(),
+        kCFAllocatorDefault,
+        0,
+    ))?;
+    if CFGetTypeID(creator.inner().cast()) != cfs::CFStringGetTypeID() {
+        return None;
+    }
+    let creator = cfstring_to_string(creator.inner() as cfs::CFStringRef)?;
+    creator
+        .strip_prefix("pid ")?
+        .split(',')
+        .next()?
+        .parse()
+        .ok()
+        .map(Pid)
+}
+
+/// Returns the `IOPlatformExpertDevice` service, the root of the IOKit registry devices.
+unsafe fn get_platform_expert() -> Option<IOReleaser> {
+    // `IOServiceGetMatchingService` takes ownership of `matching`.
+    let matching = ffi::IOServiceMatching(b"IOPlatformExpertDevice\0".as_ptr() as *const _);
+    if matching.is_null() {
+        sysinfo_debug!("IOServiceMatching call failed, `IOPlatformExpertDevice` not found");
+        return None;
+    }
+    let platform_expert = IOReleaser::new(ffi::IOServiceGetMatchingService(
+        ffi::kIOMasterPortDefault,
+        matching,
+    ));
+    if platform_expert.is_none() {
+        sysinfo_debug!("IOServiceGetMatchingService failed for `IOPlatformExpertDevice`");
+    }
+    platform_expert
+}
+
+/// Returns `true` if the IOKit registry can be queried.
+pub(crate) fn is_iokit_available() -> bool {
+    unsafe { get_platform_expert().is_some() }
+}
+
+/// Returns the `IOPlatformUUID` of the machine. Unlike the serial number, it can't be used to
+/// find the machine owner.
+pub(crate) fn get_hardware_uuid() -> Option<String> {
+    unsafe {
+        let platform_expert = get_platform_expert()?;
+        let uuid_key = create_key(ffi::kIOPlatformUUIDKey)?;
+        let uuid = CFReleaser::new(ffi::IORegistryEntryCreateCFProperty(
+            platform_expert.inner(),
+            uuid_key.inner(),
+            kCFAllocatorDefault,
+            0,
+        ))?;
+        if CFGetTypeID(uuid.inner().cast()) != cfs::CFStringGetTypeID() {
+            return None;
+        }
+        cfstring_to_string(uuid.inner() as cfs::CFStringRef)
+    }
+}
+
+/// SIP can be partially disabled with `csrutil`. Like the endpoint security tools, we consider
+/// it enabled as long as the file system protection is active.
+pub(crate) fn is_sip_enabled() -> Option<bool> {
+    let mut config = 0u32;
+    let ret = unsafe { ffi::csr_get_active_config(&mut config) };
+    if ret != 0 {
+        sysinfo_debug!("csr_get_active_config failed: {:?}", ret);
+        return None;
+    }
+    Some(config & ffi::CSR_ALLOW_UNRESTRICTED_FS == 0)
+}
+
+/// The CPU power status is only reported on Intel Macs.
+pub(crate) fn get_cpu_speed_limit() -> Option<u32> {
+    unsafe {
+        let mut status: CFDictionaryRef = std::ptr::null();
+        let ret = ffi::IOPMCopyCPUPowerStatus(&mut status);
+        if ret != ffi::KIO_RETURN_SUCCESS {
+            sysinfo_debug!("IOPMCopyCPUPowerStatus failed: {:?}", ret);
+            return None;
+        }
+        let status = CFReleaser::new(status)?;
+        get_int_value(
+            status.inner(),
+            DictKey::Defined(ffi::kIOPMCPUPowerLimitProcessorSpeedKey),
+        )
+        .map(|limit| limit.clamp(0, 100) as u32)
+    }
+}
+
+pub(crate) fn is_low_power_mode_enabled() -> Option<bool> {
+    unsafe {
+        let Some(preferences) = CFReleaser::new(ffi::IOPMCopyActivePMPreferences()) else {
+            sysinfo_debug!("IOPMCopyActivePMPreferences failed");
+            return None;
+        };
+        // The settings are stored for each power source.
+        let source = match get_power_source() {
+            PowerSource::Battery => ffi::kIOPMBatteryPowerKey,
+            PowerSource::Ups => ffi::kIOPMUPSPowerKey,
+            _ => ffi::kIOPMACPowerKey,
+        };
+        let settings = get_dict_value(preferences.inner(), DictKey::Defined(source), |v| {
+            Some(v as CFDictionaryRef)
+        })?;
+        get_int_value(settings, DictKey::Defined(ffi::kIOPMLowPowerModeKey)).map(|v| v != 0)
+    }
+}
+
+pub(crate) fn get_power_source() -> PowerSource {
+    unsafe {
+        let Some(info) = CFReleaser::new(ffi::IOPSCopyPowerSourcesInfo()) else {
+            sysinfo_debug!("IOPSCopyPowerSourcesInfo failed");
+            return PowerSource::Unknown;
+        };
+        // This string follows the "get" rule so it must not be released.
+        let source = ffi::IOPSGetProvidingPowerSourceType(info.inner());
+        if source.is_null() {
+            return PowerSource::Unknown;
+        }
+        match cfstring_to_string(source).as_deref() {
+            Some(ffi::kIOPMACPowerKey) => PowerSource::Ac,
+            Some(ffi::kIOPMBatteryPowerKey) => PowerSource::Battery,
+            Some(ffi::kIOPMUPSPowerKey) => PowerSource::Ups,
+            _ => PowerSource::Unknown,
+        }
+    }
+}
@@ -1,6 +1,8 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use std::num::NonZeroU32;
+#[cfg(all(not(feature = "apple-sandbox"), feature = "processes"))]
+use std::sync::OnceLock;
 
 type IoObject = NonZeroU32;
 
@@ -28,3 +30,52 @@ impl Drop for IOReleaser {
         unsafe { super::ffi::IOObjectRelease(self.0.get() as _) };
     }
 }
+
+/// Returns the numerator and denominator of the ratio between mach absolute time units and
+/// nanoseconds.
+#[allow(deprecated)] // Everything related to mach_timebase_info_data_t
+#[cfg(all(not(feature = "apple-sandbox"), feature = "processes"))]
+fn timebase() -> (u64, u64) {
+    static TIMEBASE: OnceLock<(u64, u64)> = OnceLock::new();
+
+    *TIMEBASE.get_or_init(|| {
+        let mut info = libc::mach_timebase_info_data_t { numer: 0, denom: 0 };
+        if unsafe { libc::mach_timebase_info(&mut info) } != libc::KERN_SUCCESS || info.denom == 0 {
+            sysinfo_debug!("mach_timebase_info failed, using default value of 1");
+            (1, 1)
+        } else {
+            (info.numer as u64, info.denom as u64)
+        }
+    })
+}
+
+/// Converts mach absolute time units (used by `mach_absolute_time` and for the tasks CPU times)
+/// into nanoseconds. They are the same on Intel, but not on Apple Silicon where one unit is
+/// `125 / 3` nanoseconds.
+#[cfg(all(not(feature = "apple-sandbox"), feature = "processes"))]
+pub(crate) fn mach_time_to_nanos(ticks: u64) -> u64 {
+    let (numer, denom) = timebase();
+    u64::try_from(ticks as u128 * numer as u128 / denom as u128).unwrap_or(u64::MAX)
+}
+
+#[cfg(all(test, not(feature = "apple-sandbox"), feature = "processes"))]
+mod test {
+    use super::mach_time_to_nanos;
+
+    #[test]
+    #[allow(deprecated)] // Because of libc::mach_absolute_time.
+    fn check_mach_time_to_nanos() {
+        assert_eq!(mach_time_to_nanos(0), 0);
+
+        let start = unsafe { libc::mach_absolute_time() };
+        let instant = std::time::Instant::now();
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        let ticks = unsafe { libc::mach_absolute_time() } - start;
+        let elapsed = instant.elapsed().as_nanos() as u64;
+
+        let nanos = mach_time_to_nanos(ticks);
+        // Both clocks were read at slightly different times so they can't be exactly equal.
+        assert!(nanos >= 100_000_000, "{nanos}");
+        assert!(nanos.abs_diff(elapsed) < 10_000_000, "{nanos} != {elapsed}");
+    }
+}
@@ -6,7 +6,7 @@ use std::collections::{hash_map, HashMap};
 use std::ptr::null_mut;
 
 use crate::common::MacAddr;
-use crate::network::refresh_networks_addresses;
+use crate::network::{refresh_networks_addresses, InterfaceFlags};
 use crate::IpNetwork;
 use crate::NetworkData;
 
@@ -41,6 +41,10 @@ impl NetworksInner {
         refresh_networks_addresses(&mut self.interfaces);
     }
 
+    pub(crate) fn default_interface() -> Option<String> {
+        crate::unix::network_helper::get_default_interface()
+    }
+
     pub(crate) fn refresh(&mut self) {
         self.update_networks(false);
     }
@@ -139,6 +143,8 @@ impl NetworksInner {
                                 old_errors_out,
                                 (*if2m).ifm_data.ifi_oerrors
                             );
+                            interface.mtu = (*if2m).ifm_data.ifi_mtu as _;
+                            interface.link_speed = (*if2m).ifm_data.ifi_baudrate;
                             interface.updated = true;
                         }
                         hash_map::Entry::Vacant(e) => {
@@ -166,10 +172,14 @@ impl NetworksInner {
                                     old_errors_in: errors_in,
                                     errors_out,
                                     old_errors_out: errors_out,
+                                    mtu: (*if2m).ifm_data.ifi_mtu as _,
+                                    link_speed: (*if2m).ifm_data.ifi_baudrate,
                                     updated: true,
                                     mac_addr: MacAddr::UNSPECIFIED,
                                     ip_networks: vec![],
+                                    flags: InterfaceFlags::default(),
                                 },
+                                elapsed: None,
                             });
                         }
                     }
@@ -193,11 +203,14 @@ pub(crate) struct NetworkDataInner {
     old_errors_in: u64,
     errors_out: u64,
     old_errors_out: u64,
+    mtu: u64,
+    link_speed: u64,
     updated: bool,
     /// MAC address
     pub(crate) mac_addr: MacAddr,
     /// IP networks
     pub(crate) ip_networks: Vec<IpNetwork>,
+    pub(crate) flags: InterfaceFlags,
 }
 
 impl NetworkDataInner {
@@ -256,4 +269,24 @@ impl NetworkDataInner {
     pub(crate) fn ip_networks(&self) -> &[IpNetwork] {
         &self.ip_networks
     }
+
+    pub(crate) fn is_up(&self) -> bool {
+        self.flags.is_up
+    }
+
+    pub(crate) fn is_loopback(&self) -> bool {
+        self.flags.is_loopback
+    }
+
+    pub(crate) fn is_running(&self) -> bool {
+        self.flags.is_running
+    }
+
+    pub(crate) fn mtu(&self) -> u64 {
+        self.mtu
+    }
+
+    pub(crate) fn link_speed(&self) -> u64 {
+        self.link_speed
+    }
 }
@@ -1,19 +1,42 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::sys::cpu::*;
-#[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+#[cfg(all(
+    target_os = "macos",
+    not(feature = "apple-sandbox"),
+    feature = "processes"
+))]
 use crate::sys::process::*;
-use crate::sys::utils::{get_sys_value, get_sys_value_by_name};
-
-use crate::{Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessRefreshKind};
-
-#[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+use crate::sys::utils::{get_sys_value, get_sys_value_by_name, ErrorReporter};
+#[cfg(all(
+    target_os = "macos",
+    not(feature = "apple-sandbox"),
+    feature = "processes"
+))]
+use crate::utils::take_exited_processes;
+
+use crate::{
+    Cpu, CpuRefreshKind, LoadAvg, MemoryPressure, MemoryRefreshKind, Pid, PowerSource, Process,
+    Subsystem, SwapFile, ThermalPressure,
+};
+#[cfg(feature = "processes")]
+use crate::{ProcessRefreshKind, Uid};
+
+#[cfg(all(
+    target_os = "macos",
+    not(feature = "apple-sandbox"),
+    feature = "processes"
+))]
+use crate::utils::RunTimeClock;
+#[cfg(all(
+    target_os = "macos",
+    not(feature = "apple-sandbox"),
+    feature = "processes"
+))]
 use std::cell::UnsafeCell;
 use std::collections::HashMap;
 use std::ffi::CStr;
 use std::mem;
-#[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
-use std::time::SystemTime;
 
 use libc::{
     c_int, c_void, host_statistics64, mach_port_t, sysconf, sysctl, timeval, vm_statistics64,
@@ -25,22 +48,44 @@ pub(crate) struct SystemInner {
     mem_total: u64,
     mem_free: u64,
     mem_used: u64,
+    mem_used_app: u64,
+    mem_used_full: u64,
     mem_available: u64,
+    mem_inactive: u64,
+    mem_speculative: u64,
+    mem_purgeable: u64,
     swap_total: u64,
     swap_free: u64,
     page_size_b: u64,
     port: mach_port_t,
-    #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+    #[cfg(all(
+        target_os = "macos",
+        not(feature = "apple-sandbox"),
+        feature = "processes"
+    ))]
     clock_info: Option<crate::sys::macos::system::SystemTimeInfo>,
     cpus: CpusWrapper,
+    errors: ErrorReporter,
 }
 
-#[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+#[cfg(all(
+    target_os = "macos",
+    not(feature = "apple-sandbox"),
+    feature = "processes"
+))]
 pub(crate) struct Wrap<'a>(pub UnsafeCell<&'a mut HashMap<Pid, Process>>);
 
-#[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+#[cfg(all(
+    target_os = "macos",
+    not(feature = "apple-sandbox"),
+    feature = "processes"
+))]
 unsafe impl<'a> Send for Wrap<'a> {}
-#[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+#[cfg(all(
+    target_os = "macos",
+    not(feature = "apple-sandbox"),
+    feature = "processes"
+))]
 unsafe impl<'a> Sync for Wrap<'a> {}
 
 fn boot_time() -> u64 {
@@ -68,14 +113,6 @@ fn boot_time() -> u64 {
     }
 }
 
-#[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
-fn get_now() -> u64 {
-    SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .map(|n| n.as_secs())
-        .unwrap_or(0)
-}
-
 impl SystemInner {
     pub(crate) fn new() -> Self {
         unsafe {
@@ -87,15 +124,57 @@ impl SystemInner {
                 mem_free: 0,
                 mem_available: 0,
                 mem_used: 0,
+                mem_used_app: 0,
+                mem_used_full: 0,
+                mem_inactive: 0,
+                mem_speculative: 0,
+                mem_purgeable: 0,
                 swap_total: 0,
                 swap_free: 0,
                 page_size_b: sysconf(_SC_PAGESIZE) as _,
                 port,
-                #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+                #[cfg(all(
+                    target_os = "macos",
+                    not(feature = "apple-sandbox"),
+                    feature = "processes"
+                ))]
                 clock_info: crate::sys::macos::system::SystemTimeInfo::new(port),
                 cpus: CpusWrapper::new(),
+                errors: ErrorReporter::default(),
+            }
+        }
+    }
+
+    pub(crate) fn failed_subsystems(&self) -> Vec<Subsystem> {
+        let mut failed = Vec::new();
+        // `MACH_PORT_NULL`
+        if self.port == 0 {
+            failed.push(Subsystem::HostPort);
+        }
+        #[cfg(all(
+            target_os = "macos",
+            not(feature = "apple-sandbox"),
+            feature = "processes"
+        ))]
+        if self.clock_info.is_none() {
+            failed.push(Subsystem::CpuTimeInfo);
+        }
+        // The SMC connection and the IOKit registry are only used when needed (by `Components`
+        // or `Disks` for example), so they're opened here only to check they're available.
+        #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+        {
+            if crate::sys::macos::smc::IoService::new_connection().is_none() {
+                failed.push(Subsystem::Smc);
+            }
+            if !crate::sys::macos::system::is_iokit_available() {
+                failed.push(Subsystem::IoKit);
             }
         }
+        failed
+    }
+
+    pub(crate) fn set_error_callback(&mut self, callback: crate::utils::ErrorCallback) {
+        self.errors.set(callback);
     }
 
     pub(crate) fn refresh_memory_specifics(&mut self, refresh_kind: MemoryRefreshKind) {
@@ -113,80 +192,120 @@ impl SystemInner {
                 ) {
                     self.swap_total = xs.xsu_total;
                     self.swap_free = xs.xsu_avail;
+                } else {
+                    self.errors.report_errno("sysctl(vm.swapusage)");
                 }
             }
             if refresh_kind.ram() {
                 mib[0] = libc::CTL_HW as _;
                 mib[1] = libc::HW_MEMSIZE as _;
                 // get ram info
-                if self.mem_total < 1 {
-                    get_sys_value(
+                if self.mem_total < 1
+                    && !get_sys_value(
                         mem::size_of::<u64>(),
                         &mut self.mem_total as *mut u64 as *mut c_void,
                         &mut mib,
-                    );
+                    )
+                {
+                    self.errors.report_errno("sysctl(hw.memsize)");
                 }
                 let mut count: u32 = libc::HOST_VM_INFO64_COUNT as _;
                 let mut stat = mem::zeroed::<vm_statistics64>();
-                if host_statistics64(
+                match host_statistics64(
                     self.port,
                     libc::HOST_VM_INFO64,
                     &mut stat as *mut vm_statistics64 as *mut _,
                     &mut count,
-                ) == libc::KERN_SUCCESS
-                {
-                    // From the apple documentation:
-                    //
-                    // /*
-                    //  * NB: speculative pages are already accounted for in "free_count",
-                    //  * so "speculative_count" is the number of "free" pages that are
-                    //  * used to hold data that was read speculatively from disk but
-                    //  * haven't actually been used by anyone so far.
-                    //  */
-                    self.mem_available = u64::from(stat.free_count)
-                        .saturating_add(u64::from(stat.inactive_count))
-                        .saturating_add(u64::from(stat.purgeable_count))
-                        .saturating_sub(u64::from(stat.compressor_page_count))
-                        .saturating_mul(self.page_size_b);
-                    self.mem_used = u64::from(stat.active_count)
-                        .saturating_add(u64::from(stat.wire_count))
-                        .saturating_add(u64::from(stat.compressor_page_count))
-                        .saturating_add(u64::from(stat.speculative_count))
-                        .saturating_mul(self.page_size_b);
-                    self.mem_free = u64::from(stat.free_count)
-                        .saturating_sub(u64::from(stat.speculative_count))
-                        .saturating_mul(self.page_size_b);
+                ) {
+                    libc::KERN_SUCCESS => self.update_memory(&stat),
+                    err => self.errors.report("host_statistics64", err),
                 }
             }
         }
     }
 
+    /// Computes the RAM values from the pages counts returned by `host_statistics64`.
+    ///
+    /// It doesn't query the system, so the computations can be tested with fixed values.
+    fn update_memory(&mut self, stat: &vm_statistics64) {
+        // From the apple documentation:
+        //
+        // /*
+        //  * NB: speculative pages are already accounted for in "free_count",
+        //  * so "speculative_count" is the number of "free" pages that are
+        //  * used to hold data that was read speculatively from disk but
+        //  * haven't actually been used by anyone so far.
+        //  */
+        self.mem_available = u64::from(stat.free_count)
+            .saturating_add(u64::from(stat.inactive_count))
+            .saturating_add(u64::from(stat.purgeable_count))
+            .saturating_sub(u64::from(stat.compressor_page_count))
+            .saturating_mul(self.page_size_b);
+        self.mem_used = u64::from(stat.active_count)
+            .saturating_add(u64::from(stat.wire_count))
+            .saturating_add(u64::from(stat.compressor_page_count))
+            .saturating_add(u64::from(stat.speculative_count))
+            .saturating_mul(self.page_size_b);
+        // This is the "App Memory" value from the Activity Monitor.
+        self.mem_used_app = u64::from(stat.internal_page_count)
+            .saturating_sub(u64::from(stat.purgeable_count))
+            .saturating_mul(self.page_size_b);
+        // This is the "Memory Used" value from the Activity Monitor: it's the sum of
+        // the "App Memory", "Wired Memory" and "Compressed" values.
+        self.mem_used_full = u64::from(stat.internal_page_count)
+            .saturating_sub(u64::from(stat.purgeable_count))
+            .saturating_add(u64::from(stat.wire_count))
+            .saturating_add(u64::from(stat.compressor_page_count))
+            .saturating_mul(self.page_size_b);
+        self.mem_free = u64::from(stat.free_count)
+            .saturating_sub(u64::from(stat.speculative_count))
+            .saturating_mul(self.page_size_b);
+        self.mem_inactive = u64::from(stat.inactive_count).saturating_mul(self.page_size_b);
+        self.mem_speculative = u64::from(stat.speculative_count).saturating_mul(self.page_size_b);
+        self.mem_purgeable = u64::from(stat.purgeable_count).saturating_mul(self.page_size_b);
+    }
+
     pub(crate) fn cgroup_limits(&self) -> Option<crate::CGroupLimits> {
         None
     }
 
     pub(crate) fn refresh_cpu_specifics(&mut self, refresh_kind: CpuRefreshKind) {
-        self.cpus.refresh(refresh_kind, self.port);
+        self.cpus.refresh(refresh_kind, self.port, &self.errors);
+    }
+
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {
+        self.cpus.refresh_global_cpu_usage(self.port, &self.errors);
     }
 
     pub(crate) fn refresh_cpu_list(&mut self, refresh_kind: CpuRefreshKind) {
         self.cpus = CpusWrapper::new();
-        self.cpus.refresh(refresh_kind, self.port);
+        self.cpus.refresh(refresh_kind, self.port, &self.errors);
     }
 
-    #[cfg(any(target_os = "ios", feature = "apple-sandbox"))]
+    #[cfg(all(
+        any(target_os = "ios", feature = "apple-sandbox"),
+        feature = "processes"
+    ))]
     pub(crate) fn refresh_processes_specifics(
         &mut self,
         _filter: Option<&[Pid]>,
+        _uid_filter: Option<&Uid>,
         _refresh_kind: ProcessRefreshKind,
+        _exited: Option<&mut Vec<Process>>,
     ) {
     }
 
-    #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+    #[cfg(all(
+        target_os = "macos",
+        not(feature = "apple-sandbox"),
+        feature = "processes"
+    ))]
     pub(crate) fn refresh_processes_specifics(
         &mut self,
         filter: Option<&[Pid]>,
+        uid_filter: Option<&Uid>,
         refresh_kind: ProcessRefreshKind,
+        exited: Option<&mut Vec<Process>>,
     ) {
         use crate::utils::into_iter;
 
@@ -217,36 +336,90 @@ impl SystemInner {
                 (&[], &empty_filter)
             };
 
-            let now = get_now();
+            let clock = RunTimeClock::now();
             let port = self.port;
-            let time_interval = self.clock_info.as_mut().map(|c| c.get_time_interval(port));
+            let errors = &self.errors;
+            let time_interval = self
+                .clock_info
+                .as_mut()
+                .map(|c| c.get_time_interval(port, errors));
             let entries: Vec<Process> = {
                 let wrap = &Wrap(UnsafeCell::new(&mut self.process_list));
 
                 #[cfg(feature = "multithread")]
                 use rayon::iter::ParallelIterator;
 
-                into_iter(pids)
-                    .flat_map(|pid| {
-                        if !filter_callback(pid, filter) {
-                            return None;
-                        }
-                        match update_process(wrap, pid, time_interval, now, refresh_kind, false) {
-                            Ok(x) => x,
-                            _ => None,
+                let update = |proc_args: &mut Vec<u8>, pid: Pid| {
+                    if !filter_callback(pid, filter) {
+                        return None;
+                    }
+                    if let Some(uid) = uid_filter {
+                        // This check is much cheaper than retrieving the process arguments
+                        // (which would fail for processes of other users anyway).
+                        match get_process_real_user_id(pid) {
+                            Some(ruid) if ruid == *uid => {}
+                            _ => return None,
                         }
-                    })
-                    .collect()
+                    }
+                    match update_process(
+                        wrap,
+                        pid,
+                        time_interval,
+                        clock,
+                        refresh_kind,
+                        false,
+                        proc_args,
+                    ) {
+                        Ok(x) => x,
+                        _ => None,
+                    }
+                };
+                // The buffer used to retrieve the process arguments is only kept for this
+                // refresh.
+                #[cfg(feature = "multithread")]
+                {
+                    into_iter(pids)
+                        .map_init(Vec::new, update)
+                        .flatten()
+                        .collect()
+                }
+                #[cfg(not(feature = "multithread"))]
+                {
+                    let mut proc_args = Vec::new();
+                    into_iter(pids)
+                        .flat_map(|pid| update(&mut proc_args, pid))
+                        .collect()
+                }
             };
             entries.into_iter().for_each(|entry| {
                 self.process_list.insert(entry.pid(), entry);
             });
+            if let Some(exited) = exited {
+                take_exited_processes(&mut self.process_list, exited, |p| !p.inner.updated);
+            }
             self.process_list
                 .retain(|_, proc_| std::mem::replace(&mut proc_.inner.updated, false));
+            if refresh_kind.cpu() {
+                // Only the GPU time of the refreshed processes (the other ones were removed just
+                // above) is retrieved.
+                let gpu_times =
+                    crate::sys::macos::system::get_processes_gpu_time(&self.errors, |pid| {
+                        self.process_list.contains_key(&pid)
+                    });
+                let now = std::time::Instant::now();
+                for (pid, proc_) in self.process_list.iter_mut() {
+                    proc_
+                        .inner
+                        .update_gpu_usage(gpu_times.get(pid).copied(), now);
+                }
+            }
         }
     }
 
-    #[cfg(any(target_os = "ios", feature = "apple-sandbox"))]
+    #[cfg(all(
+        any(target_os = "ios", feature = "apple-sandbox"),
+        feature = "processes"
+    ))]
     pub(crate) fn refresh_process_specifics(
         &mut self,
         _pid: Pid,
@@ -255,21 +428,37 @@ impl SystemInner {
         false
     }
 
-    #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+    #[cfg(all(
+        target_os = "macos",
+        not(feature = "apple-sandbox"),
+        feature = "processes"
+    ))]
     pub(crate) fn refresh_process_specifics(
         &mut self,
         pid: Pid,
         refresh_kind: ProcessRefreshKind,
     ) -> bool {
         let mut time_interval = None;
-        let now = get_now();
+        let clock = RunTimeClock::now();
 
         if refresh_kind.cpu() {
             let port = self.port;
-            time_interval = self.clock_info.as_mut().map(|c| c.get_time_interval(port));
+            let errors = &self.errors;
+            time_interval = self
+                .clock_info
+                .as_mut()
+                .map(|c| c.get_time_interval(port, errors));
         }
         let wrap = Wrap(UnsafeCell::new(&mut self.process_list));
-        match update_process(&wrap, pid, time_interval, now, refresh_kind, true) {
+        match update_process(
+            &wrap,
+            pid,
+            time_interval,
+            clock,
+            refresh_kind,
+            true,
+            &mut Vec::new(),
+        ) {
             Ok(Some(p)) => {
                 self.process_list.insert(p.pid(), p);
                 true
@@ -287,6 +476,26 @@ impl SystemInner {
         &self.process_list
     }
 
+    #[cfg(feature = "processes")]
+    pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
+        &mut self.process_list
+    }
+
+    #[cfg(feature = "processes")]
+    pub(crate) fn list_pids(&self) -> Vec<Pid> {
+        cfg_if::cfg_if! {
+            if #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))] {
+                get_proc_list().unwrap_or_default()
+            } else {
+                Vec::new()
+            }
+        }
+    }
+
+    pub(crate) fn remove_process(&mut self, pid: Pid) -> Option<Process> {
+        self.process_list.remove(&pid)
+    }
+
     pub(crate) fn process(&self, pid: Pid) -> Option<&Process> {
         self.process_list.get(&pid)
     }
@@ -299,14 +508,38 @@ impl SystemInner {
         &self.cpus.cpus
     }
 
+    pub(crate) fn set_cpu_smoothing(&mut self, alpha: f32) {
+        self.cpus.cpu_smoothing = alpha;
+    }
+
+    pub(crate) fn cpu_smoothing(&self) -> f32 {
+        self.cpus.cpu_smoothing
+    }
+
+    pub(crate) fn logical_cpu_count(&self) -> Option<usize> {
+        logical_cpu_count()
+    }
+
+    pub(crate) fn online_cpu_count(&self) -> Option<usize> {
+        online_cpu_count()
+    }
+
     pub(crate) fn physical_core_count(&self) -> Option<usize> {
         physical_core_count()
     }
 
+    pub(crate) fn cache_size(&self, level: u8) -> Option<u64> {
+        get_cache_size(level)
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
 
+    pub(crate) fn page_size(&self) -> u64 {
+        self.page_size_b
+    }
+
     pub(crate) fn free_memory(&self) -> u64 {
         self.mem_free
     }
@@ -319,10 +552,46 @@ impl SystemInner {
         self.mem_used
     }
 
+    pub(crate) fn used_memory_app(&self) -> u64 {
+        self.mem_used_app
+    }
+
+    pub(crate) fn used_memory_full(&self) -> u64 {
+        self.mem_used_full
+    }
+
+    pub(crate) fn inactive_memory(&self) -> u64 {
+        self.mem_inactive
+    }
+
+    pub(crate) fn speculative_memory(&self) -> u64 {
+        self.mem_speculative
+    }
+
+    pub(crate) fn purgeable_memory(&self) -> u64 {
+        self.mem_purgeable
+    }
+
     pub(crate) fn total_swap(&self) -> u64 {
         self.swap_total
     }
 
+    pub(crate) fn is_swap_enabled(&self) -> bool {
+        let mut enabled: c_int = 0;
+        let mut size = mem::size_of::<c_int>();
+        unsafe {
+            if get_sys_value_by_name(
+                b"vm.swap_enabled\0",
+                &mut size,
+                &mut enabled as *mut _ as *mut c_void,
+            ) {
+                return enabled != 0;
+            }
+        }
+        self.errors.report_errno("sysctl(vm.swap_enabled)");
+        self.swap_total > 0
+    }
+
     pub(crate) fn free_swap(&self) -> u64 {
         self.swap_free
     }
@@ -332,6 +601,47 @@ impl SystemInner {
         self.swap_total - self.swap_free
     }
 
+    pub(crate) fn swap_files(&self) -> Vec<SwapFile> {
+        // The swap files are created (and removed) on demand by the dynamic pager, so there is
+        // none when the swap isn't used.
+        let Ok(dir) = std::fs::read_dir("/var/vm") else {
+            return Vec::new();
+        };
+        dir.filter_map(|entry| {
+            let entry = entry.ok()?;
+            if !entry.file_name().to_str()?.starts_with("swapfile") {
+                return None;
+            }
+            Some(SwapFile {
+                path: entry.path(),
+                size: entry.metadata().ok()?.len(),
+            })
+        })
+        .collect()
+    }
+
+    pub(crate) fn idle_time(&self) -> Option<u64> {
+        unsafe {
+            let mut info = mem::zeroed::<libc::processor_cpu_load_info>();
+            let mut count = libc::HOST_CPU_LOAD_INFO_COUNT;
+            let err = libc::host_statistics(
+                self.port,
+                libc::HOST_CPU_LOAD_INFO,
+                &mut info as *mut _ as *mut _,
+                &mut count,
+            );
+            if err != libc::KERN_SUCCESS {
+                self.errors.report("host_statistics", err);
+                return None;
+            }
+            let ticks_per_sec = sysconf(libc::_SC_CLK_TCK);
+            if ticks_per_sec <= 0 {
+                return None;
+            }
+            Some(u64::from(info.cpu_ticks[libc::CPU_STATE_IDLE as usize]) / ticks_per_sec as u64)
+        }
+    }
+
     pub(crate) fn uptime() -> u64 {
         unsafe {
             let csec = libc::time(::std::ptr::null_mut());
@@ -353,6 +663,119 @@ impl SystemInner {
         }
     }
 
+    #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+    pub(crate) fn gpu_usage() -> Option<f32> {
+        crate::sys::macos::system::get_gpu_usage()
+    }
+
+    #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+    pub(crate) fn hardware_uuid() -> Option<String> {
+        crate::sys::macos::system::get_hardware_uuid()
+    }
+
+    #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+    pub(crate) fn is_low_power_mode_enabled() -> Option<bool> {
+        crate::sys::macos::system::is_low_power_mode_enabled()
+    }
+
+    #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+    pub(crate) fn power_consumption() -> Option<f32> {
+        crate::sys::macos::smc::get_power_consumption()
+    }
+
+    #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+    pub(crate) fn cpu_speed_limit() -> Option<u32> {
+        crate::sys::macos::system::get_cpu_speed_limit()
+    }
+
+    #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+    pub(crate) fn is_sip_enabled() -> Option<bool> {
+        crate::sys::macos::system::is_sip_enabled()
+    }
+
+    pub(crate) fn power_source() -> PowerSource {
+        cfg_if::cfg_if! {
+            if #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))] {
+                crate::sys::macos::system::get_power_source()
+            } else {
+                PowerSource::Unknown
+            }
+        }
+    }
+
+    pub(crate) fn thermal_pressure() -> Option<ThermalPressure> {
+        use crate::sys::ffi::{
+            notify_cancel, notify_get_state, notify_register_check, NOTIFY_STATUS_OK,
+        };
+
+        unsafe {
+            let mut token = 0;
+            if notify_register_check(
+                b"com.apple.system.thermalpressurelevel\0".as_ptr() as *const _,
+                &mut token,
+            ) != NOTIFY_STATUS_OK
+            {
+                sysinfo_debug!("notify_register_check failed");
+                return None;
+            }
+            let mut state = 0;
+            let ret = notify_get_state(token, &mut state);
+            notify_cancel(token);
+            if ret != NOTIFY_STATUS_OK {
+                sysinfo_debug!("notify_get_state failed");
+                return None;
+            }
+            // Values of `OSThermalPressureLevel`, mapped the same way as
+            // `NSProcessInfo.thermalState`.
+            Some(match state {
+                0 => ThermalPressure::Nominal,
+                1 => ThermalPressure::Fair,
+                2 => ThermalPressure::Serious,
+                _ => ThermalPressure::Critical,
+            })
+        }
+    }
+
+    pub(crate) fn subscribe_memory_pressure<F>(
+        callback: F,
+    ) -> Option<MemoryPressureSubscriptionInner>
+    where
+        F: Fn(MemoryPressure) + Send + Sync + 'static,
+    {
+        use crate::sys::ffi::{
+            _dispatch_source_type_memorypressure, dispatch_get_global_queue, dispatch_resume,
+            dispatch_set_context, dispatch_source_create, dispatch_source_set_cancel_handler_f,
+            dispatch_source_set_event_handler_f, DISPATCH_MEMORYPRESSURE_CRITICAL,
+            DISPATCH_MEMORYPRESSURE_NORMAL, DISPATCH_MEMORYPRESSURE_WARN,
+        };
+
+        unsafe {
+            let source = dispatch_source_create(
+                &_dispatch_source_type_memorypressure,
+                0,
+                DISPATCH_MEMORYPRESSURE_NORMAL
+                    | DISPATCH_MEMORYPRESSURE_WARN
+                    | DISPATCH_MEMORYPRESSURE_CRITICAL,
+                dispatch_get_global_queue(0, 0),
+            );
+            if source.is_null() {
+                sysinfo_debug!("dispatch_source_create failed");
+                return None;
+            }
+            // The context is freed by the cancel handler, which is called once the source is
+            // cancelled and no event handler is running anymore.
+            let context = Box::new(MemoryPressureContext {
+                source,
+                callback: Box::new(callback),
+            });
+            dispatch_set_context(source, Box::into_raw(context) as *mut c_void);
+            dispatch_source_set_event_handler_f(source, memory_pressure_handler);
+            dispatch_source_set_cancel_handler_f(source, memory_pressure_cancel_handler);
+            dispatch_resume(source);
+            Some(MemoryPressureSubscriptionInner { source })
+        }
+    }
+
     pub(crate) fn boot_time() -> u64 {
         boot_time()
     }
@@ -413,35 +836,11 @@ impl SystemInner {
     }
 
     pub(crate) fn os_version() -> Option<String> {
-        unsafe {
-            // get the size for the buffer first
-            let mut size = 0;
-            if get_sys_value_by_name(b"kern.osproductversion\0", &mut size, std::ptr::null_mut())
-                && size > 0
-            {
-                // now create a buffer with the size and get the real value
-                let mut buf = vec![0_u8; size as _];
-
-                if get_sys_value_by_name(
-                    b"kern.osproductversion\0",
-                    &mut size,
-                    buf.as_mut_ptr() as *mut c_void,
-                ) {
-                    if let Some(pos) = buf.iter().position(|x| *x == 0) {
-                        // Shrink buffer to terminate the null bytes
-                        buf.resize(pos, 0);
-                    }
+        get_system_info_by_name(b"kern.osproductversion\0")
+    }
 
-                    String::from_utf8(buf).ok()
-                } else {
-                    // getting the system value failed
-                    None
-                }
-            } else {
-                // getting the system value failed, or did not return a buffer size
-                None
-            }
-        }
+    pub(crate) fn hardware_model() -> Option<String> {
+        get_system_info_by_name(b"hw.model\0")
     }
 
     pub(crate) fn distribution_id() -> String {
@@ -471,6 +870,84 @@ impl SystemInner {
     }
 }
 
+struct MemoryPressureContext {
+    source: crate::sys::ffi::dispatch_object_t,
+    callback: Box<dyn Fn(MemoryPressure) + Send + Sync>,
+}
+
+extern "C" fn memory_pressure_handler(context: *mut c_void) {
+    use crate::sys::ffi::{
+        dispatch_source_get_data, DISPATCH_MEMORYPRESSURE_CRITICAL, DISPATCH_MEMORYPRESSURE_WARN,
+    };
+
+    unsafe {
+        let context = &*(context as *const MemoryPressureContext);
+        let data = dispatch_source_get_data(context.source);
+        let pressure = if data & DISPATCH_MEMORYPRESSURE_CRITICAL != 0 {
+            MemoryPressure::Critical
+        } else if data & DISPATCH_MEMORYPRESSURE_WARN != 0 {
+            MemoryPressure::Warn
+        } else {
+            MemoryPressure::Normal
+        };
+        // Unwinding through the dispatch queue is undefined behaviour.
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            (context.callback)(pressure)
+        }));
+    }
+}
+
+extern "C" fn memory_pressure_cancel_handler(context: *mut c_void) {
+    unsafe {
+        drop(Box::from_raw(context as *mut MemoryPressureContext));
+    }
+}
+
+pub(crate) struct MemoryPressureSubscriptionInner {
+    source: crate::sys::ffi::dispatch_object_t,
+}
+
+// The dispatch sources can be used from any thread.
+unsafe impl Send for MemoryPressureSubscriptionInner {}
+unsafe impl Sync for MemoryPressureSubscriptionInner {}
+
+impl Drop for MemoryPressureSubscriptionInner {
+    fn drop(&mut self) {
+        use crate::sys::ffi::{dispatch_release, dispatch_source_cancel};
+
+        unsafe {
+            dispatch_source_cancel(self.source);
+            dispatch_release(self.source);
+        }
+    }
+}
+
+fn get_system_info_by_name(name: &[u8]) -> Option<String> {
+    unsafe {
+        // get the size for the buffer first
+        let mut size = 0;
+        if get_sys_value_by_name(name, &mut size, std::ptr::null_mut()) && size > 0 {
+            // now create a buffer with the size and get the real value
+            let mut buf = vec![0_u8; size as _];
+
+            if get_sys_value_by_name(name, &mut size, buf.as_mut_ptr() as *mut c_void) {
+                if let Some(pos) = buf.iter().position(|x| *x == 0) {
+                    // Shrink buffer to terminate the null bytes
+                    buf.resize(pos, 0);
+                }
+
+                String::from_utf8(buf).ok()
+            } else {
+                // getting the system value failed
+                None
+            }
+        } else {
+            // getting the system value failed, or did not return a buffer size
+            None
+        }
+    }
+}
+
 fn get_system_info(value: c_int, default: Option<&str>) -> Option<String> {
     let mut mib: [c_int; 2] = [libc::CTL_KERN, value];
     let mut size = 0;
@@ -515,3 +992,51 @@ fn get_system_info(value: c_int, default: Option<&str>) -> Option<String> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::SystemInner;
+    use libc::vm_statistics64;
+
+    impl SystemInner {
+        fn new_with_page_size(page_size_b: u64) -> Self {
+            let mut s = Self::new();
+            s.page_size_b = page_size_b;
+            s
+        }
+    }
+
+    #[test]
+    fn check_memory_computations() {
+        let mut stat = unsafe { std::mem::zeroed::<vm_statistics64>() };
+        stat.free_count = 100;
+        stat.speculative_count = 10;
+        stat.active_count = 200;
+        stat.inactive_count = 50;
+        stat.wire_count = 30;
+        stat.purgeable_count = 5;
+        stat.compressor_page_count = 20;
+        stat.internal_page_count = 150;
+
+        let mut s = SystemInner::new_with_page_size(4_096);
+        s.update_memory(&stat);
+        // free + inactive + purgeable - compressor
+        assert_eq!(s.available_memory(), 135 * 4_096);
+        // active + wire + compressor + speculative
+        assert_eq!(s.used_memory(), 260 * 4_096);
+        // internal - purgeable
+        assert_eq!(s.used_memory_app(), 145 * 4_096);
+        // internal - purgeable + wire + compressor
+        assert_eq!(s.used_memory_full(), 195 * 4_096);
+        // free - speculative
+        assert_eq!(s.free_memory(), 90 * 4_096);
+        assert_eq!(s.inactive_memory(), 50 * 4_096);
+        assert_eq!(s.speculative_memory(), 10 * 4_096);
+        assert_eq!(s.purgeable_memory(), 5 * 4_096);
+
+        // The page size must be taken into account.
+        let mut s = SystemInner::new_with_page_size(16_384);
+        s.update_memory(&stat);
+        assert_eq!(s.used_memory(), 260 * 16_384);
+    }
+}
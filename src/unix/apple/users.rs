@@ -7,6 +7,7 @@ use crate::{
 
 use libc::{c_char, endpwent, getpwent, setpwent, strlen};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 fn endswith(s1: *const c_char, s2: &[u8]) -> bool {
     if s1.is_null() {
@@ -55,14 +56,20 @@ pub(crate) fn get_users(users: &mut Vec<User>) {
 
                 let uid = (*pw).pw_uid;
                 let gid = (*pw).pw_gid;
-                users_map.insert(name, (Uid(uid), Gid(gid)));
+                let home_directory = crate::unix::utils::cstr_to_rust((*pw).pw_dir)
+                    .filter(|dir| !dir.is_empty())
+                    .map(PathBuf::from);
+                let shell = crate::unix::utils::cstr_to_rust((*pw).pw_shell)
+                    .filter(|shell| !shell.is_empty())
+                    .map(PathBuf::from);
+                users_map.insert(name, (Uid(uid), Gid(gid), home_directory, shell));
             }
         }
         endpwent();
     }
-    for (name, (uid, gid)) in users_map {
+    for (name, (uid, gid, home_directory, shell)) in users_map {
         users.push(User {
-            inner: UserInner::new(uid, gid, name),
+            inner: UserInner::new(uid, gid, name, home_directory, shell),
         });
     }
 }
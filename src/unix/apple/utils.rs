@@ -1,9 +1,12 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use core_foundation_sys::base::CFRelease;
+use core_foundation_sys::string::{self as cfs, CFStringRef};
 use libc::{c_void, sysctl, sysctlbyname};
 use std::ptr::NonNull;
 
+use crate::utils::ErrorCallback;
+
 // A helper using to auto release the resource got from CoreFoundation.
 // More information about the ownership policy for CoreFoundation pelease refer the link below:
 // https://developer.apple.com/library/archive/documentation/CoreFoundation/Conceptual/CFMemoryMgmt/Concepts/Ownership.html#//apple_ref/doc/uid/20001148-CJBEJBHH
@@ -35,6 +38,32 @@ impl<T> Drop for CFReleaser<T> {
 unsafe impl<T> Send for CFReleaser<T> {}
 unsafe impl<T> Sync for CFReleaser<T> {}
 
+/// Sends the failures of the system calls to the callback set with `System::set_error_callback`.
+/// It does nothing if no callback was set.
+#[derive(Default)]
+pub(crate) struct ErrorReporter(Option<ErrorCallback>);
+
+impl ErrorReporter {
+    pub(crate) fn set(&mut self, callback: ErrorCallback) {
+        self.0 = Some(callback);
+    }
+
+    /// Reports that the `call` system call failed and returned `code`.
+    pub(crate) fn report(&self, call: &str, code: impl std::fmt::Display) {
+        if let Some(ref callback) = self.0 {
+            callback(&format!("{call} failed: {code}"));
+        }
+    }
+
+    /// Reports that the `call` system call failed, using `errno` as return code (like for
+    /// `sysctl`). It must be called right after the failing call.
+    pub(crate) fn report_errno(&self, call: &str) {
+        if self.0.is_some() {
+            self.report(call, std::io::Error::last_os_error());
+        }
+    }
+}
+
 pub(crate) fn vec_to_rust(buf: Vec<i8>) -> Option<String> {
     String::from_utf8(
         buf.into_iter()
@@ -44,6 +73,31 @@ pub(crate) fn vec_to_rust(buf: Vec<i8>) -> Option<String> {
     .ok()
 }
 
+pub(crate) unsafe fn cfstring_to_string(v: CFStringRef) -> Option<String> {
+    let len_utf16 = cfs::CFStringGetLength(v) as usize;
+    let len_bytes = len_utf16 * 2; // Two bytes per UTF-16 codepoint.
+
+    let v_ptr = cfs::CFStringGetCStringPtr(v, cfs::kCFStringEncodingUTF8);
+    if v_ptr.is_null() {
+        // Fallback on CFStringGetString to read the underlying bytes from the CFString.
+        let mut buf = vec![0; len_bytes];
+        let success = cfs::CFStringGetCString(
+            v,
+            buf.as_mut_ptr(),
+            len_bytes as _,
+            cfs::kCFStringEncodingUTF8,
+        );
+
+        if success != 0 {
+            vec_to_rust(buf)
+        } else {
+            None
+        }
+    } else {
+        crate::unix::utils::cstr_to_rust_with_size(v_ptr, Some(len_bytes))
+    }
+}
+
 pub(crate) unsafe fn get_sys_value(mut len: usize, value: *mut c_void, mib: &mut [i32]) -> bool {
     sysctl(
         mib.as_mut_ptr(),
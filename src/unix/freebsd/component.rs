@@ -27,6 +27,11 @@ impl ComponentInner {
         &self.label
     }
 
+    pub(crate) fn is_cpu(&self) -> bool {
+        // Only the CPU cores temperatures are listed.
+        true
+    }
+
     pub(crate) fn refresh(&mut self) {
         unsafe {
             if let Some(temperature) = refresh_component(&self.id) {
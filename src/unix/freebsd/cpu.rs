@@ -3,6 +3,7 @@
 use crate::sys::utils::{
     get_sys_value_array, get_sys_value_by_name, get_sys_value_str_by_name, init_mib, VecSwitcher,
 };
+use crate::utils::smooth_cpu_usage;
 use crate::{Cpu, CpuRefreshKind};
 
 use libc::{c_int, c_ulong};
@@ -34,6 +35,8 @@ pub(crate) struct CpusWrapper {
     // For each CPU usage.
     cp_times: VecSwitcher<c_ulong>,
     nb_cpus: usize,
+    /// Smoothing factor applied to the CPU usage (see `System::set_cpu_smoothing`).
+    pub(crate) cpu_smoothing: f32,
 }
 
 impl CpusWrapper {
@@ -56,11 +59,18 @@ impl CpusWrapper {
                 cp_time: VecSwitcher::new(vec![0; libc::CPUSTATES as usize]),
                 cp_times: VecSwitcher::new(vec![0; nb_cpus * libc::CPUSTATES as usize]),
                 nb_cpus,
+                cpu_smoothing: 1.,
             }
         }
     }
 
     pub(crate) fn refresh(&mut self, refresh_kind: CpuRefreshKind) {
+        // There is nothing to blend the CPU usage with before the first refresh.
+        let alpha = if self.cpus.is_empty() {
+            1.
+        } else {
+            self.cpu_smoothing
+        };
         if self.cpus.is_empty() {
             let mut frequency = 0;
 
@@ -88,17 +98,29 @@ impl CpusWrapper {
             self.got_cpu_frequency = true;
         }
         if refresh_kind.cpu_usage() {
-            self.get_cpu_usage();
+            self.get_cpu_usage(false, alpha);
         }
     }
 
-    fn get_cpu_usage(&mut self) {
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {
+        if self.cpus.is_empty() {
+            self.refresh(CpuRefreshKind::new().with_cpu_usage());
+        } else {
+            self.get_cpu_usage(true, self.cpu_smoothing);
+        }
+    }
+
+    /// Updates the CPU usage, blending it with the previous one with `alpha`. If
+    /// `only_global_cpu` is `true`, `kern.cp_times` isn't read and the CPUs are left untouched.
+    fn get_cpu_usage(&mut self, only_global_cpu: bool, alpha: f32) {
         unsafe {
             get_sys_value_array(&self.mib_cp_time, self.cp_time.get_mut());
-            get_sys_value_array(&self.mib_cp_times, self.cp_times.get_mut());
+            if !only_global_cpu {
+                get_sys_value_array(&self.mib_cp_times, self.cp_times.get_mut());
+            }
         }
 
-        fn fill_cpu(proc_: &mut Cpu, new_cp_time: &[c_ulong], old_cp_time: &[c_ulong]) {
+        fn fill_cpu(proc_: &mut Cpu, new_cp_time: &[c_ulong], old_cp_time: &[c_ulong], alpha: f32) {
             let mut total_new: u64 = 0;
             let mut total_old: u64 = 0;
             let mut cp_diff: c_ulong = 0;
@@ -113,31 +135,47 @@ impl CpusWrapper {
                 total_old = total_old.saturating_add(old_cp_time[i] as _);
             }
 
+            let idle = libc::CP_IDLE as usize;
+            proc_.inner.times = (total_new.saturating_sub(new_cp_time[idle] as _), total_new);
+
             let total_diff = total_new.saturating_sub(total_old);
-            if total_diff < 1 {
-                proc_.inner.cpu_usage = 0.;
+            let cpu_usage = if total_diff < 1 {
+                proc_.inner.nice_usage = 0.;
+                0.
             } else {
-                proc_.inner.cpu_usage = cp_diff as f32 / total_diff as f32 * 100.;
-            }
+                let nice = libc::CP_NICE as usize;
+                let nice_diff = new_cp_time[nice].saturating_sub(old_cp_time[nice]);
+                // `cp_time` values grow quickly, so `f64` is used to keep the ratio exact.
+                proc_.inner.nice_usage = (nice_diff as f64 / total_diff as f64 * 100.) as f32;
+                (cp_diff as f64 / total_diff as f64 * 100.) as f32
+            };
+            proc_.inner.cpu_usage = smooth_cpu_usage(proc_.inner.cpu_usage, cpu_usage, alpha);
         }
 
         fill_cpu(
             &mut self.global_cpu,
             self.cp_time.get_new(),
             self.cp_time.get_old(),
+            alpha,
         );
+        if only_global_cpu {
+            return;
+        }
         let old_cp_times = self.cp_times.get_old();
         let new_cp_times = self.cp_times.get_new();
         for (pos, proc_) in self.cpus.iter_mut().enumerate() {
             let index = pos * libc::CPUSTATES as usize;
 
-            fill_cpu(proc_, &new_cp_times[index..], &old_cp_times[index..]);
+            fill_cpu(proc_, &new_cp_times[index..], &old_cp_times[index..], alpha);
         }
     }
 }
 
+#[derive(Clone)]
 pub(crate) struct CpuInner {
     pub(crate) cpu_usage: f32,
+    pub(crate) nice_usage: f32,
+    times: (u64, u64),
     name: String,
     pub(crate) vendor_id: String,
     pub(crate) frequency: u64,
@@ -147,6 +185,8 @@ impl CpuInner {
     pub(crate) fn new(name: String, vendor_id: String, frequency: u64) -> Self {
         Self {
             cpu_usage: 0.,
+            nice_usage: 0.,
+            times: (0, 0),
             name,
             vendor_id,
             frequency,
@@ -157,6 +197,15 @@ impl CpuInner {
         self.cpu_usage
     }
 
+    pub(crate) fn nice_usage(&self) -> f32 {
+        self.nice_usage
+    }
+
+    /// Returns the time spent working and the total time since boot, in ticks.
+    pub(crate) fn times(&self) -> (u64, u64) {
+        self.times
+    }
+
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
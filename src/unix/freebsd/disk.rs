@@ -3,19 +3,33 @@
 use crate::{Disk, DiskKind};
 
 use std::ffi::{OsStr, OsString};
-use std::os::unix::ffi::OsStringExt;
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
 use std::path::{Path, PathBuf};
+use std::time::Instant;
 
 use super::utils::c_buf_to_utf8_str;
+use crate::unix::utils::is_root_mount_point;
+use crate::utils::TimeoutRunner;
 
 pub(crate) struct DiskInner {
     name: OsString,
+    device_name: Option<OsString>,
     c_mount_point: Vec<libc::c_char>,
     mount_point: PathBuf,
     total_space: u64,
     available_space: u64,
+    /// Available space before the last refresh.
+    previous_available_space: u64,
+    total_inodes: u64,
+    available_inodes: u64,
     file_system: OsString,
     is_removable: bool,
+    is_network: bool,
+    is_boot_disk: bool,
+    is_read_only: bool,
+    is_nosuid: bool,
+    is_noexec: bool,
+    timeout_runner: TimeoutRunner<Option<libc::statvfs>>,
 }
 
 impl DiskInner {
@@ -27,6 +41,10 @@ impl DiskInner {
         &self.name
     }
 
+    pub(crate) fn device_name(&self) -> Option<&OsStr> {
+        self.device_name.as_deref()
+    }
+
     pub(crate) fn file_system(&self) -> &OsStr {
         &self.file_system
     }
@@ -43,14 +61,74 @@ impl DiskInner {
         self.available_space
     }
 
+    pub(crate) fn previous_available_space(&self) -> u64 {
+        self.previous_available_space
+    }
+
+    pub(crate) fn total_inodes(&self) -> u64 {
+        self.total_inodes
+    }
+
+    pub(crate) fn available_inodes(&self) -> u64 {
+        self.available_inodes
+    }
+
     pub(crate) fn is_removable(&self) -> bool {
         self.is_removable
     }
 
+    pub(crate) fn is_network(&self) -> bool {
+        self.is_network
+    }
+
+    pub(crate) fn is_boot_disk(&self) -> bool {
+        self.is_boot_disk
+    }
+
+    pub(crate) fn is_read_only(&self) -> bool {
+        self.is_read_only
+    }
+
+    pub(crate) fn is_nosuid(&self) -> bool {
+        self.is_nosuid
+    }
+
+    pub(crate) fn is_noexec(&self) -> bool {
+        self.is_noexec
+    }
+
+    pub(crate) fn is_timed_out(&self) -> bool {
+        self.timeout_runner.is_timed_out()
+    }
+
     pub(crate) fn refresh(&mut self) -> bool {
-        unsafe {
-            let mut vfs: libc::statvfs = std::mem::zeroed();
-            refresh_disk(self, &mut vfs)
+        self.timeout_runner.reset();
+        let vfs = unsafe { get_statvfs(&self.c_mount_point) };
+        self.update_space(vfs)
+    }
+
+    pub(crate) fn start_refresh(&mut self) {
+        let c_mount_point = self.c_mount_point.clone();
+        self.timeout_runner
+            .start(move || unsafe { get_statvfs(&c_mount_point) });
+    }
+
+    pub(crate) fn wait_refresh(&mut self, deadline: Instant) -> bool {
+        let vfs = self.timeout_runner.wait(deadline);
+        self.update_space(vfs.flatten())
+    }
+
+    fn update_space(&mut self, vfs: Option<libc::statvfs>) -> bool {
+        if let Some(vfs) = vfs {
+            let f_frsize: u64 = vfs.f_frsize as _;
+            self.total_space = vfs.f_blocks.saturating_mul(f_frsize);
+            self.previous_available_space = self.available_space;
+            self.available_space = vfs.f_bavail.saturating_mul(f_frsize);
+            self.total_inodes = vfs.f_files;
+            self.available_inodes = vfs.f_favail;
+            true
+        } else {
+            false
         }
     }
 }
@@ -59,6 +137,7 @@ impl crate::DisksInner {
     pub(crate) fn new() -> Self {
         Self {
             disks: Vec::with_capacity(2),
+            list_timeout_runner: TimeoutRunner::default(),
         }
     }
 
@@ -78,15 +157,13 @@ impl crate::DisksInner {
 // FIXME: if you want to get disk I/O usage:
 // statfs.[f_syncwrites, f_asyncwrites, f_syncreads, f_asyncreads]
 
-unsafe fn refresh_disk(disk: &mut DiskInner, vfs: &mut libc::statvfs) -> bool {
-    if libc::statvfs(disk.c_mount_point.as_ptr() as *const _, vfs) < 0 {
-        return false;
+/// Returns the file system information of the disk mounted on `c_mount_point`.
+unsafe fn get_statvfs(c_mount_point: &[libc::c_char]) -> Option<libc::statvfs> {
+    let mut vfs: libc::statvfs = std::mem::zeroed();
+    if libc::statvfs(c_mount_point.as_ptr() as *const _, &mut vfs) < 0 {
+        return None;
     }
-    let f_frsize: u64 = vfs.f_frsize as _;
-
-    disk.total_space = vfs.f_blocks.saturating_mul(f_frsize);
-    disk.available_space = vfs.f_favail.saturating_mul(f_frsize);
-    true
+    Some(vfs)
 }
 
 pub unsafe fn get_all_list(container: &mut Vec<Disk>) {
@@ -152,17 +229,37 @@ pub unsafe fn get_all_list(container: &mut Vec<Disk>) {
         let is_removable =
             [b"USB", b"usb"].iter().any(|b| *b == &fs_type[..]) || fs_type.starts_with(b"/dev/cd");
 
+        // ZFS datasets (among others) aren't mounted from a device.
+        let device_name = std::ffi::CStr::from_ptr(fs_info.f_mntfromname.as_ptr())
+            .to_bytes()
+            .strip_prefix(b"/dev/")
+            .map(|name| OsStr::from_bytes(name).to_owned());
+
+        let is_network = fs_info.f_flags & libc::MNT_LOCAL == 0;
+        let is_boot_disk = !is_network && is_root_mount_point(Path::new(mount_point));
+
         let f_frsize: u64 = vfs.f_frsize as _;
+        let available_space = vfs.f_bavail.saturating_mul(f_frsize);
 
         container.push(Disk {
             inner: DiskInner {
                 name,
+                device_name,
                 c_mount_point: fs_info.f_mntonname.to_vec(),
                 mount_point: PathBuf::from(mount_point),
                 total_space: vfs.f_blocks.saturating_mul(f_frsize),
-                available_space: vfs.f_favail.saturating_mul(f_frsize),
+                available_space,
+                previous_available_space: available_space,
+                total_inodes: vfs.f_files,
+                available_inodes: vfs.f_favail,
                 file_system: OsString::from_vec(fs_type),
                 is_removable,
+                is_network,
+                is_boot_disk,
+                is_read_only: fs_info.f_flags & libc::MNT_RDONLY as u64 != 0,
+                is_nosuid: fs_info.f_flags & libc::MNT_NOSUID as u64 != 0,
+                is_noexec: fs_info.f_flags & libc::MNT_NOEXEC as u64 != 0,
+                timeout_runner: TimeoutRunner::default(),
             },
         });
     }
@@ -5,7 +5,7 @@ use std::mem::MaybeUninit;
 
 use super::utils;
 use crate::common::MacAddr;
-use crate::network::refresh_networks_addresses;
+use crate::network::{refresh_networks_addresses, InterfaceFlags};
 use crate::IpNetwork;
 use crate::NetworkData;
 
@@ -40,6 +40,10 @@ impl NetworksInner {
         refresh_networks_addresses(&mut self.interfaces);
     }
 
+    pub(crate) fn default_interface() -> Option<String> {
+        crate::unix::network_helper::get_default_interface()
+    }
+
     pub(crate) fn refresh(&mut self) {
         unsafe {
             self.refresh_interfaces(false);
@@ -93,6 +97,8 @@ impl NetworksInner {
                         old_and_new!(interface, ifi_opackets, old_ifi_opackets, data);
                         old_and_new!(interface, ifi_ierrors, old_ifi_ierrors, data);
                         old_and_new!(interface, ifi_oerrors, old_ifi_oerrors, data);
+                        interface.mtu = data.ifi_mtu as _;
+                        interface.link_speed = data.ifi_baudrate;
                         interface.updated = true;
                     }
                     hash_map::Entry::Vacant(e) => {
@@ -114,10 +120,14 @@ impl NetworksInner {
                                 old_ifi_ierrors: 0,
                                 ifi_oerrors: data.ifi_oerrors,
                                 old_ifi_oerrors: 0,
+                                mtu: data.ifi_mtu as _,
+                                link_speed: data.ifi_baudrate,
                                 updated: true,
                                 mac_addr: MacAddr::UNSPECIFIED,
                                 ip_networks: vec![],
+                                flags: InterfaceFlags::default(),
                             },
+                            elapsed: None,
                         });
                     }
                 }
@@ -147,12 +157,17 @@ pub(crate) struct NetworkDataInner {
     /// similar to `ifi_ierrors`
     ifi_oerrors: u64,
     old_ifi_oerrors: u64,
+    /// Maximum transmission unit, in bytes.
+    mtu: u64,
+    /// Link speed, in bits per second.
+    link_speed: u64,
     /// Whether or not the above data has been updated during refresh
     updated: bool,
     /// MAC address
     pub(crate) mac_addr: MacAddr,
     /// IP networks
     pub(crate) ip_networks: Vec<IpNetwork>,
+    pub(crate) flags: InterfaceFlags,
 }
 
 impl NetworkDataInner {
@@ -211,4 +226,24 @@ impl NetworkDataInner {
     pub(crate) fn ip_networks(&self) -> &[IpNetwork] {
         &self.ip_networks
     }
+
+    pub(crate) fn is_up(&self) -> bool {
+        self.flags.is_up
+    }
+
+    pub(crate) fn is_loopback(&self) -> bool {
+        self.flags.is_loopback
+    }
+
+    pub(crate) fn is_running(&self) -> bool {
+        self.flags.is_running
+    }
+
+    pub(crate) fn mtu(&self) -> u64 {
+        self.mtu
+    }
+
+    pub(crate) fn link_speed(&self) -> u64 {
+        self.link_speed
+    }
 }
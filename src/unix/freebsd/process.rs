@@ -1,6 +1,10 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, Signal, Uid};
+#[cfg(feature = "processes")]
+use crate::utils::RunTimeClock;
+use crate::{DiskUsage, Gid, PageFaults, Pid, ProcessStatus, Signal, Uid};
+#[cfg(feature = "processes")]
+use crate::{Process, ProcessRefreshKind};
 
 use std::ffi::{OsStr, OsString};
 use std::fmt;
@@ -8,6 +12,8 @@ use std::path::{Path, PathBuf};
 
 use libc::kill;
 
+use super::utils::get_sys_value;
+#[cfg(feature = "processes")]
 use super::utils::{get_sys_value_str, WrapMap};
 
 #[doc(hidden)]
@@ -41,6 +47,7 @@ impl fmt::Display for ProcessStatus {
     }
 }
 
+#[derive(Clone)]
 pub(crate) struct ProcessInner {
     pub(crate) name: OsString,
     pub(crate) cmd: Vec<OsString>,
@@ -52,8 +59,11 @@ pub(crate) struct ProcessInner {
     pub(crate) root: Option<PathBuf>,
     pub(crate) memory: u64,
     pub(crate) virtual_memory: u64,
+    #[cfg(feature = "processes")]
     pub(crate) updated: bool,
     cpu_usage: f32,
+    accumulated_cpu_time: u64,
+    page_faults: Option<PageFaults>,
     start_time: u64,
     run_time: u64,
     pub(crate) status: ProcessStatus,
@@ -125,6 +135,14 @@ impl ProcessInner {
         self.run_time
     }
 
+    pub(crate) fn accumulated_cpu_time(&self) -> u64 {
+        self.accumulated_cpu_time
+    }
+
+    pub(crate) fn page_faults(&self) -> Option<PageFaults> {
+        self.page_faults
+    }
+
     pub(crate) fn cpu_usage(&self) -> f32 {
         self.cpu_usage
     }
@@ -178,14 +196,35 @@ impl ProcessInner {
             }
         }
     }
+
+    pub(crate) fn is_being_traced(&self) -> Option<bool> {
+        unsafe {
+            let mut kproc: libc::kinfo_proc = std::mem::zeroed();
+            let mib = [
+                libc::CTL_KERN,
+                libc::KERN_PROC,
+                libc::KERN_PROC_PID,
+                self.pid.0,
+            ];
+            if !get_sys_value(&mib, &mut kproc) || kproc.ki_pid != self.pid.0 {
+                return None;
+            }
+            Some(kproc.ki_flag as libc::c_int & libc::P_TRACED != 0)
+        }
+    }
+
+    pub(crate) fn swapped_memory(&self) -> u64 {
+        0
+    }
 }
 
+#[cfg(feature = "processes")]
 pub(crate) unsafe fn get_process_data(
     kproc: &libc::kinfo_proc,
     wrap: &WrapMap,
     page_size: isize,
     fscale: f32,
-    now: u64,
+    clock: RunTimeClock,
     refresh_kind: ProcessRefreshKind,
 ) -> Result<Option<Process>, ()> {
     if kproc.ki_pid != 1 && (kproc.ki_flag as libc::c_int & libc::P_SYSTEM) != 0 {
@@ -199,6 +238,12 @@ pub(crate) unsafe fn get_process_data(
     } else {
         0.
     };
+    // `ki_runtime` is the CPU time used by the process, in microseconds.
+    let accumulated_cpu_time = if refresh_kind.cpu() {
+        kproc.ki_runtime / 1_000
+    } else {
+        0
+    };
     // Processes can be reparented apparently?
     let parent = if kproc.ki_ppid != 0 {
         Some(Pid(kproc.ki_ppid))
@@ -229,13 +274,21 @@ pub(crate) unsafe fn get_process_data(
         // same process.
         if proc_.start_time == start_time {
             proc_.cpu_usage = cpu_usage;
+            if refresh_kind.cpu() {
+                proc_.accumulated_cpu_time = accumulated_cpu_time;
+            }
             proc_.parent = parent;
             proc_.status = status;
             if refresh_kind.memory() {
                 proc_.virtual_memory = virtual_memory;
                 proc_.memory = memory;
+                proc_.page_faults = Some(PageFaults::new(
+                    proc_.page_faults,
+                    kproc.ki_rusage.ru_minflt as _,
+                    kproc.ki_rusage.ru_majflt as _,
+                ));
             }
-            proc_.run_time = now.saturating_sub(proc_.start_time);
+            proc_.run_time = clock.run_time(proc_.start_time);
 
             if refresh_kind.disk_usage() {
                 proc_.old_read_bytes = proc_.read_bytes;
@@ -264,40 +317,47 @@ pub(crate) unsafe fn get_process_data(
     // .map(|s| s.into())
     // .unwrap_or_else(PathBuf::new);
 
-    Ok(Some(Process {
-        inner: ProcessInner {
-            pid: Pid(kproc.ki_pid),
-            parent,
-            user_id: Uid(kproc.ki_ruid),
-            effective_user_id: Uid(kproc.ki_uid),
-            group_id: Gid(kproc.ki_rgid),
-            effective_group_id: Gid(kproc.ki_svgid),
-            start_time,
-            run_time: now.saturating_sub(start_time),
-            cpu_usage,
-            virtual_memory,
-            memory,
-            // procstat_getfiles
-            cwd: None,
-            exe: None,
-            // kvm_getargv isn't thread-safe so we get it in the main thread.
-            name: OsString::new(),
-            // kvm_getargv isn't thread-safe so we get it in the main thread.
-            cmd: Vec::new(),
-            // kvm_getargv isn't thread-safe so we get it in the main thread.
-            root: None,
-            // kvm_getenvv isn't thread-safe so we get it in the main thread.
-            environ: Vec::new(),
-            status,
-            read_bytes: kproc.ki_rusage.ru_inblock as _,
-            old_read_bytes: 0,
-            written_bytes: kproc.ki_rusage.ru_oublock as _,
-            old_written_bytes: 0,
-            updated: false,
-        },
-    }))
+    Ok(Some(Process::new(ProcessInner {
+        pid: Pid(kproc.ki_pid),
+        parent,
+        user_id: Uid(kproc.ki_ruid),
+        effective_user_id: Uid(kproc.ki_uid),
+        group_id: Gid(kproc.ki_rgid),
+        effective_group_id: Gid(kproc.ki_svgid),
+        start_time,
+        run_time: clock.run_time(start_time),
+        cpu_usage,
+        accumulated_cpu_time,
+        page_faults: refresh_kind.memory().then(|| {
+            PageFaults::new(
+                None,
+                kproc.ki_rusage.ru_minflt as _,
+                kproc.ki_rusage.ru_majflt as _,
+            )
+        }),
+        virtual_memory,
+        memory,
+        // procstat_getfiles
+        cwd: None,
+        exe: None,
+        // kvm_getargv isn't thread-safe so we get it in the main thread.
+        name: OsString::new(),
+        // kvm_getargv isn't thread-safe so we get it in the main thread.
+        cmd: Vec::new(),
+        // kvm_getargv isn't thread-safe so we get it in the main thread.
+        root: None,
+        // kvm_getenvv isn't thread-safe so we get it in the main thread.
+        environ: Vec::new(),
+        status,
+        read_bytes: kproc.ki_rusage.ru_inblock as _,
+        old_read_bytes: 0,
+        written_bytes: kproc.ki_rusage.ru_oublock as _,
+        old_written_bytes: 0,
+        updated: false,
+    })))
 }
 
+#[cfg(feature = "processes")]
 pub(crate) unsafe fn get_exe(
     exe: &mut Option<PathBuf>,
     pid: crate::Pid,
@@ -1,22 +1,30 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessInner, ProcessRefreshKind,
-};
+use crate::{Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, PowerSource, Process, SwapFile};
+#[cfg(feature = "processes")]
+use crate::{ProcessInner, ProcessRefreshKind, Uid};
 
+#[cfg(feature = "processes")]
 use std::cell::UnsafeCell;
 use std::collections::HashMap;
 use std::ffi::CStr;
 use std::mem::MaybeUninit;
-use std::path::{Path, PathBuf};
+use std::path::Path;
+#[cfg(feature = "processes")]
+use std::path::PathBuf;
 use std::ptr::NonNull;
 
-use crate::sys::cpu::{physical_core_count, CpusWrapper};
+use crate::sys::cpu::{get_nb_cpus, physical_core_count, CpusWrapper};
+#[cfg(feature = "processes")]
 use crate::sys::process::get_exe;
+#[cfg(feature = "processes")]
+use crate::sys::utils::from_cstr_array;
 use crate::sys::utils::{
-    self, boot_time, c_buf_to_os_string, from_cstr_array, get_sys_value, get_sys_value_by_name,
-    get_system_info, init_mib,
+    self, boot_time, c_buf_to_os_string, get_sys_value, get_sys_value_by_name, get_system_info,
+    init_mib,
 };
+#[cfg(feature = "processes")]
+use crate::utils::{take_exited_processes, RunTimeClock};
 
 use libc::c_int;
 
@@ -68,19 +76,27 @@ impl SystemInner {
         self.cpus.refresh(refresh_kind)
     }
 
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {
+        self.cpus.refresh_global_cpu_usage()
+    }
+
     pub(crate) fn refresh_cpu_list(&mut self, refresh_kind: CpuRefreshKind) {
         self.cpus = CpusWrapper::new();
         self.cpus.refresh(refresh_kind);
     }
 
+    #[cfg(feature = "processes")]
     pub(crate) fn refresh_processes_specifics(
         &mut self,
         filter: Option<&[Pid]>,
+        uid_filter: Option<&Uid>,
         refresh_kind: ProcessRefreshKind,
+        exited: Option<&mut Vec<Process>>,
     ) {
-        unsafe { self.refresh_procs(filter, refresh_kind) }
+        unsafe { self.refresh_procs(filter, uid_filter, refresh_kind, exited) }
     }
 
+    #[cfg(feature = "processes")]
     pub(crate) fn refresh_process_specifics(
         &mut self,
         pid: Pid,
@@ -94,7 +110,7 @@ impl SystemInner {
                 sysinfo_debug!("kvm_getprocs returned nothing...");
                 return false;
             }
-            let now = super::utils::get_now();
+            let clock = RunTimeClock::now();
 
             let fscale = self.system_info.fscale;
             let page_size = self.system_info.page_size as isize;
@@ -127,7 +143,7 @@ impl SystemInner {
                 &proc_list,
                 page_size,
                 fscale,
-                now,
+                clock,
                 refresh_kind,
             ) {
                 Ok(Some(process)) => {
@@ -150,6 +166,36 @@ impl SystemInner {
         &self.process_list
     }
 
+    #[cfg(feature = "processes")]
+    pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
+        &mut self.process_list
+    }
+
+    #[cfg(feature = "processes")]
+    pub(crate) fn list_pids(&self) -> Vec<Pid> {
+        unsafe {
+            let mut count = 0;
+            let procs = libc::kvm_getprocs(
+                self.system_info.kd.as_ptr(),
+                libc::KERN_PROC_PROC,
+                0,
+                &mut count,
+            );
+            if count < 1 {
+                sysinfo_debug!("kvm_getprocs returned nothing...");
+                return Vec::new();
+            }
+            std::slice::from_raw_parts(procs, count as _)
+                .iter()
+                .map(|kproc| Pid(kproc.ki_pid))
+                .collect()
+        }
+    }
+
+    pub(crate) fn remove_process(&mut self, pid: Pid) -> Option<Process> {
+        self.process_list.remove(&pid)
+    }
+
     pub(crate) fn process(&self, pid: Pid) -> Option<&Process> {
         self.process_list.get(&pid)
     }
@@ -162,14 +208,43 @@ impl SystemInner {
         &self.cpus.cpus
     }
 
+    pub(crate) fn set_error_callback(&mut self, _callback: crate::utils::ErrorCallback) {}
+
+    pub(crate) fn set_cpu_smoothing(&mut self, alpha: f32) {
+        self.cpus.cpu_smoothing = alpha;
+    }
+
+    pub(crate) fn cpu_smoothing(&self) -> f32 {
+        self.cpus.cpu_smoothing
+    }
+
+    pub(crate) fn logical_cpu_count(&self) -> Option<usize> {
+        Some(unsafe { get_nb_cpus() })
+    }
+
+    pub(crate) fn online_cpu_count(&self) -> Option<usize> {
+        match unsafe { libc::sysconf(libc::_SC_NPROCESSORS_ONLN) } {
+            count if count > 0 => Some(count as _),
+            _ => self.logical_cpu_count(),
+        }
+    }
+
     pub(crate) fn physical_core_count(&self) -> Option<usize> {
         physical_core_count()
     }
 
+    pub(crate) fn cache_size(&self, _level: u8) -> Option<u64> {
+        None
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
 
+    pub(crate) fn page_size(&self) -> u64 {
+        self.system_info.page_size as _
+    }
+
     pub(crate) fn free_memory(&self) -> u64 {
         self.mem_free
     }
@@ -195,6 +270,14 @@ impl SystemInner {
         self.swap_used
     }
 
+    pub(crate) fn swap_files(&self) -> Vec<SwapFile> {
+        self.system_info.get_swap_files()
+    }
+
+    pub(crate) fn idle_time(&self) -> Option<u64> {
+        None
+    }
+
     pub(crate) fn uptime() -> u64 {
         unsafe {
             let csec = libc::time(std::ptr::null_mut());
@@ -203,6 +286,21 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn power_source() -> PowerSource {
+        let mut acline: c_int = 0;
+        unsafe {
+            // This sysctl doesn't exist without ACPI battery support, so very likely a desktop.
+            if !get_sys_value_by_name(b"hw.acpi.acline\0", &mut acline) {
+                return PowerSource::Ac;
+            }
+        }
+        if acline != 0 {
+            PowerSource::Ac
+        } else {
+            PowerSource::Battery
+        }
+    }
+
     pub(crate) fn boot_time() -> u64 {
         boot_time()
     }
@@ -253,6 +351,22 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn hardware_model() -> Option<String> {
+        let mut buf = [0 as libc::c_char; libc::KENV_MVALLEN as usize + 1];
+        unsafe {
+            if libc::kenv(
+                libc::KENV_GET,
+                b"smbios.system.product\0".as_ptr() as *const _,
+                buf.as_mut_ptr(),
+                buf.len() as _,
+            ) < 0
+            {
+                return None;
+            }
+        }
+        utils::c_buf_to_utf8_string(&buf).filter(|model| !model.is_empty())
+    }
+
     pub(crate) fn kernel_version() -> Option<String> {
         let mut kern_version: [c_int; 2] = [0; 2];
         unsafe {
@@ -285,7 +399,14 @@ impl SystemInner {
 }
 
 impl SystemInner {
-    unsafe fn refresh_procs(&mut self, filter: Option<&[Pid]>, refresh_kind: ProcessRefreshKind) {
+    #[cfg(feature = "processes")]
+    unsafe fn refresh_procs(
+        &mut self,
+        filter: Option<&[Pid]>,
+        uid_filter: Option<&Uid>,
+        refresh_kind: ProcessRefreshKind,
+        exited: Option<&mut Vec<Process>>,
+    ) {
         let mut count = 0;
         let kvm_procs = libc::kvm_getprocs(
             self.system_info.kd.as_ptr(),
@@ -329,19 +450,22 @@ impl SystemInner {
 
             let fscale = self.system_info.fscale;
             let page_size = self.system_info.page_size as isize;
-            let now = super::utils::get_now();
+            let clock = RunTimeClock::now();
             let proc_list = utils::WrapMap(UnsafeCell::new(&mut self.process_list));
 
             IterTrait::filter_map(crate::utils::into_iter(kvm_procs), |kproc| {
                 if !filter_callback(kproc, filter) {
                     return None;
                 }
+                if uid_filter.is_some_and(|uid| kproc.ki_ruid != uid.0) {
+                    return None;
+                }
                 super::process::get_process_data(
                     kproc,
                     &proc_list,
                     page_size,
                     fscale,
-                    now,
+                    clock,
                     refresh_kind,
                 )
                 .ok()?
@@ -350,6 +474,9 @@ impl SystemInner {
         };
 
         // We remove all processes that don't exist anymore.
+        if let Some(exited) = exited {
+            take_exited_processes(&mut self.process_list, exited, |p| !p.inner.updated);
+        }
         self.process_list
             .retain(|_, v| std::mem::replace(&mut v.inner.updated, false));
 
@@ -367,6 +494,7 @@ impl SystemInner {
     }
 }
 
+#[cfg(feature = "processes")]
 unsafe fn add_missing_proc_info(
     system_info: &mut SystemInfo,
     kproc: &libc::kinfo_proc,
@@ -560,6 +688,28 @@ impl SystemInfo {
         }
     }
 
+    fn get_swap_files(&self) -> Vec<SwapFile> {
+        const LEN: usize = 16;
+        let mut swap = MaybeUninit::<[libc::kvm_swap; LEN]>::uninit();
+        unsafe {
+            let nswap =
+                libc::kvm_getswapinfo(self.kd.as_ptr(), swap.as_mut_ptr() as *mut _, LEN as _, 0);
+            if nswap < 1 {
+                return Vec::new();
+            }
+            let swap = std::slice::from_raw_parts(
+                swap.as_ptr() as *mut libc::kvm_swap,
+                (nswap as usize).min(LEN),
+            );
+            swap.iter()
+                .map(|swap| SwapFile {
+                    path: Path::new("/dev").join(c_buf_to_os_string(&swap.ksw_devname)),
+                    size: (swap.ksw_total as u64).saturating_mul(self.page_size as _),
+                })
+                .collect()
+        }
+    }
+
     fn get_total_memory(&self) -> u64 {
         let mut nb_pages: u64 = 0;
         unsafe {
@@ -615,6 +765,7 @@ impl SystemInfo {
     }
 
     #[allow(clippy::collapsible_if)] // I keep as is for readability reasons.
+    #[cfg(feature = "processes")]
     unsafe fn get_proc_missing_info(
         &mut self,
         kproc: &libc::kinfo_proc,
@@ -1,13 +1,18 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{Pid, Process};
 use libc::{c_char, c_int, timeval};
-use std::cell::UnsafeCell;
-use std::collections::HashMap;
-use std::ffi::{CStr, OsStr, OsString};
+use std::ffi::{OsStr, OsString};
 use std::mem;
 use std::os::unix::ffi::OsStrExt;
-use std::time::SystemTime;
+
+#[cfg(feature = "processes")]
+use crate::{Pid, Process};
+#[cfg(feature = "processes")]
+use std::cell::UnsafeCell;
+#[cfg(feature = "processes")]
+use std::collections::HashMap;
+#[cfg(feature = "processes")]
+use std::ffi::CStr;
 
 /// This struct is used to switch between the "old" and "new" every time you use "get_mut".
 #[derive(Debug)]
@@ -143,6 +148,7 @@ pub(crate) fn c_buf_to_os_string(buf: &[libc::c_char]) -> OsString {
     c_buf_to_os_str(buf).to_owned()
 }
 
+#[cfg(feature = "processes")]
 pub(crate) unsafe fn get_sys_value_str(
     mib: &[c_int],
     buf: &mut [libc::c_char],
@@ -253,6 +259,7 @@ pub(crate) fn get_system_info(mib: &[c_int], default: Option<&str>) -> Option<St
     }
 }
 
+#[cfg(feature = "processes")]
 pub(crate) unsafe fn from_cstr_array(ptr: *const *const c_char) -> Vec<OsString> {
     if ptr.is_null() {
         return Vec::new();
@@ -277,24 +284,24 @@ pub(crate) unsafe fn from_cstr_array(ptr: *const *const c_char) -> Vec<OsString>
     ret
 }
 
-pub(crate) fn get_now() -> u64 {
-    SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .map(|n| n.as_secs())
-        .unwrap_or(0)
-}
-
 // All this is needed because `kinfo_proc` doesn't implement `Send` (because it contains pointers).
+#[cfg(feature = "processes")]
 pub(crate) struct WrapMap<'a>(pub UnsafeCell<&'a mut HashMap<Pid, Process>>);
 
+#[cfg(feature = "processes")]
 unsafe impl<'a> Send for WrapMap<'a> {}
+#[cfg(feature = "processes")]
 unsafe impl<'a> Sync for WrapMap<'a> {}
 
 #[repr(transparent)]
+#[cfg(feature = "processes")]
 pub(crate) struct KInfoProc(libc::kinfo_proc);
+#[cfg(feature = "processes")]
 unsafe impl Send for KInfoProc {}
+#[cfg(feature = "processes")]
 unsafe impl Sync for KInfoProc {}
 
+#[cfg(feature = "processes")]
 impl std::ops::Deref for KInfoProc {
     type Target = libc::kinfo_proc;
 
@@ -319,6 +319,13 @@ impl ComponentInner {
         &self.label
     }
 
+    pub(crate) fn is_cpu(&self) -> bool {
+        matches!(
+            self.name.as_str(),
+            "coretemp" | "k10temp" | "zenpower" | "cpu_thermal"
+        ) || matches!(self.sensor_type, Some(ThermalSensorType::CPUEmbeddedDiode))
+    }
+
     pub(crate) fn refresh(&mut self) {
         let current = self
             .input_file
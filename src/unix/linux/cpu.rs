@@ -5,9 +5,11 @@
 use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
 use std::time::Instant;
 
 use crate::sys::utils::to_u64;
+use crate::utils::smooth_cpu_usage;
 use crate::{Cpu, CpuRefreshKind};
 
 macro_rules! to_str {
@@ -27,6 +29,8 @@ pub(crate) struct CpusWrapper {
     got_cpu_frequency: bool,
     /// This field is needed to prevent updating when not enough time passed since last update.
     last_update: Option<Instant>,
+    /// Smoothing factor applied to the CPU usage (see `System::set_cpu_smoothing`).
+    pub(crate) cpu_smoothing: f32,
 }
 
 impl CpusWrapper {
@@ -54,9 +58,11 @@ impl CpusWrapper {
             need_cpus_update: true,
             got_cpu_frequency: false,
             last_update: None,
+            cpu_smoothing: 1.,
         }
     }
 
+    #[cfg(feature = "processes")]
     pub(crate) fn refresh_if_needed(
         &mut self,
         only_update_global_cpu: bool,
@@ -74,6 +80,8 @@ impl CpusWrapper {
             .unwrap_or(true);
 
         let first = self.cpus.is_empty();
+        // There is nothing to blend the CPU usage with before the first refresh.
+        let alpha = if first { 1. } else { self.cpu_smoothing };
         let mut vendors_brands = if first {
             get_vendor_id_and_brand()
         } else {
@@ -120,6 +128,7 @@ impl CpusWrapper {
                         parts.next().map(to_u64).unwrap_or(0),
                         parts.next().map(to_u64).unwrap_or(0),
                         parts.next().map(to_u64).unwrap_or(0),
+                        alpha,
                     );
                 }
                 if first || !only_update_global_cpu {
@@ -165,6 +174,7 @@ impl CpusWrapper {
                                 parts.next().map(to_u64).unwrap_or(0),
                                 parts.next().map(to_u64).unwrap_or(0),
                                 parts.next().map(to_u64).unwrap_or(0),
+                                alpha,
                             );
                         }
 
@@ -205,6 +215,7 @@ impl CpusWrapper {
         }
     }
 
+    #[cfg(feature = "processes")]
     pub(crate) fn get_global_raw_times(&self) -> (u64, u64) {
         (
             self.global_cpu.inner.total_time,
@@ -212,14 +223,17 @@ impl CpusWrapper {
         )
     }
 
+    #[cfg(feature = "processes")]
     pub(crate) fn len(&self) -> usize {
         self.cpus.len()
     }
 
+    #[cfg(feature = "processes")]
     pub(crate) fn is_empty(&self) -> bool {
         self.cpus.is_empty()
     }
 
+    #[cfg(feature = "processes")]
     pub(crate) fn set_need_cpus_update(&mut self) {
         self.need_cpus_update = true;
     }
@@ -307,11 +321,13 @@ impl CpuValues {
     }
 }
 
+#[derive(Clone)]
 pub(crate) struct CpuInner {
     old_values: CpuValues,
     new_values: CpuValues,
     pub(crate) name: String,
     cpu_usage: f32,
+    nice_usage: f32,
     total_time: u64,
     old_total_time: u64,
     pub(crate) frequency: u64,
@@ -345,6 +361,7 @@ impl CpuInner {
             old_values: CpuValues::new(),
             new_values,
             cpu_usage: 0f32,
+            nice_usage: 0f32,
             total_time: 0,
             old_total_time: 0,
             frequency,
@@ -365,11 +382,13 @@ impl CpuInner {
         steal: u64,
         guest: u64,
         guest_nice: u64,
+        alpha: f32,
     ) {
+        // The ticks are converted to `f64` as `f32` can't represent large tick counts exactly.
         macro_rules! min {
             ($a:expr, $b:expr, $def:expr) => {
                 if $a > $b {
-                    ($a - $b) as f32
+                    ($a - $b) as f64
                 } else {
                     $def
                 }
@@ -381,18 +400,32 @@ impl CpuInner {
         );
         self.total_time = self.new_values.total_time();
         self.old_total_time = self.old_values.total_time();
-        self.cpu_usage = min!(self.new_values.work_time(), self.old_values.work_time(), 0.)
+        let mut cpu_usage = (min!(self.new_values.work_time(), self.old_values.work_time(), 0.)
             / min!(self.total_time, self.old_total_time, 1.)
-            * 100.;
-        if self.cpu_usage > 100. {
-            self.cpu_usage = 100.; // to prevent the percentage to go above 100%
+            * 100.) as f32;
+        if cpu_usage > 100. {
+            cpu_usage = 100.; // to prevent the percentage to go above 100%
         }
+        self.cpu_usage = smooth_cpu_usage(self.cpu_usage, cpu_usage, alpha);
+        self.nice_usage = ((min!(self.new_values.nice, self.old_values.nice, 0.)
+            / min!(self.total_time, self.old_total_time, 1.)
+            * 100.) as f32)
+            .min(100.);
+    }
+
+    /// Returns the time spent working and the total time since boot, in ticks.
+    pub(crate) fn times(&self) -> (u64, u64) {
+        (self.new_values.work_time(), self.new_values.total_time())
     }
 
     pub(crate) fn cpu_usage(&self) -> f32 {
         self.cpu_usage
     }
 
+    pub(crate) fn nice_usage(&self) -> f32 {
+        self.nice_usage
+    }
+
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
@@ -446,6 +479,34 @@ pub(crate) fn get_cpu_frequency(cpu_core_index: usize) -> u64 {
         .unwrap_or_default()
 }
 
+/// Returns the size of the data (or unified) cache of the given `level` used by the first CPU.
+pub(crate) fn get_cache_size(level: u8) -> Option<u64> {
+    let read = |path: &Path| std::fs::read_to_string(path).ok();
+
+    for entry in std::fs::read_dir("/sys/devices/system/cpu/cpu0/cache")
+        .ok()?
+        .flatten()
+    {
+        let path = entry.path();
+        if read(&path.join("level")).and_then(|l| l.trim().parse::<u8>().ok()) != Some(level)
+            || read(&path.join("type")).is_some_and(|t| t.trim() == "Instruction")
+        {
+            continue;
+        }
+        // The size looks like `32K`.
+        let size = read(&path.join("size"))?;
+        let size = size.trim();
+        let (value, unit) = match size.as_bytes().last()? {
+            b'K' => (&size[..size.len() - 1], 1024),
+            b'M' => (&size[..size.len() - 1], 1024 * 1024),
+            b'G' => (&size[..size.len() - 1], 1024 * 1024 * 1024),
+            _ => (size, 1),
+        };
+        return value.parse::<u64>().ok().map(|value| value * unit);
+    }
+    None
+}
+
 #[allow(unused_assignments)]
 pub(crate) fn get_physical_core_count() -> Option<usize> {
     let mut s = String::new();
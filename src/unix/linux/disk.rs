@@ -1,6 +1,8 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::sys::utils::{get_all_utf8_data, to_cpath};
+use crate::unix::utils::is_root_mount_point;
+use crate::utils::TimeoutRunner;
 use crate::{Disk, DiskKind};
 
 use libc::statvfs;
@@ -9,6 +11,7 @@ use std::fs;
 use std::mem;
 use std::os::unix::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
+use std::time::Instant;
 
 macro_rules! cast {
     ($x:expr) => {
@@ -23,7 +26,17 @@ pub(crate) struct DiskInner {
     mount_point: PathBuf,
     total_space: u64,
     available_space: u64,
+    /// Available space before the last refresh.
+    previous_available_space: u64,
+    total_inodes: u64,
+    available_inodes: u64,
     is_removable: bool,
+    is_network: bool,
+    is_boot_disk: bool,
+    is_read_only: bool,
+    is_nosuid: bool,
+    is_noexec: bool,
+    timeout_runner: TimeoutRunner<Option<(u64, u64)>>,
 }
 
 impl DiskInner {
@@ -35,6 +48,13 @@ impl DiskInner {
         &self.device_name
     }
 
+    pub(crate) fn device_name(&self) -> Option<&OsStr> {
+        self.device_name
+            .as_bytes()
+            .strip_prefix(b"/dev/")
+            .map(OsStr::from_bytes)
+    }
+
     pub(crate) fn file_system(&self) -> &OsStr {
         &self.file_system
     }
@@ -51,21 +71,85 @@ impl DiskInner {
         self.available_space
     }
 
+    pub(crate) fn previous_available_space(&self) -> u64 {
+        self.previous_available_space
+    }
+
+    pub(crate) fn total_inodes(&self) -> u64 {
+        self.total_inodes
+    }
+
+    pub(crate) fn available_inodes(&self) -> u64 {
+        self.available_inodes
+    }
+
     pub(crate) fn is_removable(&self) -> bool {
         self.is_removable
     }
 
+    pub(crate) fn is_network(&self) -> bool {
+        self.is_network
+    }
+
+    pub(crate) fn is_boot_disk(&self) -> bool {
+        self.is_boot_disk
+    }
+
+    pub(crate) fn is_read_only(&self) -> bool {
+        self.is_read_only
+    }
+
+    pub(crate) fn is_nosuid(&self) -> bool {
+        self.is_nosuid
+    }
+
+    pub(crate) fn is_noexec(&self) -> bool {
+        self.is_noexec
+    }
+
+    pub(crate) fn is_timed_out(&self) -> bool {
+        self.timeout_runner.is_timed_out()
+    }
+
     pub(crate) fn refresh(&mut self) -> bool {
-        unsafe {
-            let mut stat: statvfs = mem::zeroed();
-            let mount_point_cpath = to_cpath(&self.mount_point);
-            if retry_eintr!(statvfs(mount_point_cpath.as_ptr() as *const _, &mut stat)) == 0 {
-                let tmp = cast!(stat.f_bsize).saturating_mul(cast!(stat.f_bavail));
-                self.available_space = cast!(tmp);
-                true
-            } else {
-                false
-            }
+        self.timeout_runner.reset();
+        self.update_available(get_available(&to_cpath(&self.mount_point)))
+    }
+
+    pub(crate) fn start_refresh(&mut self) {
+        let mount_point_cpath = to_cpath(&self.mount_point);
+        self.timeout_runner
+            .start(move || get_available(&mount_point_cpath));
+    }
+
+    pub(crate) fn wait_refresh(&mut self, deadline: Instant) -> bool {
+        let available = self.timeout_runner.wait(deadline);
+        self.update_available(available.flatten())
+    }
+
+    fn update_available(&mut self, available: Option<(u64, u64)>) -> bool {
+        if let Some((available_space, available_inodes)) = available {
+            self.previous_available_space = self.available_space;
+            self.available_space = available_space;
+            self.available_inodes = available_inodes;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Returns the available space and inodes of the disk mounted on `mount_point_cpath`.
+fn get_available(mount_point_cpath: &[u8]) -> Option<(u64, u64)> {
+    unsafe {
+        let mut stat: statvfs = mem::zeroed();
+        if retry_eintr!(statvfs(mount_point_cpath.as_ptr() as *const _, &mut stat)) == 0 {
+            Some((
+                cast!(stat.f_bsize).saturating_mul(cast!(stat.f_bavail)),
+                cast!(stat.f_favail),
+            ))
+        } else {
+            None
         }
     }
 }
@@ -74,6 +158,7 @@ impl crate::DisksInner {
     pub(crate) fn new() -> Self {
         Self {
             disks: Vec::with_capacity(2),
+            list_timeout_runner: TimeoutRunner::default(),
         }
     }
 
@@ -103,6 +188,9 @@ fn new_disk(
     let type_ = find_type_for_device_name(device_name);
     let mut total = 0;
     let mut available = 0;
+    let mut total_inodes = 0;
+    let mut available_inodes = 0;
+    let mut flags = 0;
     unsafe {
         let mut stat: statvfs = mem::zeroed();
         if retry_eintr!(statvfs(mount_point_cpath.as_ptr() as *const _, &mut stat)) == 0 {
@@ -111,6 +199,9 @@ fn new_disk(
             let bavail = cast!(stat.f_bavail);
             total = bsize.saturating_mul(blocks);
             available = bsize.saturating_mul(bavail);
+            total_inodes = cast!(stat.f_files);
+            available_inodes = cast!(stat.f_favail);
+            flags = stat.f_flag;
         }
         if total == 0 {
             return None;
@@ -119,6 +210,9 @@ fn new_disk(
         let is_removable = removable_entries
             .iter()
             .any(|e| e.as_os_str() == device_name);
+        let is_network = is_network_file_system(file_system);
+        // Network mounts are skipped to not risk hanging on them.
+        let is_boot_disk = !is_network && is_root_mount_point(&mount_point);
         Some(Disk {
             inner: DiskInner {
                 type_,
@@ -127,12 +221,29 @@ fn new_disk(
                 mount_point,
                 total_space: cast!(total),
                 available_space: cast!(available),
+                previous_available_space: cast!(available),
+                total_inodes,
+                available_inodes,
                 is_removable,
+                is_network,
+                is_boot_disk,
+                is_read_only: flags & libc::ST_RDONLY != 0,
+                is_nosuid: flags & libc::ST_NOSUID != 0,
+                is_noexec: flags & libc::ST_NOEXEC != 0,
+                timeout_runner: TimeoutRunner::default(),
             },
         })
     }
 }
 
+// Network file systems are only listed if the `linux-netdevs` feature is enabled.
+fn is_network_file_system(file_system: &OsStr) -> bool {
+    matches!(
+        file_system.as_bytes(),
+        b"nfs" | b"nfs4" | b"cifs" | b"smb3" | b"smbfs" | b"ncpfs" | b"afs" | b"9p" | b"fuse.sshfs"
+    )
+}
+
 #[allow(clippy::manual_range_contains)]
 fn find_type_for_device_name(device_name: &OsStr) -> DiskKind {
     // The format of devices are as follows:
@@ -6,7 +6,7 @@ use std::io::Read;
 use std::path::Path;
 
 use crate::common::{IpNetwork, MacAddr};
-use crate::network::refresh_networks_addresses;
+use crate::network::{refresh_networks_addresses, InterfaceFlags};
 use crate::NetworkData;
 
 macro_rules! old_and_new {
@@ -51,7 +51,8 @@ fn refresh_networks_list_from_sysfs(
         }
 
         for entry in dir.flatten() {
-            let parent = &entry.path().join("statistics");
+            let path = entry.path();
+            let parent = &path.join("statistics");
             let entry = match entry.file_name().into_string() {
                 Ok(entry) => entry,
                 Err(_) => continue,
@@ -64,6 +65,9 @@ fn refresh_networks_list_from_sysfs(
             let tx_errors = read(parent, "tx_errors", &mut data);
             // let rx_compressed = read(parent, "rx_compressed", &mut data);
             // let tx_compressed = read(parent, "tx_compressed", &mut data);
+            let mtu = read(&path, "mtu", &mut data);
+            // The speed is in Mbit/s, and is `-1` (or can't be read) when it's unknown.
+            let link_speed = read(&path, "speed", &mut data).saturating_mul(1_000_000);
             match interfaces.entry(entry) {
                 hash_map::Entry::Occupied(mut e) => {
                     let interface = e.get_mut();
@@ -77,6 +81,8 @@ fn refresh_networks_list_from_sysfs(
                     old_and_new!(interface, tx_errors, old_tx_errors);
                     // old_and_new!(e, rx_compressed, old_rx_compressed);
                     // old_and_new!(e, tx_compressed, old_tx_compressed);
+                    interface.mtu = mtu;
+                    interface.link_speed = link_speed;
                     interface.updated = true;
                 }
                 hash_map::Entry::Vacant(e) => {
@@ -96,12 +102,16 @@ fn refresh_networks_list_from_sysfs(
                             old_tx_errors: tx_errors,
                             mac_addr: MacAddr::UNSPECIFIED,
                             ip_networks: vec![],
+                            flags: InterfaceFlags::default(),
+                            mtu,
+                            link_speed,
                             // rx_compressed,
                             // old_rx_compressed: rx_compressed,
                             // tx_compressed,
                             // old_tx_compressed: tx_compressed,
                             updated: true,
                         },
+                        elapsed: None,
                     });
                 }
             };
@@ -139,6 +149,63 @@ impl NetworksInner {
         refresh_networks_list_from_sysfs(&mut self.interfaces, Path::new("/sys/class/net/"));
         refresh_networks_addresses(&mut self.interfaces);
     }
+
+    pub(crate) fn default_interface() -> Option<String> {
+        let ipv4_routes = std::fs::read_to_string("/proc/net/route").unwrap_or_default();
+        let ipv6_routes = std::fs::read_to_string("/proc/net/ipv6_route").unwrap_or_default();
+        get_default_route_interface(&ipv4_routes, &ipv6_routes)
+    }
+}
+
+// Routes flags from `linux/route.h`.
+const RTF_UP: u32 = 0x0001;
+const RTF_REJECT: u32 = 0x0200;
+
+/// Returns the interface of the default route with the lowest metric, preferring IPv4 over IPv6.
+///
+/// `ipv4_routes` and `ipv6_routes` are the content of `/proc/net/route` and
+/// `/proc/net/ipv6_route`.
+fn get_default_route_interface(ipv4_routes: &str, ipv6_routes: &str) -> Option<String> {
+    let is_usable = |flags: &str| {
+        u32::from_str_radix(flags, 16).is_ok_and(|flags| flags & (RTF_UP | RTF_REJECT) == RTF_UP)
+    };
+    let parse_metric = |metric: &str| u32::from_str_radix(metric, 16).unwrap_or(u32::MAX);
+
+    // Columns: Iface, Destination, Gateway, Flags, RefCnt, Use, Metric, Mask, ...
+    let ipv4_default = ipv4_routes
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            match fields[..] {
+                [iface, "00000000", _, flags, _, _, metric, "00000000", ..] if is_usable(flags) => {
+                    Some((parse_metric(metric), iface))
+                }
+                _ => None,
+            }
+        })
+        .min();
+    // Columns: Destination, Prefix length, Source, Source prefix length, Next hop, Metric,
+    // RefCnt, Use, Flags, Iface.
+    let ipv6_default = || {
+        ipv6_routes
+            .lines()
+            .filter_map(|line| {
+                let fields = line.split_whitespace().collect::<Vec<_>>();
+                match fields[..] {
+                    [dest, "00", _, _, _, metric, _, _, flags, iface]
+                        if dest.bytes().all(|c| c == b'0') && is_usable(flags) =>
+                    {
+                        Some((parse_metric(metric), iface))
+                    }
+                    _ => None,
+                }
+            })
+            .min()
+    };
+    ipv4_default
+        .or_else(ipv6_default)
+        .map(|(_, iface)| iface.to_owned())
 }
 
 pub(crate) struct NetworkDataInner {
@@ -165,6 +232,11 @@ pub(crate) struct NetworkDataInner {
     /// MAC address
     pub(crate) mac_addr: MacAddr,
     pub(crate) ip_networks: Vec<IpNetwork>,
+    pub(crate) flags: InterfaceFlags,
+    /// Maximum transmission unit, in bytes.
+    mtu: u64,
+    /// Link speed, in bits per second.
+    link_speed: u64,
     // /// Indicates the number of compressed packets received by this
     // /// network device. This value might only be relevant for interfaces
     // /// that support packet compression (e.g: PPP).
@@ -277,11 +349,31 @@ impl NetworkDataInner {
     pub(crate) fn ip_networks(&self) -> &[IpNetwork] {
         &self.ip_networks
     }
+
+    pub(crate) fn is_up(&self) -> bool {
+        self.flags.is_up
+    }
+
+    pub(crate) fn is_loopback(&self) -> bool {
+        self.flags.is_loopback
+    }
+
+    pub(crate) fn is_running(&self) -> bool {
+        self.flags.is_running
+    }
+
+    pub(crate) fn mtu(&self) -> u64 {
+        self.mtu
+    }
+
+    pub(crate) fn link_speed(&self) -> u64 {
+        self.link_speed
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::refresh_networks_list_from_sysfs;
+    use super::{get_default_route_interface, refresh_networks_list_from_sysfs};
     use std::collections::HashMap;
     use std::fs;
 
@@ -325,4 +417,27 @@ mod test {
         refresh_networks_list_from_sysfs(&mut interfaces, sys_net_dir.path());
         assert_eq!(interfaces.keys().collect::<Vec<_>>(), ["itf2"]);
     }
+
+    #[test]
+    fn default_route_interface() {
+        let ipv4_routes = "\
+Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT
+wlan0\t00000000\t0101A8C0\t0003\t0\t0\t600\t00000000\t0\t0\t0
+eth0\t00000000\t0100A8C0\t0003\t0\t0\t100\t00000000\t0\t0\t0
+eth0\t0000A8C0\t00000000\t0001\t0\t0\t100\t00FFFFFF\t0\t0\t0
+";
+        let ipv6_routes = "\
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 fe800000000000000000000000000001 00000400 00000001 00000000 00000003     eth1
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 00000000000000000000000000000000 ffffffff 00000001 00000000 00200200       lo
+";
+        assert_eq!(
+            get_default_route_interface(ipv4_routes, ipv6_routes).as_deref(),
+            Some("eth0")
+        );
+        assert_eq!(
+            get_default_route_interface("", ipv6_routes).as_deref(),
+            Some("eth1")
+        );
+        assert_eq!(get_default_route_interface("", ""), None);
+    }
 }
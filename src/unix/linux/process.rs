@@ -1,25 +1,39 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use std::cell::UnsafeCell;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 use std::ffi::{OsStr, OsString};
 use std::fmt;
+use std::path::{Path, PathBuf};
+
+use libc::kill;
+
+use crate::sys::utils::get_all_utf8_data;
+use crate::{DiskUsage, Gid, PageFaults, Pid, ProcessStatus, Signal, ThreadKind, Uid};
+
+#[cfg(feature = "processes")]
+use std::cell::UnsafeCell;
+#[cfg(feature = "processes")]
+use std::collections::HashMap;
+#[cfg(feature = "processes")]
 use std::fs::{self, DirEntry, File};
+#[cfg(feature = "processes")]
 use std::io::Read;
+#[cfg(feature = "processes")]
 use std::os::unix::ffi::OsStrExt;
-use std::path::{Path, PathBuf};
+#[cfg(feature = "processes")]
 use std::str::{self, FromStr};
 
+#[cfg(feature = "processes")]
 use bstr::ByteSlice;
-use libc::{c_ulong, gid_t, kill, uid_t};
+#[cfg(feature = "processes")]
+use libc::{c_ulong, gid_t, uid_t};
 
+#[cfg(feature = "processes")]
 use crate::sys::system::SystemInfo;
-use crate::sys::utils::{
-    get_all_data_from_file, get_all_utf8_data, realpath, FileCounter, PathHandler, PathPush,
-};
-use crate::{
-    DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, Signal, ThreadKind, Uid,
-};
+#[cfg(feature = "processes")]
+use crate::sys::utils::{get_all_data_from_file, realpath, FileCounter, PathHandler, PathPush};
+#[cfg(feature = "processes")]
+use crate::{Process, ProcessRefreshKind};
 
 #[doc(hidden)]
 impl From<char> for ProcessStatus {
@@ -100,10 +114,13 @@ pub(crate) struct ProcessInner {
     pub(crate) root: Option<PathBuf>,
     pub(crate) memory: u64,
     pub(crate) virtual_memory: u64,
+    swapped_memory: u64,
     utime: u64,
     stime: u64,
     old_utime: u64,
     old_stime: u64,
+    accumulated_cpu_time: u64,
+    page_faults: Option<PageFaults>,
     start_time_without_boot_time: u64,
     start_time: u64,
     run_time: u64,
@@ -115,6 +132,7 @@ pub(crate) struct ProcessInner {
     effective_group_id: Option<Gid>,
     pub(crate) status: ProcessStatus,
     pub(crate) tasks: Option<HashSet<Pid>>,
+    #[cfg(feature = "processes")]
     pub(crate) stat_file: Option<FileCounter>,
     old_read_bytes: u64,
     old_written_bytes: u64,
@@ -124,7 +142,53 @@ pub(crate) struct ProcessInner {
     proc_path: PathBuf,
 }
 
+// Implemented manually because the opened `stat` file cannot be shared: the clone will open
+// its own if it ever needs it.
+impl Clone for ProcessInner {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            cmd: self.cmd.clone(),
+            exe: self.exe.clone(),
+            pid: self.pid,
+            parent: self.parent,
+            environ: self.environ.clone(),
+            cwd: self.cwd.clone(),
+            root: self.root.clone(),
+            memory: self.memory,
+            virtual_memory: self.virtual_memory,
+            swapped_memory: self.swapped_memory,
+            utime: self.utime,
+            stime: self.stime,
+            old_utime: self.old_utime,
+            old_stime: self.old_stime,
+            accumulated_cpu_time: self.accumulated_cpu_time,
+            page_faults: self.page_faults,
+            start_time_without_boot_time: self.start_time_without_boot_time,
+            start_time: self.start_time,
+            run_time: self.run_time,
+            updated: self.updated,
+            cpu_usage: self.cpu_usage,
+            user_id: self.user_id.clone(),
+            effective_user_id: self.effective_user_id.clone(),
+            group_id: self.group_id,
+            effective_group_id: self.effective_group_id,
+            status: self.status,
+            tasks: self.tasks.clone(),
+            #[cfg(feature = "processes")]
+            stat_file: None,
+            old_read_bytes: self.old_read_bytes,
+            old_written_bytes: self.old_written_bytes,
+            read_bytes: self.read_bytes,
+            written_bytes: self.written_bytes,
+            thread_kind: self.thread_kind,
+            proc_path: self.proc_path.clone(),
+        }
+    }
+}
+
 impl ProcessInner {
+    #[cfg(feature = "processes")]
     pub(crate) fn new(pid: Pid, proc_path: PathBuf) -> Self {
         Self {
             name: OsString::new(),
@@ -137,11 +201,14 @@ impl ProcessInner {
             root: None,
             memory: 0,
             virtual_memory: 0,
+            swapped_memory: 0,
             cpu_usage: 0.,
             utime: 0,
             stime: 0,
             old_utime: 0,
             old_stime: 0,
+            accumulated_cpu_time: 0,
+            page_faults: None,
             updated: true,
             start_time_without_boot_time: 0,
             start_time: 0,
@@ -219,6 +286,14 @@ impl ProcessInner {
         self.run_time
     }
 
+    pub(crate) fn accumulated_cpu_time(&self) -> u64 {
+        self.accumulated_cpu_time
+    }
+
+    pub(crate) fn page_faults(&self) -> Option<PageFaults> {
+        self.page_faults
+    }
+
     pub(crate) fn cpu_usage(&self) -> f32 {
         self.cpu_usage
     }
@@ -273,11 +348,24 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn is_being_traced(&self) -> Option<bool> {
+        let data = get_all_utf8_data(Path::join(&self.proc_path, "status"), 16_384).ok()?;
+        data.lines()
+            .find_map(|line| line.strip_prefix("TracerPid:"))
+            .and_then(|tracer| tracer.trim().parse::<u32>().ok())
+            .map(|tracer| tracer != 0)
+    }
+
+    pub(crate) fn swapped_memory(&self) -> u64 {
+        self.swapped_memory
+    }
+
     pub(crate) fn thread_kind(&self) -> Option<ThreadKind> {
         self.thread_kind
     }
 }
 
+#[cfg(feature = "processes")]
 pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, total_time: f32, max_value: f32) {
     // First time updating the values without reference, wait for a second cycle to update cpu_usage
     if p.old_utime == 0 && p.old_stime == 0 {
@@ -295,10 +383,12 @@ pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, total_time: f32, max_value
         .min(max_value);
 }
 
+#[cfg(feature = "processes")]
 pub(crate) fn unset_updated(p: &mut ProcessInner) {
     p.updated = false;
 }
 
+#[cfg(feature = "processes")]
 pub(crate) fn set_time(p: &mut ProcessInner, utime: u64, stime: u64) {
     p.old_utime = p.utime;
     p.old_stime = p.stime;
@@ -307,6 +397,7 @@ pub(crate) fn set_time(p: &mut ProcessInner, utime: u64, stime: u64) {
     p.updated = true;
 }
 
+#[cfg(feature = "processes")]
 pub(crate) fn update_process_disk_activity(p: &mut ProcessInner, path: &mut PathHandler) {
     let data = match get_all_utf8_data(path.join("io"), 16_384) {
         Ok(d) => d,
@@ -340,25 +431,31 @@ pub(crate) fn update_process_disk_activity(p: &mut ProcessInner, path: &mut Path
     }
 }
 
+#[cfg(feature = "processes")]
 struct Wrap<'a, T>(UnsafeCell<&'a mut T>);
 
+#[cfg(feature = "processes")]
 impl<'a, T> Wrap<'a, T> {
     fn get(&self) -> &'a mut T {
         unsafe { *(self.0.get()) }
     }
 }
 
+#[cfg(feature = "processes")]
 #[allow(clippy::non_send_fields_in_send_ty)]
 unsafe impl<'a, T> Send for Wrap<'a, T> {}
+#[cfg(feature = "processes")]
 unsafe impl<'a, T> Sync for Wrap<'a, T> {}
 
 #[inline(always)]
+#[cfg(feature = "processes")]
 fn compute_start_time_without_boot_time(parts: &Parts<'_>, info: &SystemInfo) -> u64 {
     // To be noted that the start time is invalid here, it still needs to be converted into
     // "real" time.
     u64::from_str(parts.str_parts[ProcIndex::StartTime as usize]).unwrap_or(0) / info.clock_cycle
 }
 
+#[cfg(feature = "processes")]
 fn _get_stat_data(path: &Path, stat_file: &mut Option<FileCounter>) -> Result<Vec<u8>, ()> {
     let mut file = File::open(path.join("stat")).map_err(|_| ())?;
     let data = get_all_data_from_file(&mut file, 1024).map_err(|_| ())?;
@@ -367,6 +464,7 @@ fn _get_stat_data(path: &Path, stat_file: &mut Option<FileCounter>) -> Result<Ve
 }
 
 #[inline(always)]
+#[cfg(feature = "processes")]
 fn get_status(p: &mut ProcessInner, part: &str) {
     p.status = part
         .chars()
@@ -375,6 +473,7 @@ fn get_status(p: &mut ProcessInner, part: &str) {
         .unwrap_or_else(|| ProcessStatus::Unknown(0));
 }
 
+#[cfg(feature = "processes")]
 fn refresh_user_group_ids(
     p: &mut ProcessInner,
     path: &mut PathHandler,
@@ -395,6 +494,7 @@ fn refresh_user_group_ids(
 }
 
 #[allow(clippy::too_many_arguments)]
+#[cfg(feature = "processes")]
 fn update_proc_info(
     p: &mut ProcessInner,
     parent_pid: Option<Pid>,
@@ -434,6 +534,7 @@ fn update_proc_info(
     }
 }
 
+#[cfg(feature = "processes")]
 fn update_parent_pid(p: &mut ProcessInner, parent_pid: Option<Pid>, str_parts: &[&str]) {
     p.parent = match parent_pid {
         Some(parent_pid) if parent_pid.0 != 0 => Some(parent_pid),
@@ -444,6 +545,7 @@ fn update_parent_pid(p: &mut ProcessInner, parent_pid: Option<Pid>, str_parts: &
     };
 }
 
+#[cfg(feature = "processes")]
 fn retrieve_all_new_process_info(
     pid: Pid,
     parent_pid: Option<Pid>,
@@ -482,9 +584,10 @@ fn retrieve_all_new_process_info(
         info,
     );
 
-    Process { inner: p }
+    Process::new(p)
 }
 
+#[cfg(feature = "processes")]
 pub(crate) fn _get_process_data(
     path: &Path,
     proc_list: &mut HashMap<Pid, Process>,
@@ -567,6 +670,7 @@ pub(crate) fn _get_process_data(
     Ok((None, pid))
 }
 
+#[cfg(feature = "processes")]
 fn old_get_memory(entry: &mut ProcessInner, str_parts: &[&str], info: &SystemInfo) {
     // rss
     entry.memory = u64::from_str(str_parts[ProcIndex::ResidentSetSize as usize])
@@ -577,6 +681,7 @@ fn old_get_memory(entry: &mut ProcessInner, str_parts: &[&str], info: &SystemInf
     entry.virtual_memory = u64::from_str(str_parts[ProcIndex::VirtualSize as usize]).unwrap_or(0);
 }
 
+#[cfg(feature = "processes")]
 fn slice_to_nb(s: &[u8]) -> u64 {
     let mut nb: u64 = 0;
 
@@ -586,6 +691,7 @@ fn slice_to_nb(s: &[u8]) -> u64 {
     nb
 }
 
+#[cfg(feature = "processes")]
 fn get_memory(path: &Path, entry: &mut ProcessInner, info: &SystemInfo) -> bool {
     let mut file = match File::open(path) {
         Ok(f) => f,
@@ -619,7 +725,21 @@ fn get_memory(path: &Path, entry: &mut ProcessInner, info: &SystemInfo) -> bool
     true
 }
 
+/// Returns the `VmSwap` value (in bytes) of the given `status` file.
+#[cfg(feature = "processes")]
+fn get_swapped_memory(path: &Path) -> u64 {
+    let Ok(data) = get_all_utf8_data(path, 16_384) else {
+        return 0;
+    };
+    data.lines()
+        .find_map(|line| line.strip_prefix("VmSwap:"))
+        .and_then(|swap| swap.trim().strip_suffix("kB"))
+        .and_then(|swap| swap.trim().parse::<u64>().ok())
+        .map_or(0, |swap| swap.saturating_mul(1_024))
+}
+
 #[allow(clippy::too_many_arguments)]
+#[cfg(feature = "processes")]
 fn update_time_and_memory(
     path: &mut PathHandler,
     entry: &mut ProcessInner,
@@ -635,16 +755,34 @@ fn update_time_and_memory(
             if !get_memory(path.join("statm"), entry, info) {
                 old_get_memory(entry, str_parts, info);
             }
+            entry.page_faults = Some(PageFaults::new(
+                entry.page_faults,
+                u64::from_str(str_parts[ProcIndex::MinorFaults as usize]).unwrap_or(0),
+                u64::from_str(str_parts[ProcIndex::MajorFaults as usize]).unwrap_or(0),
+            ));
+        }
+        // It requires to read an extra file, so it's only done if explicitly asked.
+        if refresh_kind.memory_regions() {
+            entry.swapped_memory = get_swapped_memory(path.join("status"));
         }
         set_time(
             entry,
             u64::from_str(str_parts[ProcIndex::UserTime as usize]).unwrap_or(0),
             u64::from_str(str_parts[ProcIndex::SystemTime as usize]).unwrap_or(0),
         );
+        if refresh_kind.cpu() {
+            // The times are in clock ticks.
+            entry.accumulated_cpu_time = entry
+                .utime
+                .saturating_add(entry.stime)
+                .saturating_mul(1_000)
+                / info.clock_cycle.max(1);
+        }
         entry.run_time = uptime.saturating_sub(entry.start_time_without_boot_time);
     }
 }
 
+#[cfg(feature = "processes")]
 struct ProcAndTasks {
     pid: Pid,
     parent_pid: Option<Pid>,
@@ -652,6 +790,7 @@ struct ProcAndTasks {
     tasks: Option<HashSet<Pid>>,
 }
 
+#[cfg(feature = "processes")]
 fn get_all_pid_entries(
     parent: Option<&OsStr>,
     parent_pid: Option<Pid>,
@@ -699,7 +838,7 @@ fn get_all_pid_entries(
     Some(pid)
 }
 
-#[cfg(feature = "multithread")]
+#[cfg(all(feature = "multithread", feature = "processes"))]
 #[inline]
 pub(crate) fn iter<T>(val: T) -> rayon::iter::IterBridge<T>
 where
@@ -708,7 +847,7 @@ where
     val.par_bridge()
 }
 
-#[cfg(not(feature = "multithread"))]
+#[cfg(all(not(feature = "multithread"), feature = "processes"))]
 #[inline]
 pub(crate) fn iter<T>(val: T) -> T
 where
@@ -717,12 +856,14 @@ where
     val
 }
 
+#[cfg(feature = "processes")]
 pub(crate) fn refresh_procs(
     proc_list: &mut HashMap<Pid, Process>,
     path: &Path,
     uptime: u64,
     info: &SystemInfo,
     filter: Option<&[Pid]>,
+    uid_filter: Option<&Uid>,
     refresh_kind: ProcessRefreshKind,
 ) -> bool {
     #[cfg(feature = "multithread")]
@@ -766,6 +907,14 @@ pub(crate) fn refresh_procs(
             })
             .flatten()
             .filter(|e| filter_callback(e, filter))
+            .filter(|e| {
+                // Only the `status` file is read to check the real user ID, which is much cheaper
+                // than reading all the process files, so we do it first.
+                uid_filter.map_or(true, |uid| {
+                    get_uid_and_gid(&Path::join(&e.path, "status"))
+                        .is_some_and(|((real_uid, _), _)| real_uid == uid.0)
+                })
+            })
             .filter_map(|e| {
                 let (mut p, _) = _get_process_data(
                     e.path.as_path(),
@@ -790,6 +939,7 @@ pub(crate) fn refresh_procs(
     true
 }
 
+#[cfg(feature = "processes")]
 fn copy_from_file(entry: &Path) -> Vec<OsString> {
     match File::open(entry) {
         Ok(mut f) => {
@@ -819,6 +969,7 @@ fn copy_from_file(entry: &Path) -> Vec<OsString> {
 }
 
 // Fetch tuples of real and effective UID and GID.
+#[cfg(feature = "processes")]
 fn get_uid_and_gid(file_path: &Path) -> Option<((uid_t, uid_t), (gid_t, gid_t))> {
     let status_data = get_all_utf8_data(file_path, 16_385).ok()?;
 
@@ -865,11 +1016,13 @@ fn get_uid_and_gid(file_path: &Path) -> Option<((uid_t, uid_t), (gid_t, gid_t))>
     }
 }
 
+#[cfg(feature = "processes")]
 struct Parts<'a> {
     str_parts: Vec<&'a str>,
     short_exe: &'a [u8],
 }
 
+#[cfg(feature = "processes")]
 fn parse_stat_file(data: &[u8]) -> Option<Parts<'_>> {
     // The stat file is "interesting" to parse, because spaces cannot
     // be used as delimiters. The second field stores the command name
@@ -1,17 +1,24 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::sys::cpu::{get_physical_core_count, CpusWrapper};
+use crate::sys::cpu::{get_cache_size, get_physical_core_count, CpusWrapper};
+#[cfg(feature = "processes")]
 use crate::sys::process::{_get_process_data, compute_cpu_usage, refresh_procs, unset_updated};
 use crate::sys::utils::{get_all_utf8_data, to_u64};
-use crate::{Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessRefreshKind};
-
-use libc::{self, c_char, sysconf, _SC_CLK_TCK, _SC_HOST_NAME_MAX, _SC_PAGESIZE};
+#[cfg(feature = "processes")]
+use crate::utils::take_exited_processes;
+use crate::{Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, PowerSource, Process, SwapFile};
+#[cfg(feature = "processes")]
+use crate::{ProcessRefreshKind, Uid};
+
+#[cfg(feature = "processes")]
+use libc::_SC_CLK_TCK;
+use libc::{self, c_char, sysconf, _SC_HOST_NAME_MAX, _SC_PAGESIZE};
 use std::cmp::min;
 use std::collections::HashMap;
 use std::ffi::CStr;
 use std::fs::File;
 use std::io::Read;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::{atomic::AtomicIsize, OnceLock};
 
@@ -89,7 +96,9 @@ fn boot_time() -> u64 {
 
 pub(crate) struct SystemInfo {
     pub(crate) page_size_b: u64,
+    #[cfg(feature = "processes")]
     pub(crate) clock_cycle: u64,
+    #[cfg(feature = "processes")]
     pub(crate) boot_time: u64,
 }
 
@@ -98,7 +107,9 @@ impl SystemInfo {
         unsafe {
             Self {
                 page_size_b: sysconf(_SC_PAGESIZE) as _,
+                #[cfg(feature = "processes")]
                 clock_cycle: sysconf(_SC_CLK_TCK) as _,
+                #[cfg(feature = "processes")]
                 boot_time: boot_time(),
             }
         }
@@ -126,11 +137,13 @@ impl SystemInner {
     ///
     /// To prevent that, we compute ahead of time this maximum value and ensure that processes'
     /// CPU usage don't go over it.
+    #[cfg(feature = "processes")]
     fn get_max_process_cpu_usage(&self) -> f32 {
         self.cpus.len() as f32 * 100.
     }
 
-    fn clear_procs(&mut self, refresh_kind: ProcessRefreshKind) {
+    #[cfg(feature = "processes")]
+    fn clear_procs(&mut self, refresh_kind: ProcessRefreshKind, exited: Option<&mut Vec<Process>>) {
         let (total_time, compute_cpu, max_value) = if refresh_kind.cpu() {
             self.cpus
                 .refresh_if_needed(true, CpuRefreshKind::new().with_cpu_usage());
@@ -151,6 +164,9 @@ impl SystemInner {
             (0., false, 0.)
         };
 
+        if let Some(exited) = exited {
+            take_exited_processes(&mut self.process_list, exited, |p| !p.inner.updated);
+        }
         self.process_list.retain(|_, proc_| {
             let proc_ = &mut proc_.inner;
             if !proc_.updated {
@@ -233,10 +249,17 @@ impl SystemInner {
         self.refresh_cpus(false, refresh_kind);
     }
 
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {
+        self.refresh_cpus(true, CpuRefreshKind::new().with_cpu_usage());
+    }
+
+    #[cfg(feature = "processes")]
     pub(crate) fn refresh_processes_specifics(
         &mut self,
         filter: Option<&[Pid]>,
+        uid_filter: Option<&Uid>,
         refresh_kind: ProcessRefreshKind,
+        exited: Option<&mut Vec<Process>>,
     ) {
         let uptime = Self::uptime();
         refresh_procs(
@@ -245,12 +268,14 @@ impl SystemInner {
             uptime,
             &self.info,
             filter,
+            uid_filter,
             refresh_kind,
         );
-        self.clear_procs(refresh_kind);
+        self.clear_procs(refresh_kind, exited);
         self.cpus.set_need_cpus_update();
     }
 
+    #[cfg(feature = "processes")]
     pub(crate) fn refresh_process_specifics(
         &mut self,
         pid: Pid,
@@ -306,6 +331,26 @@ impl SystemInner {
         &self.process_list
     }
 
+    #[cfg(feature = "processes")]
+    pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
+        &mut self.process_list
+    }
+
+    #[cfg(feature = "processes")]
+    pub(crate) fn list_pids(&self) -> Vec<Pid> {
+        std::fs::read_dir("/proc")
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn remove_process(&mut self, pid: Pid) -> Option<Process> {
+        self.process_list.remove(&pid)
+    }
+
     pub(crate) fn process(&self, pid: Pid) -> Option<&Process> {
         self.process_list.get(&pid)
     }
@@ -318,14 +363,47 @@ impl SystemInner {
         &self.cpus.cpus
     }
 
+    pub(crate) fn set_error_callback(&mut self, _callback: crate::utils::ErrorCallback) {}
+
+    pub(crate) fn set_cpu_smoothing(&mut self, alpha: f32) {
+        self.cpus.cpu_smoothing = alpha;
+    }
+
+    pub(crate) fn cpu_smoothing(&self) -> f32 {
+        self.cpus.cpu_smoothing
+    }
+
+    pub(crate) fn logical_cpu_count(&self) -> Option<usize> {
+        match unsafe { libc::sysconf(libc::_SC_NPROCESSORS_ONLN) } {
+            count if count > 0 => Some(count as _),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn online_cpu_count(&self) -> Option<usize> {
+        // `logical_cpu_count` also only counts the online CPUs, so they're always the same.
+        match unsafe { libc::sysconf(libc::_SC_NPROCESSORS_ONLN) } {
+            count if count > 0 => Some(count as _),
+            _ => None,
+        }
+    }
+
     pub(crate) fn physical_core_count(&self) -> Option<usize> {
         get_physical_core_count()
     }
 
+    pub(crate) fn cache_size(&self, level: u8) -> Option<u64> {
+        get_cache_size(level)
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
 
+    pub(crate) fn page_size(&self) -> u64 {
+        self.info.page_size_b
+    }
+
     pub(crate) fn free_memory(&self) -> u64 {
         self.mem_free
     }
@@ -351,6 +429,12 @@ impl SystemInner {
         self.swap_total - self.swap_free
     }
 
+    pub(crate) fn swap_files(&self) -> Vec<SwapFile> {
+        get_all_utf8_data("/proc/swaps", 1_024)
+            .map(|content| parse_swaps(&content))
+            .unwrap_or_default()
+    }
+
     pub(crate) fn uptime() -> u64 {
         let content = get_all_utf8_data("/proc/uptime", 50).unwrap_or_default();
         content
@@ -360,6 +444,21 @@ impl SystemInner {
             .unwrap_or_default()
     }
 
+    pub(crate) fn idle_time(&self) -> Option<u64> {
+        // The second value is the sum of the idle time of each CPU.
+        let content = get_all_utf8_data("/proc/uptime", 50).ok()?;
+        content
+            .split_whitespace()
+            .nth(1)?
+            .split('.')
+            .next()
+            .and_then(|t| t.parse().ok())
+    }
+
+    pub(crate) fn power_source() -> PowerSource {
+        get_power_source(Path::new("/sys/class/power_supply"))
+    }
+
     pub(crate) fn boot_time() -> u64 {
         boot_time()
     }
@@ -431,6 +530,17 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn hardware_model() -> Option<String> {
+        // Provided by the firmware (SMBIOS) on x86, and by the device tree on ARM boards.
+        ["/sys/class/dmi/id/product_name", "/proc/device-tree/model"]
+            .iter()
+            .find_map(|path| {
+                let model = get_all_utf8_data(path, 256).ok()?;
+                let model = model.trim_matches(|c: char| c.is_whitespace() || c == '\0');
+                (!model.is_empty()).then(|| model.to_owned())
+            })
+    }
+
     pub(crate) fn kernel_version() -> Option<String> {
         let mut raw = std::mem::MaybeUninit::<libc::utsname>::zeroed();
 
@@ -674,13 +784,72 @@ fn get_system_info_android(info: InfoType) -> Option<String> {
     }
 }
 
+fn get_power_source(power_supply_dir: &Path) -> PowerSource {
+    let Ok(dir) = std::fs::read_dir(power_supply_dir) else {
+        // No power supply information, very likely a desktop.
+        return PowerSource::Ac;
+    };
+    let mut has_battery = false;
+    let mut is_mains_online = false;
+    for entry in dir.flatten() {
+        let path = entry.path();
+        let read = |name: &str| get_all_utf8_data(path.join(name), 32).unwrap_or_default();
+        let is_discharging = || read("status").trim() == "Discharging";
+        match read("type").trim() {
+            "Mains" => is_mains_online |= read("online").trim() == "1",
+            "UPS" if is_discharging() => return PowerSource::Ups,
+            "Battery" => {
+                // Batteries of peripherals (like mice) don't power the system.
+                if read("scope").trim() == "Device" {
+                    continue;
+                }
+                if is_discharging() {
+                    return PowerSource::Battery;
+                }
+                has_battery = true;
+            }
+            _ => {}
+        }
+    }
+    if is_mains_online || !has_battery {
+        PowerSource::Ac
+    } else {
+        PowerSource::Unknown
+    }
+}
+
+/// Parses the content of `/proc/swaps`. The first line contains the columns names:
+///
+/// ```text
+/// Filename        Type        Size        Used    Priority
+/// /swapfile       file        2097148     0       -2
+/// ```
+fn parse_swaps(content: &str) -> Vec<SwapFile> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let path = fields.next()?;
+            // The size is in KiB.
+            let size = fields.nth(1)?.parse::<u64>().ok()?;
+            Some(SwapFile {
+                path: PathBuf::from(path),
+                size: size.saturating_mul(1_024),
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     #[cfg(target_os = "android")]
     use super::get_system_info_android;
     #[cfg(not(target_os = "android"))]
     use super::get_system_info_linux;
-    use super::InfoType;
+    use super::{get_power_source, parse_swaps, InfoType};
+    use crate::{PowerSource, SwapFile};
+    use std::path::PathBuf;
 
     #[test]
     #[cfg(target_os = "android")]
@@ -753,4 +922,60 @@ DISTRIB_DESCRIPTION="Ubuntu 20.10"
             None
         );
     }
+
+    #[test]
+    fn check_parse_swaps() {
+        assert!(parse_swaps("Filename\tType\tSize\tUsed\tPriority\n").is_empty());
+        assert_eq!(
+            parse_swaps(
+                "Filename\tType\tSize\tUsed\tPriority
+/dev/sda2                               partition\t1003516\t\t0\t\t-2
+/swapfile                               file\t\t2097148\t\t512\t\t-3
+"
+            ),
+            vec![
+                SwapFile {
+                    path: PathBuf::from("/dev/sda2"),
+                    size: 1_027_600_384,
+                },
+                SwapFile {
+                    path: PathBuf::from("/swapfile"),
+                    size: 2_147_479_552,
+                },
+            ],
+        );
+    }
+
+    #[test]
+    #[cfg(not(target_os = "android"))]
+    fn check_power_source() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let add_supply = |name: &str, files: &[(&str, &str)]| {
+            let supply = dir.path().join(name);
+            std::fs::create_dir(&supply).expect("failed to create power supply directory");
+            for (file, content) in files {
+                std::fs::write(supply.join(file), content).expect("failed to write file");
+            }
+        };
+
+        // No power supply at all.
+        assert_eq!(get_power_source(&dir.path().join("none")), PowerSource::Ac);
+
+        add_supply(
+            "hidpp_battery_0",
+            &[("type", "Battery\n"), ("scope", "Device\n")],
+        );
+        assert_eq!(get_power_source(dir.path()), PowerSource::Ac);
+
+        add_supply(
+            "BAT0",
+            &[("type", "Battery\n"), ("status", "Discharging\n")],
+        );
+        add_supply("AC", &[("type", "Mains\n"), ("online", "0\n")]);
+        assert_eq!(get_power_source(dir.path()), PowerSource::Battery);
+
+        std::fs::write(dir.path().join("BAT0/status"), "Charging\n").unwrap();
+        std::fs::write(dir.path().join("AC/online"), "1\n").unwrap();
+        assert_eq!(get_power_source(dir.path()), PowerSource::Ac);
+    }
 }
@@ -2,11 +2,16 @@
 
 use std::fs::File;
 use std::io::{self, Read, Seek};
-use std::path::{Path, PathBuf};
+use std::path::Path;
+#[cfg(feature = "processes")]
+use std::path::PathBuf;
+#[cfg(feature = "processes")]
 use std::sync::atomic::Ordering;
 
+#[cfg(feature = "processes")]
 use crate::sys::system::remaining_files;
 
+#[cfg(feature = "processes")]
 pub(crate) fn get_all_data_from_file(file: &mut File, size: usize) -> io::Result<Vec<u8>> {
     let mut buf = Vec::with_capacity(size);
     file.rewind()?;
@@ -27,6 +32,7 @@ pub(crate) fn get_all_utf8_data<P: AsRef<Path>>(file_path: P, size: usize) -> io
 }
 
 #[allow(clippy::useless_conversion)]
+#[cfg(feature = "processes")]
 pub(crate) fn realpath(path: &Path) -> Option<std::path::PathBuf> {
     match std::fs::read_link(path) {
         Ok(path) => Some(path),
@@ -38,8 +44,10 @@ pub(crate) fn realpath(path: &Path) -> Option<std::path::PathBuf> {
 }
 
 /// Type used to correctly handle the `REMAINING_FILES` global.
+#[cfg(feature = "processes")]
 pub(crate) struct FileCounter(File);
 
+#[cfg(feature = "processes")]
 impl FileCounter {
     pub(crate) fn new(f: File) -> Option<Self> {
         let any_remaining =
@@ -56,6 +64,7 @@ impl FileCounter {
     }
 }
 
+#[cfg(feature = "processes")]
 impl std::ops::Deref for FileCounter {
     type Target = File;
 
@@ -63,12 +72,14 @@ impl std::ops::Deref for FileCounter {
         &self.0
     }
 }
+#[cfg(feature = "processes")]
 impl std::ops::DerefMut for FileCounter {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
 }
 
+#[cfg(feature = "processes")]
 impl Drop for FileCounter {
     fn drop(&mut self) {
         remaining_files().fetch_add(1, Ordering::Relaxed);
@@ -77,8 +88,10 @@ impl Drop for FileCounter {
 
 /// This type is used in `retrieve_all_new_process_info` because we have a "parent" path and
 /// from it, we `pop`/`join` every time because it's more memory efficient than using `Path::join`.
+#[cfg(feature = "processes")]
 pub(crate) struct PathHandler(PathBuf);
 
+#[cfg(feature = "processes")]
 impl PathHandler {
     pub(crate) fn new(path: &Path) -> Self {
         // `path` is the "parent" for all paths which will follow so we add a fake element at
@@ -87,10 +100,12 @@ impl PathHandler {
     }
 }
 
+#[cfg(feature = "processes")]
 pub(crate) trait PathPush {
     fn join(&mut self, p: &str) -> &Path;
 }
 
+#[cfg(feature = "processes")]
 impl PathPush for PathHandler {
     fn join(&mut self, p: &str) -> &Path {
         self.0.pop();
@@ -100,6 +115,7 @@ impl PathPush for PathHandler {
 }
 
 // This implementation allows to skip one allocation that is done in `PathHandler`.
+#[cfg(feature = "processes")]
 impl PathPush for PathBuf {
     fn join(&mut self, p: &str) -> &Path {
         self.push(p);
@@ -31,11 +31,15 @@ pub(crate) mod utils;
 
 pub(crate) struct DisksInner {
     pub(crate) disks: Vec<crate::Disk>,
+    pub(crate) list_timeout_runner: crate::utils::TimeoutRunner<Vec<crate::Disk>>,
 }
 
 impl DisksInner {
     pub(crate) fn from_vec(disks: Vec<crate::Disk>) -> Self {
-        Self { disks }
+        Self {
+            disks,
+            list_timeout_runner: Default::default(),
+        }
     }
 
     pub(crate) fn into_vec(self) -> Vec<crate::Disk> {
@@ -12,6 +12,7 @@ use std::str::from_utf8_unchecked;
 use std::{io, mem};
 
 use crate::common::{IpNetwork, MacAddr};
+use crate::network::InterfaceFlags;
 
 /// This iterator yields an interface name and address.
 pub(crate) struct InterfaceAddressIterator {
@@ -173,6 +174,227 @@ pub(crate) unsafe fn get_interface_ip_networks() -> HashMap<String, HashSet<IpNe
     ifaces
 }
 
+pub(crate) unsafe fn get_interface_flags() -> HashMap<String, InterfaceFlags> {
+    let mut ifaces = HashMap::new();
+    let mut addrs = null_mut();
+
+    if retry_eintr!(libc::getifaddrs(&mut addrs)) != 0 || addrs.is_null() {
+        sysinfo_debug!("Failed to call libc::getifaddrs");
+        return ifaces;
+    }
+
+    let mut addr = addrs;
+    while !addr.is_null() {
+        // Safety: We assume that addr is valid for the lifetime of this loop
+        // body, and is not mutated.
+        let addr_ref: &libc::ifaddrs = &*addr;
+        addr = addr_ref.ifa_next;
+
+        if addr_ref.ifa_name.is_null() {
+            continue;
+        }
+        // Safety: ifa_name is a null terminated interface name
+        let name = CStr::from_ptr(addr_ref.ifa_name)
+            .to_string_lossy()
+            .into_owned();
+        let flags = addr_ref.ifa_flags as libc::c_int;
+        // An interface is listed once per address, but the flags are the same for all of them.
+        ifaces.entry(name).or_insert(InterfaceFlags {
+            is_up: flags & libc::IFF_UP != 0,
+            is_loopback: flags & libc::IFF_LOOPBACK != 0,
+            is_running: flags & libc::IFF_RUNNING != 0,
+        });
+    }
+
+    // Safety: addrs has been previously allocated through getifaddrs
+    libc::freeifaddrs(addrs);
+    ifaces
+}
+
+#[cfg(target_os = "freebsd")]
+#[allow(dead_code, non_camel_case_types)]
+mod route {
+    // These aren't provided by the `libc` crate on FreeBSD.
+    pub(super) const RTF_UP: libc::c_int = 0x1;
+    pub(super) const RTF_GATEWAY: libc::c_int = 0x2;
+    pub(super) const RTF_HOST: libc::c_int = 0x4;
+    pub(super) const RTA_DST: libc::c_int = 0x1;
+    pub(super) const RTA_GATEWAY: libc::c_int = 0x2;
+    pub(super) const RTA_NETMASK: libc::c_int = 0x4;
+
+    #[repr(C)]
+    pub(super) struct rt_metrics {
+        pub(super) rmx_locks: libc::c_ulong,
+        pub(super) rmx_mtu: libc::c_ulong,
+        pub(super) rmx_hopcount: libc::c_ulong,
+        pub(super) rmx_expire: libc::c_ulong,
+        pub(super) rmx_recvpipe: libc::c_ulong,
+        pub(super) rmx_sendpipe: libc::c_ulong,
+        pub(super) rmx_ssthresh: libc::c_ulong,
+        pub(super) rmx_rtt: libc::c_ulong,
+        pub(super) rmx_rttvar: libc::c_ulong,
+        pub(super) rmx_pksent: libc::c_ulong,
+        pub(super) rmx_weight: libc::c_ulong,
+        pub(super) rmx_nhidx: libc::c_ulong,
+        pub(super) rmx_filler: [libc::c_ulong; 2],
+    }
+
+    #[repr(C)]
+    pub(super) struct rt_msghdr {
+        pub(super) rtm_msglen: libc::c_ushort,
+        pub(super) rtm_version: libc::c_uchar,
+        pub(super) rtm_type: libc::c_uchar,
+        pub(super) rtm_index: libc::c_ushort,
+        pub(super) _rtm_spare1: libc::c_ushort,
+        pub(super) rtm_flags: libc::c_int,
+        pub(super) rtm_addrs: libc::c_int,
+        pub(super) rtm_pid: libc::pid_t,
+        pub(super) rtm_seq: libc::c_int,
+        pub(super) rtm_errno: libc::c_int,
+        pub(super) rtm_fmask: libc::c_int,
+        pub(super) rtm_inits: libc::c_ulong,
+        pub(super) rtm_rmx: rt_metrics,
+    }
+
+    /// The addresses following the header are aligned on `long`.
+    pub(super) const SA_ALIGN: usize = std::mem::size_of::<libc::c_long>();
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+mod route {
+    pub(super) use libc::{
+        rt_msghdr, RTA_DST, RTA_GATEWAY, RTA_NETMASK, RTF_GATEWAY, RTF_HOST, RTF_IFSCOPE, RTF_UP,
+    };
+
+    /// The addresses following the header are aligned on `u32`.
+    pub(super) const SA_ALIGN: usize = std::mem::size_of::<u32>();
+}
+
+/// Returns the name of the interface used by the default route, read from the routing table.
+///
+/// The IPv4 default route is preferred over the IPv6 one.
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+pub(crate) fn get_default_interface() -> Option<String> {
+    use route::*;
+
+    let mut mib = [libc::CTL_NET, libc::PF_ROUTE, 0, 0, libc::NET_RT_DUMP, 0];
+    let mut len = 0;
+    let mut buf: Vec<u8> = Vec::new();
+    unsafe {
+        // The routing table can grow between the two calls, so we retry if needed.
+        loop {
+            if libc::sysctl(
+                mib.as_mut_ptr(),
+                mib.len() as _,
+                null_mut(),
+                &mut len,
+                null_mut(),
+                0,
+            ) < 0
+            {
+                sysinfo_debug!("sysctl NET_RT_DUMP failed");
+                return None;
+            }
+            buf.resize(len, 0);
+            if libc::sysctl(
+                mib.as_mut_ptr(),
+                mib.len() as _,
+                buf.as_mut_ptr() as _,
+                &mut len,
+                null_mut(),
+                0,
+            ) == 0
+            {
+                buf.truncate(len);
+                break;
+            }
+            if io::Error::last_os_error().raw_os_error() != Some(libc::ENOMEM) {
+                sysinfo_debug!("sysctl NET_RT_DUMP failed");
+                return None;
+            }
+        }
+    }
+
+    let header_len = mem::size_of::<rt_msghdr>();
+    let mut ipv6_index = None;
+    let mut pos = 0;
+    while pos + header_len <= buf.len() {
+        // SAFETY: the buffer is big enough for the header, which is read unaligned.
+        let header = unsafe { (buf.as_ptr().add(pos) as *const rt_msghdr).read_unaligned() };
+        let msg_len = header.rtm_msglen as usize;
+        if msg_len == 0 || pos + msg_len > buf.len() {
+            break;
+        }
+        let msg = &buf[pos..pos + msg_len];
+        pos += msg_len;
+
+        if header.rtm_flags & (RTF_UP | RTF_GATEWAY) != (RTF_UP | RTF_GATEWAY)
+            || header.rtm_flags & RTF_HOST != 0
+            || header.rtm_addrs & RTA_DST == 0
+        {
+            continue;
+        }
+        // Scoped routes are only used by the sockets bound to their interface, so they aren't
+        // the system default route.
+        #[cfg(any(target_os = "macos", target_os = "ios"))]
+        if header.rtm_flags & RTF_IFSCOPE != 0 {
+            continue;
+        }
+        let mut addrs = &msg[header_len..];
+        let mut destination: &[u8] = &[];
+        let mut netmask: &[u8] = &[];
+        for addr in [RTA_DST, RTA_GATEWAY, RTA_NETMASK] {
+            if header.rtm_addrs & addr == 0 {
+                continue;
+            }
+            let Some(&sa_len) = addrs.first() else {
+                break;
+            };
+            let sa_len = sa_len as usize;
+            if addr == RTA_DST {
+                destination = &addrs[..sa_len.min(addrs.len())];
+            } else if addr == RTA_NETMASK {
+                netmask = &addrs[..sa_len.min(addrs.len())];
+            }
+            let padded_len = if sa_len == 0 {
+                SA_ALIGN
+            } else {
+                (sa_len + SA_ALIGN - 1) & !(SA_ALIGN - 1)
+            };
+            addrs = &addrs[padded_len.min(addrs.len())..];
+        }
+        // The address starts after the `sa_len`, `sa_family` and port fields for IPv4, and
+        // after the flow info field for IPv6.
+        let (is_ipv4, addr_offset) = match destination.get(1).map(|f| *f as libc::c_int) {
+            Some(libc::AF_INET) => (true, 4),
+            Some(libc::AF_INET6) => (false, 8),
+            _ => continue,
+        };
+        // The default route goes to the unspecified address with an empty netmask. The masks
+        // are truncated after their last non-zero byte.
+        let is_unspecified = |sa: &[u8]| sa.iter().skip(addr_offset).all(|b| *b == 0);
+        if !is_unspecified(destination) || !is_unspecified(netmask) {
+            continue;
+        }
+        if is_ipv4 {
+            return interface_name(header.rtm_index as _);
+        }
+        ipv6_index.get_or_insert(header.rtm_index);
+    }
+    interface_name(ipv6_index? as _)
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+fn interface_name(index: libc::c_uint) -> Option<String> {
+    let mut name = [0 as c_char; libc::IFNAMSIZ];
+    unsafe {
+        if libc::if_indextoname(index, name.as_mut_ptr()).is_null() {
+            return None;
+        }
+        Some(CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned())
+    }
+}
+
 #[cfg(any(target_os = "linux", target_os = "android"))]
 fn sockaddr_to_network_addr(sa: *const libc::sockaddr) -> Option<IpAddr> {
     unsafe {
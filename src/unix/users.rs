@@ -9,16 +9,27 @@ use crate::{
 use crate::User;
 
 use libc::{getgrgid_r, getgrouplist};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
 
 pub(crate) struct UserInner {
     pub(crate) uid: Uid,
     pub(crate) gid: Gid,
     pub(crate) name: String,
     c_user: Vec<u8>,
+    home_directory: Option<PathBuf>,
+    shell: Option<PathBuf>,
 }
 
 impl UserInner {
-    pub(crate) fn new(uid: Uid, gid: Gid, name: String) -> Self {
+    pub(crate) fn new(
+        uid: Uid,
+        gid: Gid,
+        name: String,
+        home_directory: Option<PathBuf>,
+        shell: Option<PathBuf>,
+    ) -> Self {
         let mut c_user = name.as_bytes().to_vec();
         c_user.push(0);
         Self {
@@ -26,6 +37,8 @@ impl UserInner {
             gid,
             name,
             c_user,
+            home_directory,
+            shell,
         }
     }
 
@@ -44,6 +57,14 @@ impl UserInner {
     pub(crate) fn groups(&self) -> Vec<Group> {
         unsafe { get_user_groups(self.c_user.as_ptr() as *const _, self.gid.0 as _) }
     }
+
+    pub(crate) fn home_directory(&self) -> Option<&Path> {
+        self.home_directory.as_deref()
+    }
+
+    pub(crate) fn shell(&self) -> Option<&Path> {
+        self.shell.as_deref()
+    }
 }
 
 pub(crate) unsafe fn get_group_name(
@@ -83,6 +104,20 @@ pub(crate) unsafe fn get_user_groups(
     group_id: libc::gid_t,
 ) -> Vec<Group> {
     let mut buffer = Vec::with_capacity(2048);
+    get_user_group_ids(name, group_id)
+        .into_iter()
+        .filter_map(|group_id| {
+            let name = get_group_name(group_id.0 as _, &mut buffer)?;
+            Some(Group {
+                inner: crate::GroupInner::new(group_id, name),
+            })
+        })
+        .collect()
+}
+
+/// Returns the IDs of the groups the user `name` belongs to, including its primary group
+/// `group_id`.
+unsafe fn get_user_group_ids(name: *const libc::c_char, group_id: libc::gid_t) -> Vec<Gid> {
     let mut groups = Vec::with_capacity(256);
 
     loop {
@@ -101,18 +136,60 @@ pub(crate) unsafe fn get_user_groups(
             continue;
         }
         groups.set_len(nb_groups as _);
-        return groups
-            .iter()
-            .filter_map(|group_id| {
-                let name = get_group_name(*group_id as _, &mut buffer)?;
-                Some(Group {
-                    inner: crate::GroupInner::new(Gid(*group_id as _), name),
-                })
-            })
-            .collect();
+        return groups.iter().map(|group_id| Gid(*group_id as _)).collect();
     }
 }
 
+/// Returns the IDs of the groups the user `uid` belongs to, or `None` if it has no passwd
+/// entry.
+///
+/// Since resolving them can be slow (it may query a directory service), they're cached per user.
+pub(crate) fn get_supplementary_groups(uid: &Uid) -> Option<Vec<Gid>> {
+    static CACHE: OnceLock<Mutex<HashMap<Uid, Option<Vec<Gid>>>>> = OnceLock::new();
+
+    let cache = CACHE.get_or_init(Default::default);
+    if let Some(groups) = cache.lock().ok()?.get(uid) {
+        return groups.clone();
+    }
+    let groups = unsafe { get_passwd_group_ids(uid) };
+    cache.lock().ok()?.insert(uid.clone(), groups.clone());
+    groups
+}
+
+unsafe fn get_passwd_group_ids(uid: &Uid) -> Option<Vec<Gid>> {
+    let mut buffer: Vec<libc::c_char> = Vec::with_capacity(2048);
+    let mut passwd = std::mem::MaybeUninit::<libc::passwd>::uninit();
+    let mut tmp_ptr: *mut libc::passwd = std::ptr::null_mut();
+    let mut last_errno = 0;
+    loop {
+        if retry_eintr!(set_to_0 => last_errno => libc::getpwuid_r(
+            uid.0 as _,
+            passwd.as_mut_ptr(),
+            buffer.as_mut_ptr(),
+            buffer.capacity() as _,
+            &mut tmp_ptr as _
+        )) != 0
+        {
+            // If there was not enough memory, we give it more.
+            if last_errno == libc::ERANGE as _ {
+                // Needs to be updated for `Vec::reserve` to actually add additional capacity.
+                // In here it's "fine" since we never read from `buffer`.
+                buffer.set_len(buffer.capacity());
+                buffer.reserve(2048);
+                continue;
+            }
+            return None;
+        }
+        break;
+    }
+    // `tmp_ptr` is null if there is no entry for this user.
+    if tmp_ptr.is_null() {
+        return None;
+    }
+    let passwd = passwd.assume_init();
+    Some(get_user_group_ids(passwd.pw_name, passwd.pw_gid))
+}
+
 // Not used by mac.
 #[cfg(not(any(target_os = "macos", target_os = "ios")))]
 pub(crate) fn get_users(users: &mut Vec<User>) {
@@ -124,6 +201,11 @@ pub(crate) fn get_users(users: &mut Vec<User>) {
         id.parse::<u32>().ok()
     }
 
+    #[inline]
+    fn parse_path(path: Option<&str>) -> Option<PathBuf> {
+        path.filter(|path| !path.is_empty()).map(PathBuf::from)
+    }
+
     users.clear();
 
     let mut s = String::new();
@@ -136,8 +218,18 @@ pub(crate) fn get_users(users: &mut Vec<User>) {
             // Skip the user if the uid cannot be parsed correctly
             if let Some(uid) = parts.next().and_then(parse_id) {
                 if let Some(group_id) = parts.next().and_then(parse_id) {
+                    // We skip the user description (GECOS) field.
+                    let mut parts = parts.skip(1);
+                    let home_directory = parse_path(parts.next());
+                    let shell = parse_path(parts.next());
                     users.push(User {
-                        inner: UserInner::new(Uid(uid), Gid(group_id), username.to_owned()),
+                        inner: UserInner::new(
+                            Uid(uid),
+                            Gid(group_id),
+                            username.to_owned(),
+                            home_directory,
+                            shell,
+                        ),
                     });
                 }
             }
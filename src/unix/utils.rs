@@ -1,6 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use libc::c_char;
+use std::path::Path;
 
 pub(crate) fn cstr_to_rust(c: *const c_char) -> Option<String> {
     cstr_to_rust_with_size(c, None)
@@ -30,3 +31,30 @@ pub(crate) fn cstr_to_rust_with_size(c: *const c_char, size: Option<usize>) -> O
         String::from_utf8(s).ok()
     }
 }
+
+/// Returns `true` if `mount_point` resolves to the root of the file system hierarchy (`/`).
+pub(crate) fn is_root_mount_point(mount_point: &Path) -> bool {
+    mount_point == Path::new("/")
+        || std::fs::canonicalize(mount_point).is_ok_and(|path| path == Path::new("/"))
+}
+
+/// Returns the mount point of the file system `path` belongs to, or `None` if `path` doesn't
+/// exist.
+///
+/// It is resolved by the kernel, so it handles the macOS firmlinks (like `/Users`, which looks
+/// like it is on `/` but is actually on the data volume, mounted on `/System/Volumes/Data`).
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+pub(crate) fn get_mount_point_of(path: &Path) -> Option<std::path::PathBuf> {
+    use std::ffi::{CStr, CString, OsStr};
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = CString::new(path.as_os_str().as_bytes()).ok()?;
+    unsafe {
+        let mut stat: libc::statfs = std::mem::zeroed();
+        if libc::statfs(path.as_ptr(), &mut stat) != 0 {
+            return None;
+        }
+        let mount_point = CStr::from_ptr(stat.f_mntonname.as_ptr());
+        Some(OsStr::from_bytes(mount_point.to_bytes()).into())
+    }
+}
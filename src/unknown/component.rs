@@ -21,6 +21,10 @@ impl ComponentInner {
         ""
     }
 
+    pub(crate) fn is_cpu(&self) -> bool {
+        false
+    }
+
     pub(crate) fn refresh(&mut self) {}
 }
 
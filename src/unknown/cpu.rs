@@ -1,5 +1,6 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+#[derive(Clone)]
 pub(crate) struct CpuInner;
 
 impl CpuInner {
@@ -11,6 +12,14 @@ impl CpuInner {
         0.0
     }
 
+    pub(crate) fn nice_usage(&self) -> f32 {
+        0.0
+    }
+
+    pub(crate) fn times(&self) -> (u64, u64) {
+        (0, 0)
+    }
+
     pub(crate) fn name(&self) -> &str {
         ""
     }
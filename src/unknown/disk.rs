@@ -15,6 +15,10 @@ impl DiskInner {
         unreachable!()
     }
 
+    pub(crate) fn device_name(&self) -> Option<&OsStr> {
+        unreachable!()
+    }
+
     pub(crate) fn file_system(&self) -> &OsStr {
         Default::default()
     }
@@ -31,26 +35,75 @@ impl DiskInner {
         0
     }
 
+    pub(crate) fn previous_available_space(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_inodes(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn available_inodes(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn is_removable(&self) -> bool {
         false
     }
 
+    pub(crate) fn is_network(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_boot_disk(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_read_only(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_nosuid(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_noexec(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_timed_out(&self) -> bool {
+        false
+    }
+
     pub(crate) fn refresh(&mut self) -> bool {
         true
     }
+
+    pub(crate) fn start_refresh(&mut self) {}
+
+    pub(crate) fn wait_refresh(&mut self, _deadline: std::time::Instant) -> bool {
+        true
+    }
 }
 
 pub(crate) struct DisksInner {
     pub(crate) disks: Vec<Disk>,
+    pub(crate) list_timeout_runner: crate::utils::TimeoutRunner<Vec<Disk>>,
 }
 
 impl DisksInner {
     pub(crate) fn new() -> Self {
-        Self { disks: Vec::new() }
+        Self {
+            disks: Vec::new(),
+            list_timeout_runner: Default::default(),
+        }
     }
 
     pub(crate) fn from_vec(disks: Vec<Disk>) -> Self {
-        Self { disks }
+        Self {
+            disks,
+            list_timeout_runner: Default::default(),
+        }
     }
 
     pub(crate) fn into_vec(self) -> Vec<Disk> {
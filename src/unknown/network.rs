@@ -24,6 +24,10 @@ impl NetworksInner {
     pub(crate) fn refresh_list(&mut self) {}
 
     pub(crate) fn refresh(&mut self) {}
+
+    pub(crate) fn default_interface() -> Option<String> {
+        None
+    }
 }
 
 pub(crate) struct NetworkDataInner;
@@ -84,4 +88,24 @@ impl NetworkDataInner {
     pub(crate) fn ip_networks(&self) -> &[IpNetwork] {
         &[]
     }
+
+    pub(crate) fn is_up(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_loopback(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_running(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn mtu(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn link_speed(&self) -> u64 {
+        0
+    }
 }
@@ -1,6 +1,6 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{DiskUsage, Gid, Pid, ProcessStatus, Signal, Uid};
+use crate::{DiskUsage, Gid, PageFaults, Pid, ProcessStatus, Signal, Uid};
 
 use std::ffi::{OsStr, OsString};
 use std::fmt;
@@ -12,6 +12,7 @@ impl fmt::Display for ProcessStatus {
     }
 }
 
+#[derive(Clone)]
 pub(crate) struct ProcessInner {
     pid: Pid,
     parent: Option<Pid>,
@@ -74,6 +75,14 @@ impl ProcessInner {
         0
     }
 
+    pub(crate) fn accumulated_cpu_time(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn page_faults(&self) -> Option<PageFaults> {
+        None
+    }
+
     pub(crate) fn cpu_usage(&self) -> f32 {
         0.0
     }
@@ -103,4 +112,12 @@ impl ProcessInner {
     pub(crate) fn session_id(&self) -> Option<Pid> {
         None
     }
+
+    pub(crate) fn is_being_traced(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn swapped_memory(&self) -> u64 {
+        0
+    }
 }
@@ -1,14 +1,17 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::{
-    Cpu, CpuInner, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessRefreshKind,
+    Cpu, CpuInner, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, PowerSource, Process, SwapFile,
 };
+#[cfg(feature = "processes")]
+use crate::{ProcessRefreshKind, Uid};
 
 use std::collections::HashMap;
 
 pub(crate) struct SystemInner {
     processes_list: HashMap<Pid, Process>,
     global_cpu: Cpu,
+    cpu_smoothing: f32,
 }
 
 impl SystemInner {
@@ -18,6 +21,7 @@ impl SystemInner {
             global_cpu: Cpu {
                 inner: CpuInner::new(),
             },
+            cpu_smoothing: 1.,
         }
     }
 
@@ -29,15 +33,21 @@ impl SystemInner {
 
     pub(crate) fn refresh_cpu_specifics(&mut self, _refresh_kind: CpuRefreshKind) {}
 
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {}
+
     pub(crate) fn refresh_cpu_list(&mut self, _refresh_kind: CpuRefreshKind) {}
 
+    #[cfg(feature = "processes")]
     pub(crate) fn refresh_processes_specifics(
         &mut self,
         _filter: Option<&[Pid]>,
+        _uid_filter: Option<&Uid>,
         _refresh_kind: ProcessRefreshKind,
+        _exited: Option<&mut Vec<Process>>,
     ) {
     }
 
+    #[cfg(feature = "processes")]
     pub(crate) fn refresh_process_specifics(
         &mut self,
         _pid: Pid,
@@ -54,6 +64,20 @@ impl SystemInner {
         &self.processes_list
     }
 
+    #[cfg(feature = "processes")]
+    pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
+        &mut self.processes_list
+    }
+
+    #[cfg(feature = "processes")]
+    pub(crate) fn list_pids(&self) -> Vec<Pid> {
+        Vec::new()
+    }
+
+    pub(crate) fn remove_process(&mut self, pid: Pid) -> Option<Process> {
+        self.processes_list.remove(&pid)
+    }
+
     pub(crate) fn process(&self, _pid: Pid) -> Option<&Process> {
         None
     }
@@ -66,14 +90,40 @@ impl SystemInner {
         &[]
     }
 
+    pub(crate) fn set_error_callback(&mut self, _callback: crate::utils::ErrorCallback) {}
+
+    pub(crate) fn set_cpu_smoothing(&mut self, alpha: f32) {
+        self.cpu_smoothing = alpha;
+    }
+
+    pub(crate) fn cpu_smoothing(&self) -> f32 {
+        self.cpu_smoothing
+    }
+
+    pub(crate) fn logical_cpu_count(&self) -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn online_cpu_count(&self) -> Option<usize> {
+        None
+    }
+
     pub(crate) fn physical_core_count(&self) -> Option<usize> {
         None
     }
 
+    pub(crate) fn cache_size(&self, _level: u8) -> Option<u64> {
+        None
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         0
     }
 
+    pub(crate) fn page_size(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn free_memory(&self) -> u64 {
         0
     }
@@ -98,10 +148,22 @@ impl SystemInner {
         0
     }
 
+    pub(crate) fn swap_files(&self) -> Vec<SwapFile> {
+        Vec::new()
+    }
+
+    pub(crate) fn idle_time(&self) -> Option<u64> {
+        None
+    }
+
     pub(crate) fn uptime() -> u64 {
         0
     }
 
+    pub(crate) fn power_source() -> PowerSource {
+        PowerSource::Unknown
+    }
+
     pub(crate) fn boot_time() -> u64 {
         0
     }
@@ -122,6 +184,10 @@ impl SystemInner {
         None
     }
 
+    pub(crate) fn hardware_model() -> Option<String> {
+        None
+    }
+
     pub(crate) fn kernel_version() -> Option<String> {
         None
     }
@@ -2,6 +2,8 @@
 
 use crate::{Gid, Group, Uid, User};
 
+use std::path::Path;
+
 pub(crate) struct UserInner;
 
 impl UserInner {
@@ -20,6 +22,14 @@ impl UserInner {
     pub(crate) fn groups(&self) -> Vec<Group> {
         Vec::new()
     }
+
+    pub(crate) fn home_directory(&self) -> Option<&Path> {
+        None
+    }
+
+    pub(crate) fn shell(&self) -> Option<&Path> {
+        None
+    }
 }
 
 pub(crate) fn get_users(_: &mut Vec<User>) {}
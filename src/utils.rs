@@ -63,3 +63,417 @@ where
 {
     val.into_iter()
 }
+
+/// Removes the processes for which `is_exited` returns `true` from `processes` and moves them into
+/// `exited`.
+#[cfg(feature = "processes")]
+#[cfg_attr(
+    any(
+        feature = "unknown-ci",
+        not(any(
+            target_os = "macos",
+            target_os = "linux",
+            target_os = "android",
+            target_os = "freebsd",
+            windows,
+        )),
+        all(target_os = "macos", feature = "apple-sandbox"),
+    ),
+    allow(dead_code)
+)]
+pub(crate) fn take_exited_processes<F: Fn(&crate::Process) -> bool>(
+    processes: &mut std::collections::HashMap<crate::Pid, crate::Process>,
+    exited: &mut Vec<crate::Process>,
+    is_exited: F,
+) {
+    let pids = processes
+        .iter()
+        .filter(|(_, process)| is_exited(process))
+        .map(|(pid, _)| *pid)
+        .collect::<Vec<_>>();
+    exited.extend(pids.iter().filter_map(|pid| processes.remove(pid)));
+}
+
+/// Runs functions on a separate thread with a timeout. If one doesn't complete in time, its
+/// thread is kept as pending and the next calls wait for its result instead of spawning a new
+/// thread.
+pub(crate) struct TimeoutRunner<T> {
+    pending: Option<std::sync::mpsc::Receiver<T>>,
+}
+
+impl<T> Default for TimeoutRunner<T> {
+    fn default() -> Self {
+        Self { pending: None }
+    }
+}
+
+impl<T: Send + 'static> TimeoutRunner<T> {
+    /// Returns the result of `f` (or of the pending thread if there is one), or `None` if it
+    /// didn't complete within `timeout`.
+    pub(crate) fn run<F>(&mut self, timeout: std::time::Duration, f: F) -> Option<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+    {
+        self.start(f);
+        self.wait(std::time::Instant::now() + timeout)
+    }
+
+    /// Runs `f` on a separate thread, unless the thread of a previous call is still pending.
+    /// Its result is retrieved with [`TimeoutRunner::wait`].
+    pub(crate) fn start<F>(&mut self, f: F)
+    where
+        F: FnOnce() -> T + Send + 'static,
+    {
+        if self.pending.is_some() {
+            return;
+        }
+        // The channel is bounded so the thread doesn't block on `send` if we stopped waiting
+        // for it.
+        let (sender, receiver) = std::sync::mpsc::sync_channel(1);
+        if std::thread::Builder::new()
+            .name("sysinfo-timeout".to_owned())
+            .spawn(move || {
+                let _ = sender.send(f());
+            })
+            .is_ok()
+        {
+            self.pending = Some(receiver);
+        }
+    }
+
+    /// Returns the result of the thread started by [`TimeoutRunner::start`], or `None` if it
+    /// didn't complete before `deadline`.
+    pub(crate) fn wait(&mut self, deadline: std::time::Instant) -> Option<T> {
+        let receiver = self.pending.take()?;
+        match receiver.recv_timeout(deadline.saturating_duration_since(std::time::Instant::now())) {
+            Ok(value) => Some(value),
+            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
+                self.pending = Some(receiver);
+                None
+            }
+            // The thread panicked.
+            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => None,
+        }
+    }
+
+    /// Returns `true` if the last [`TimeoutRunner::wait`] call timed out.
+    #[cfg_attr(
+        any(
+            feature = "unknown-ci",
+            not(any(
+                target_os = "macos",
+                target_os = "ios",
+                target_os = "linux",
+                target_os = "android",
+                target_os = "freebsd",
+                windows,
+            )),
+        ),
+        allow(dead_code)
+    )]
+    pub(crate) fn is_timed_out(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Forgets the pending thread (if any), because its result is outdated.
+    pub(crate) fn reset(&mut self) {
+        self.pending = None;
+    }
+}
+
+/// Clock used to compute [`Process::run_time`](crate::Process::run_time), so it is derived from
+/// the same clock as [`System::uptime`](crate::System::uptime).
+#[cfg(all(
+    feature = "processes",
+    any(
+        windows,
+        target_os = "freebsd",
+        all(target_os = "macos", not(feature = "apple-sandbox")),
+    ),
+    not(feature = "unknown-ci"),
+))]
+#[derive(Clone, Copy)]
+pub(crate) struct RunTimeClock {
+    uptime: u64,
+    boot_time: u64,
+}
+
+#[cfg(all(
+    feature = "processes",
+    any(
+        windows,
+        target_os = "freebsd",
+        all(target_os = "macos", not(feature = "apple-sandbox")),
+    ),
+    not(feature = "unknown-ci"),
+))]
+impl RunTimeClock {
+    pub(crate) fn now() -> Self {
+        Self {
+            uptime: crate::System::uptime(),
+            boot_time: crate::System::boot_time(),
+        }
+    }
+
+    /// Returns the run time of a process started at `start_time` (in seconds since the epoch):
+    /// `uptime - (start_time - boot_time)`.
+    pub(crate) fn run_time(self, start_time: u64) -> u64 {
+        self.uptime
+            .saturating_sub(start_time.saturating_sub(self.boot_time))
+    }
+}
+
+/// Blends the `new` CPU usage with the `previous` one (exponential smoothing, see
+/// `System::set_cpu_smoothing`). `alpha` is the weight of the `new` value.
+#[cfg_attr(
+    any(
+        feature = "unknown-ci",
+        not(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "linux",
+            target_os = "android",
+            target_os = "freebsd",
+            windows,
+        )),
+    ),
+    allow(dead_code)
+)]
+pub(crate) fn smooth_cpu_usage(previous: f32, new: f32, alpha: f32) -> f32 {
+    alpha * new + (1. - alpha) * previous
+}
+
+/// Returns the percentage `used` represents of `total`, or `0.` if `total` is `0`.
+pub(crate) fn usage_percent(used: u64, total: u64) -> f32 {
+    if total == 0 {
+        0.
+    } else {
+        (used as f64 / total as f64 * 100.) as f32
+    }
+}
+
+/// Callback set with `System::set_error_callback`.
+pub(crate) type ErrorCallback = Box<dyn Fn(&str) + Send + Sync>;
+
+/// Joins the command line arguments with spaces, quoting them when needed so they can be split
+/// back the same way by a shell (or by `CommandLineToArgvW` on Windows).
+pub(crate) fn join_command_line(cmd: &[std::ffi::OsString]) -> std::ffi::OsString {
+    let mut command_line = std::ffi::OsString::new();
+    for (pos, arg) in cmd.iter().enumerate() {
+        if pos != 0 {
+            command_line.push(" ");
+        }
+        if cfg!(windows) {
+            push_windows_arg(&mut command_line, arg);
+        } else {
+            push_unix_arg(&mut command_line, arg);
+        }
+    }
+    command_line
+}
+
+/// Truncates `s` to at most `max_bytes` bytes, ending it with an ellipsis (`…`) if it fits. It
+/// never splits a UTF-8 character.
+pub(crate) fn truncate_with_ellipsis(mut s: String, max_bytes: usize) -> String {
+    const ELLIPSIS: &str = "…";
+
+    if s.len() <= max_bytes {
+        return s;
+    }
+    let mut end = max_bytes.saturating_sub(ELLIPSIS.len());
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    s.truncate(end);
+    if max_bytes >= ELLIPSIS.len() {
+        s.push_str(ELLIPSIS);
+    }
+    s
+}
+
+/// Turns `s` into a single component of a dotted metric name: every character which isn't an
+/// ASCII alphanumeric, `-` or `_` is replaced with `_`, and the leading and trailing `_` are
+/// removed. An empty result becomes `root` (like for the `/` mount point).
+pub(crate) fn metric_name_component(s: &str) -> String {
+    let s = s
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    match s.trim_matches('_') {
+        "" => "root".to_owned(),
+        s => s.to_owned(),
+    }
+}
+
+/// Returns unique metric name components (see [`metric_name_component`]): if a component was
+/// already returned, a `_2` (then `_3`, ...) suffix is added to it.
+#[derive(Default)]
+pub(crate) struct MetricNameComponents {
+    used: std::collections::HashSet<String>,
+}
+
+impl MetricNameComponents {
+    pub(crate) fn get(&mut self, s: &str) -> String {
+        let component = metric_name_component(s);
+        let mut unique = component.clone();
+        let mut suffix = 2;
+        while !self.used.insert(unique.clone()) {
+            unique = format!("{component}_{suffix}");
+            suffix += 1;
+        }
+        unique
+    }
+}
+
+fn push_unix_arg(command_line: &mut std::ffi::OsString, arg: &std::ffi::OsStr) {
+    let bytes = arg.as_encoded_bytes();
+    if !bytes.is_empty()
+        && bytes
+            .iter()
+            .all(|c| c.is_ascii_alphanumeric() || b"-_./:=+,@%^".contains(c) || !c.is_ascii())
+    {
+        command_line.push(arg);
+        return;
+    }
+    // Inside single quotes, everything is kept as is, except the single quotes themselves which
+    // need to be closed, escaped and reopened (`'\''`).
+    command_line.push("'");
+    for (pos, part) in bytes.split(|c| *c == b'\'').enumerate() {
+        if pos != 0 {
+            command_line.push("'\\''");
+        }
+        // SAFETY: `part` comes from `as_encoded_bytes` and was split on an ASCII character.
+        command_line.push(unsafe { std::ffi::OsStr::from_encoded_bytes_unchecked(part) });
+    }
+    command_line.push("'");
+}
+
+fn push_windows_arg(command_line: &mut std::ffi::OsString, arg: &std::ffi::OsStr) {
+    let bytes = arg.as_encoded_bytes();
+    if !bytes.is_empty()
+        && !bytes
+            .iter()
+            .any(|c| matches!(c, b' ' | b'\t' | b'\n' | b'\x0b' | b'"'))
+    {
+        command_line.push(arg);
+        return;
+    }
+    // The backslashes are only special when they're followed by a double quote: then they need
+    // to be doubled and the double quote escaped. This also applies to the trailing backslashes
+    // since they're followed by the closing double quote.
+    command_line.push("\"");
+    let mut backslashes = 0;
+    let mut start = 0;
+    for (pos, c) in bytes.iter().enumerate() {
+        match c {
+            b'\\' => backslashes += 1,
+            b'"' => {
+                // SAFETY: `bytes` comes from `as_encoded_bytes` and is split on an ASCII
+                // character.
+                command_line.push(unsafe {
+                    std::ffi::OsStr::from_encoded_bytes_unchecked(&bytes[start..pos])
+                });
+                command_line.push("\\".repeat(backslashes + 1));
+                start = pos;
+                backslashes = 0;
+            }
+            _ => backslashes = 0,
+        }
+    }
+    // SAFETY: `bytes` comes from `as_encoded_bytes` and is split on an ASCII character.
+    command_line.push(unsafe { std::ffi::OsStr::from_encoded_bytes_unchecked(&bytes[start..]) });
+    command_line.push("\\".repeat(backslashes));
+    command_line.push("\"");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        metric_name_component, push_unix_arg, push_windows_arg, truncate_with_ellipsis,
+        MetricNameComponents, TimeoutRunner,
+    };
+    use std::ffi::OsString;
+
+    fn quote(push: fn(&mut OsString, &std::ffi::OsStr), arg: &str) -> OsString {
+        let mut out = OsString::new();
+        push(&mut out, arg.as_ref());
+        out
+    }
+
+    #[test]
+    fn test_unix_quoting() {
+        assert_eq!(quote(push_unix_arg, "/usr/bin/ls"), "/usr/bin/ls");
+        assert_eq!(quote(push_unix_arg, "--color=auto"), "--color=auto");
+        assert_eq!(quote(push_unix_arg, ""), "''");
+        assert_eq!(quote(push_unix_arg, "a b"), "'a b'");
+        assert_eq!(quote(push_unix_arg, "it's"), "'it'\\''s'");
+        assert_eq!(quote(push_unix_arg, "$HOME"), "'$HOME'");
+    }
+
+    #[test]
+    fn test_windows_quoting() {
+        assert_eq!(quote(push_windows_arg, r"C:\a\b.exe"), r"C:\a\b.exe");
+        assert_eq!(quote(push_windows_arg, ""), r#""""#);
+        assert_eq!(quote(push_windows_arg, r"C:\a b\"), r#""C:\a b\\""#);
+        assert_eq!(quote(push_windows_arg, r#"a\"b c"#), r#""a\\\"b c""#);
+        assert_eq!(quote(push_windows_arg, r#"say "hi""#), r#""say \"hi\"""#);
+    }
+
+    #[test]
+    fn test_truncate_with_ellipsis() {
+        let truncate = |s: &str, max_bytes| truncate_with_ellipsis(s.to_owned(), max_bytes);
+
+        assert_eq!(truncate("ls -l", 5), "ls -l");
+        assert_eq!(truncate("ls -l /tmp", 8), "ls -l…");
+        // `é` is 2 bytes long so it can't be cut in half.
+        assert_eq!(truncate("café au lait", 7), "caf…");
+        assert_eq!(truncate("ls -l", 2), "");
+    }
+
+    #[test]
+    fn test_metric_name_component() {
+        assert_eq!(metric_name_component("/"), "root");
+        assert_eq!(metric_name_component("/mnt/my disk"), "mnt_my_disk");
+        assert_eq!(metric_name_component("C:\\"), "C");
+        assert_eq!(metric_name_component("eth0.100"), "eth0_100");
+        assert_eq!(metric_name_component("wlan-0"), "wlan-0");
+    }
+
+    #[test]
+    fn test_metric_name_components() {
+        let mut components = MetricNameComponents::default();
+        assert_eq!(components.get("/mnt/data"), "mnt_data");
+        assert_eq!(components.get("/mnt_data"), "mnt_data_2");
+        assert_eq!(components.get("/mnt_data_2"), "mnt_data_2_2");
+        assert_eq!(components.get("mnt.data"), "mnt_data_3");
+        assert_eq!(components.get("/"), "root");
+    }
+
+    #[test]
+    fn test_timeout_runner() {
+        let mut runner = TimeoutRunner::default();
+        let timeout = std::time::Duration::from_millis(50);
+
+        assert_eq!(runner.run(timeout, || 1), Some(1));
+        assert!(!runner.is_timed_out());
+
+        assert_eq!(
+            runner.run(timeout, || {
+                std::thread::sleep(std::time::Duration::from_millis(500));
+                2
+            }),
+            None,
+        );
+        assert!(runner.is_timed_out());
+        // The pending thread is waited for instead of running the new function.
+        assert_eq!(runner.run(std::time::Duration::from_secs(5), || 3), Some(2));
+        assert!(!runner.is_timed_out());
+        assert_eq!(runner.run(timeout, || 4), Some(4));
+    }
+}
@@ -61,6 +61,11 @@ impl ComponentInner {
         &self.label
     }
 
+    pub(crate) fn is_cpu(&self) -> bool {
+        // This is the ACPI thermal zone temperature, which isn't specific to the CPU.
+        false
+    }
+
     pub(crate) fn refresh(&mut self) {
         if self.connection.is_none() {
             self.connection = Connection::new()
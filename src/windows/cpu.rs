@@ -1,6 +1,8 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::sys::tools::KeyHandler;
+use crate::sys::utils::filetime_to_u64;
+use crate::utils::smooth_cpu_usage;
 use crate::{Cpu, CpuRefreshKind, LoadAvg};
 
 use std::collections::HashMap;
@@ -10,9 +12,13 @@ use std::mem;
 use std::ops::DerefMut;
 use std::sync::{Mutex, OnceLock};
 
+use ntapi::ntexapi::{
+    SystemProcessorPerformanceInformation, SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION,
+};
 use windows::core::{s, PCSTR, PCWSTR};
+use windows::Wdk::System::SystemInformation::{NtQuerySystemInformation, SYSTEM_INFORMATION_CLASS};
 use windows::Win32::Foundation::{
-    CloseHandle, BOOLEAN, ERROR_INSUFFICIENT_BUFFER, ERROR_SUCCESS, FALSE, HANDLE,
+    CloseHandle, BOOLEAN, ERROR_INSUFFICIENT_BUFFER, ERROR_SUCCESS, FALSE, FILETIME, HANDLE,
 };
 use windows::Win32::System::Performance::{
     PdhAddEnglishCounterA, PdhAddEnglishCounterW, PdhCloseQuery, PdhCollectQueryData,
@@ -24,11 +30,11 @@ use windows::Win32::System::Power::{
 };
 use windows::Win32::System::SystemInformation;
 use windows::Win32::System::SystemInformation::{
-    GetLogicalProcessorInformationEx, GetSystemInfo, RelationAll, RelationProcessorCore,
-    SYSTEM_INFO, SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
+    CacheData, CacheUnified, GetLogicalProcessorInformationEx, GetSystemInfo, RelationAll,
+    RelationCache, RelationProcessorCore, SYSTEM_INFO, SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
 };
 use windows::Win32::System::Threading::{
-    CreateEventA, RegisterWaitForSingleObject, INFINITE, WT_EXECUTEDEFAULT,
+    CreateEventA, GetSystemTimes, RegisterWaitForSingleObject, INFINITE, WT_EXECUTEDEFAULT,
 };
 
 // This formula comes from Linux's include/linux/sched/loadavg.h
@@ -249,6 +255,8 @@ pub(crate) struct CpusWrapper {
     global: Cpu,
     cpus: Vec<Cpu>,
     got_cpu_frequency: bool,
+    /// Smoothing factor applied to the CPU usage (see `System::set_cpu_smoothing`).
+    pub(crate) cpu_smoothing: f32,
 }
 
 impl CpusWrapper {
@@ -264,6 +272,7 @@ impl CpusWrapper {
             },
             cpus: Vec::new(),
             got_cpu_frequency: false,
+            cpu_smoothing: 1.,
         }
     }
 
@@ -286,6 +295,7 @@ impl CpusWrapper {
         }
     }
 
+    #[cfg(feature = "processes")]
     pub fn len(&mut self) -> usize {
         self.init_if_needed(CpuRefreshKind::new());
         self.cpus.len()
@@ -296,6 +306,62 @@ impl CpusWrapper {
         self.cpus.iter_mut()
     }
 
+    /// Updates the cumulative times of the CPUs, used by `System::cpu_times`.
+    pub fn refresh_times(&mut self) {
+        let mut info: Vec<SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION> =
+            vec![unsafe { mem::zeroed() }; self.cpus.len()];
+        let mut returned = 0;
+        unsafe {
+            if let Err(_err) = NtQuerySystemInformation(
+                SYSTEM_INFORMATION_CLASS(SystemProcessorPerformanceInformation as _),
+                info.as_mut_ptr().cast(),
+                mem::size_of_val(info.as_slice()) as _,
+                &mut returned,
+            )
+            .ok()
+            {
+                sysinfo_debug!("Couldn't get CPU times: NtQuerySystemInformation returned {_err}");
+                return;
+            }
+        }
+        let count = returned as usize / mem::size_of::<SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION>();
+        let mut global = (0u64, 0u64);
+        for (cpu, info) in self.cpus.iter_mut().zip(info.iter().take(count)) {
+            let (idle, kernel, user) = unsafe {
+                (
+                    *info.IdleTime.QuadPart() as u64,
+                    *info.KernelTime.QuadPart() as u64,
+                    *info.UserTime.QuadPart() as u64,
+                )
+            };
+            // The kernel time includes the idle time.
+            let total = kernel.saturating_add(user);
+            let times = (total.saturating_sub(idle), total);
+            cpu.inner.times = times;
+            global = (
+                global.0.saturating_add(times.0),
+                global.1.saturating_add(times.1),
+            );
+        }
+        self.global.inner.times = global;
+    }
+
+    /// Updates the cumulative times of the global CPU only.
+    pub fn refresh_global_times(&mut self) {
+        let mut idle = FILETIME::default();
+        let mut kernel = FILETIME::default();
+        let mut user = FILETIME::default();
+        unsafe {
+            if let Err(_err) = GetSystemTimes(Some(&mut idle), Some(&mut kernel), Some(&mut user)) {
+                sysinfo_debug!("Couldn't get CPU times: GetSystemTimes returned {_err}");
+                return;
+            }
+        }
+        // The kernel time includes the idle time.
+        let total = filetime_to_u64(kernel).saturating_add(filetime_to_u64(user));
+        self.global.inner.times = (total.saturating_sub(filetime_to_u64(idle)), total);
+    }
+
     pub fn get_frequencies(&mut self) {
         if self.got_cpu_frequency {
             return;
@@ -312,9 +378,11 @@ impl CpusWrapper {
     }
 }
 
+#[derive(Clone)]
 pub(crate) struct CpuInner {
     name: String,
     cpu_usage: f32,
+    times: (u64, u64),
     key_used: Option<KeyHandler>,
     vendor_id: String,
     brand: String,
@@ -326,6 +394,15 @@ impl CpuInner {
         self.cpu_usage
     }
 
+    pub(crate) fn nice_usage(&self) -> f32 {
+        0.
+    }
+
+    /// Returns the time spent working and the total time since boot, in 100 ns units.
+    pub(crate) fn times(&self) -> (u64, u64) {
+        self.times
+    }
+
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
@@ -351,6 +428,7 @@ impl CpuInner {
         Self {
             name,
             cpu_usage: 0f32,
+            times: (0, 0),
             key_used: None,
             vendor_id,
             brand,
@@ -358,8 +436,8 @@ impl CpuInner {
         }
     }
 
-    pub(crate) fn set_cpu_usage(&mut self, value: f32) {
-        self.cpu_usage = value;
+    pub(crate) fn set_cpu_usage(&mut self, value: f32, alpha: f32) {
+        self.cpu_usage = smooth_cpu_usage(self.cpu_usage, value, alpha);
     }
 
     pub(crate) fn set_frequency(&mut self, value: u64) {
@@ -502,11 +580,9 @@ pub(crate) fn get_frequencies(nb_cpus: usize) -> Vec<u64> {
     vec![0; nb_cpus]
 }
 
-pub(crate) fn get_physical_core_count() -> Option<usize> {
-    // We cannot use the number of cpus here to pre calculate the buf size.
-    // `GetLogicalCpuInformationEx` with `RelationProcessorCore` passed to it not only returns
-    // the logical cores but also numa nodes.
-    //
+/// Returns the buffer filled by `GetLogicalProcessorInformationEx`, containing
+/// `SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX` items of variable size.
+fn get_logical_processor_information() -> Option<Vec<u8>> {
     // GetLogicalCpuInformationEx: https://docs.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getlogicalprocessorinformationex
 
     let mut needed_size = 0;
@@ -537,7 +613,7 @@ pub(crate) fn get_physical_core_count() -> Option<usize> {
                     Some(value) if value == ERROR_INSUFFICIENT_BUFFER.0 as i32 => {}
                     _ => {
                         sysinfo_debug!(
-                            "get_physical_core_count: GetLogicalCpuInformationEx failed"
+                            "get_logical_processor_information: GetLogicalCpuInformationEx failed"
                         );
                         return None;
                     }
@@ -552,7 +628,7 @@ pub(crate) fn get_physical_core_count() -> Option<usize> {
                 Some(new_size) => new_size,
                 None => {
                     sysinfo_debug!(
-                        "get_physical_core_count: buffer size is too big ({} + {})",
+                        "get_logical_processor_information: buffer size is too big ({} + {})",
                         needed_size,
                         reserve,
                     );
@@ -563,19 +639,63 @@ pub(crate) fn get_physical_core_count() -> Option<usize> {
         }
 
         buf.set_len(needed_size as _);
+        Some(buf)
+    }
+}
 
-        let mut i = 0;
-        let raw_buf = buf.as_ptr();
-        let mut count = 0;
-        while i < buf.len() {
-            let p = &*(raw_buf.add(i) as *const SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX);
-            i += p.Size as usize;
-            if p.Relationship == RelationProcessorCore {
-                // Only count the physical cores.
-                count += 1;
-            }
+/// Calls `f` on each item of the buffer returned by `get_logical_processor_information`.
+fn for_each_logical_processor_information<F>(buf: &[u8], mut f: F)
+where
+    F: FnMut(&SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX),
+{
+    let mut i = 0;
+    let raw_buf = buf.as_ptr();
+    while i < buf.len() {
+        // SAFETY: `buf` was filled by `GetLogicalProcessorInformationEx` and each item gives the
+        // offset of the next one with its `Size` field.
+        let p = unsafe { &*(raw_buf.add(i) as *const SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX) };
+        i += p.Size as usize;
+        f(p);
+    }
+}
+
+pub(crate) fn get_physical_core_count() -> Option<usize> {
+    // We cannot use the number of cpus here to pre calculate the buf size.
+    // `GetLogicalCpuInformationEx` with `RelationProcessorCore` passed to it not only returns
+    // the logical cores but also numa nodes.
+    let buf = get_logical_processor_information()?;
+    let mut count = 0;
+    for_each_logical_processor_information(&buf, |p| {
+        if p.Relationship == RelationProcessorCore {
+            // Only count the physical cores.
+            count += 1;
+        }
+    });
+    Some(count)
+}
+
+pub(crate) fn get_cache_size(level: u8) -> Option<u64> {
+    let buf = get_logical_processor_information()?;
+    let mut size = None;
+    for_each_logical_processor_information(&buf, |p| {
+        if size.is_some() || p.Relationship != RelationCache {
+            return;
+        }
+        // SAFETY: `Cache` is the field filled for the `RelationCache` items.
+        let cache = unsafe { &p.Anonymous.Cache };
+        if cache.Level == level && (cache.Type == CacheData || cache.Type == CacheUnified) {
+            size = Some(cache.CacheSize as u64);
         }
-        Some(count)
+    });
+    size
+}
+
+pub(crate) fn get_logical_cpu_count() -> Option<usize> {
+    let mut sys_info = SYSTEM_INFO::default();
+    unsafe { GetSystemInfo(&mut sys_info) };
+    match sys_info.dwNumberOfProcessors {
+        0 => None,
+        count => Some(count as _),
     }
 }
 
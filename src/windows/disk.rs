@@ -1,12 +1,14 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::sys::utils::HandleWrapper;
+use crate::utils::TimeoutRunner;
 use crate::{Disk, DiskKind};
 
 use std::ffi::{c_void, OsStr, OsString};
 use std::mem::size_of;
 use std::os::windows::ffi::OsStringExt;
 use std::path::Path;
+use std::time::Instant;
 
 use windows::core::{Error, HRESULT, PCWSTR};
 use windows::Win32::Foundation::MAX_PATH;
@@ -18,6 +20,7 @@ use windows::Win32::System::Ioctl::{
     PropertyStandardQuery, StorageDeviceSeekPenaltyProperty, DEVICE_SEEK_PENALTY_DESCRIPTOR,
     IOCTL_STORAGE_QUERY_PROPERTY, STORAGE_PROPERTY_QUERY,
 };
+use windows::Win32::System::SystemServices::FILE_READ_ONLY_VOLUME;
 use windows::Win32::System::WindowsProgramming::{DRIVE_FIXED, DRIVE_REMOVABLE};
 use windows::Win32::System::IO::DeviceIoControl;
 
@@ -124,7 +127,12 @@ pub(crate) struct DiskInner {
     s_mount_point: OsString,
     total_space: u64,
     available_space: u64,
+    /// Available space before the last refresh.
+    previous_available_space: u64,
     is_removable: bool,
+    is_boot_disk: bool,
+    is_read_only: bool,
+    timeout_runner: TimeoutRunner<Option<u64>>,
 }
 
 impl DiskInner {
@@ -136,6 +144,10 @@ impl DiskInner {
         &self.name
     }
 
+    pub(crate) fn device_name(&self) -> Option<&OsStr> {
+        None
+    }
+
     pub(crate) fn file_system(&self) -> &OsStr {
         &self.file_system
     }
@@ -152,38 +164,113 @@ impl DiskInner {
         self.available_space
     }
 
+    pub(crate) fn previous_available_space(&self) -> u64 {
+        self.previous_available_space
+    }
+
+    pub(crate) fn total_inodes(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn available_inodes(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn is_removable(&self) -> bool {
         self.is_removable
     }
 
+    pub(crate) fn is_network(&self) -> bool {
+        // Only fixed and removable drives are listed.
+        false
+    }
+
+    pub(crate) fn is_boot_disk(&self) -> bool {
+        self.is_boot_disk
+    }
+
+    pub(crate) fn is_read_only(&self) -> bool {
+        self.is_read_only
+    }
+
+    pub(crate) fn is_nosuid(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_noexec(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_timed_out(&self) -> bool {
+        self.timeout_runner.is_timed_out()
+    }
+
     pub(crate) fn refresh(&mut self) -> bool {
-        if self.total_space != 0 {
-            unsafe {
-                let mut tmp = 0;
-                let lpdirectoryname = PCWSTR::from_raw(self.mount_point.as_ptr());
-                if GetDiskFreeSpaceExW(lpdirectoryname, None, None, Some(&mut tmp)).is_ok() {
-                    self.available_space = tmp;
-                    return true;
-                }
-            }
+        if self.total_space == 0 {
+            return false;
+        }
+        self.timeout_runner.reset();
+        let available_space = unsafe { get_available_space(&self.mount_point) };
+        self.update_available_space(available_space)
+    }
+
+    pub(crate) fn start_refresh(&mut self) {
+        if self.total_space == 0 {
+            return;
+        }
+        let mount_point = self.mount_point.clone();
+        self.timeout_runner
+            .start(move || unsafe { get_available_space(&mount_point) });
+    }
+
+    pub(crate) fn wait_refresh(&mut self, deadline: Instant) -> bool {
+        if self.total_space == 0 {
+            return false;
+        }
+        let available_space = self.timeout_runner.wait(deadline);
+        self.update_available_space(available_space.flatten())
+    }
+
+    fn update_available_space(&mut self, available_space: Option<u64>) -> bool {
+        if let Some(available_space) = available_space {
+            self.previous_available_space = self.available_space;
+            self.available_space = available_space;
+            true
+        } else {
+            false
         }
-        false
+    }
+}
+
+/// `mount_point` must be NUL terminated.
+unsafe fn get_available_space(mount_point: &[u16]) -> Option<u64> {
+    let mut tmp = 0;
+    let lpdirectoryname = PCWSTR::from_raw(mount_point.as_ptr());
+    if GetDiskFreeSpaceExW(lpdirectoryname, None, None, Some(&mut tmp)).is_ok() {
+        Some(tmp)
+    } else {
+        None
     }
 }
 
 pub(crate) struct DisksInner {
     pub(crate) disks: Vec<Disk>,
+    pub(crate) list_timeout_runner: TimeoutRunner<Vec<Disk>>,
 }
 
 impl DisksInner {
     pub(crate) fn new() -> Self {
         Self {
             disks: Vec::with_capacity(2),
+            list_timeout_runner: TimeoutRunner::default(),
         }
     }
 
     pub(crate) fn from_vec(disks: Vec<Disk>) -> Self {
-        Self { disks }
+        Self {
+            disks,
+            list_timeout_runner: TimeoutRunner::default(),
+        }
     }
 
     pub(crate) fn into_vec(self) -> Vec<Disk> {
@@ -239,12 +326,13 @@ pub(crate) unsafe fn get_list() -> Vec<Disk> {
             }
             let mut name = [0u16; MAX_PATH as usize + 1];
             let mut file_system = [0u16; 32];
+            let mut file_system_flags = 0;
             let volume_info_res = GetVolumeInformationW(
                 raw_volume_name,
                 Some(&mut name),
                 None,
                 None,
-                None,
+                Some(&mut file_system_flags),
                 Some(&mut file_system),
             )
             .is_ok();
@@ -316,6 +404,7 @@ pub(crate) unsafe fn get_list() -> Vec<Disk> {
                 .into_iter()
                 .map(move |mount_path| Disk {
                     inner: DiskInner {
+                        is_boot_disk: is_system_drive(&mount_path),
                         type_,
                         name: name.clone(),
                         file_system: file_system.clone(),
@@ -323,7 +412,10 @@ pub(crate) unsafe fn get_list() -> Vec<Disk> {
                         mount_point: mount_path,
                         total_space,
                         available_space,
+                        previous_available_space: available_space,
                         is_removable,
+                        is_read_only: file_system_flags & FILE_READ_ONLY_VOLUME != 0,
+                        timeout_runner: TimeoutRunner::default(),
                     },
                 })
                 .collect::<Vec<_>>()
@@ -331,6 +423,19 @@ pub(crate) unsafe fn get_list() -> Vec<Disk> {
         .collect::<Vec<_>>()
 }
 
+/// Returns `true` if `mount_path` (a NUL terminated path like `C:\`) is the system drive.
+fn is_system_drive(mount_path: &[u16]) -> bool {
+    let Some(system_drive) = std::env::var_os("SystemDrive") else {
+        return false;
+    };
+    let mount_path = os_string_from_zero_terminated(mount_path);
+    // `SystemDrive` doesn't have the trailing backslash (`C:`).
+    mount_path
+        .to_string_lossy()
+        .trim_end_matches('\\')
+        .eq_ignore_ascii_case(&system_drive.to_string_lossy())
+}
+
 fn os_string_from_zero_terminated(name: &[u16]) -> OsString {
     let len = name.iter().position(|&x| x == 0).unwrap_or(name.len());
     OsString::from_wide(&name[..len])
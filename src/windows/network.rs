@@ -1,16 +1,18 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::common::MacAddr;
-use crate::network::refresh_networks_addresses;
+use crate::network::{refresh_networks_addresses, InterfaceFlags};
 use crate::IpNetwork;
 use crate::NetworkData;
 
 use std::collections::{hash_map, HashMap};
 
 use windows::Win32::NetworkManagement::IpHelper::{
-    FreeMibTable, GetIfEntry2, GetIfTable2, MIB_IF_ROW2, MIB_IF_TABLE2,
+    FreeMibTable, GetIfEntry2, GetIfTable2, GetIpForwardTable2, GetIpInterfaceEntry, MIB_IF_ROW2,
+    MIB_IF_TABLE2, MIB_IPFORWARD_ROW2, MIB_IPFORWARD_TABLE2, MIB_IPINTERFACE_ROW,
 };
 use windows::Win32::NetworkManagement::Ndis::{MediaConnectStateDisconnected, NET_LUID_LH};
+use windows::Win32::Networking::WinSock::{AF_INET, AF_UNSPEC};
 
 macro_rules! old_and_new {
     ($ty_:expr, $name:ident, $old:ident, $new_val:expr) => {{
@@ -116,6 +118,8 @@ impl NetworksInner {
                         );
                         old_and_new!(interface, errors_in, old_errors_in, ptr.InErrors);
                         old_and_new!(interface, errors_out, old_errors_out, ptr.OutErrors);
+                        interface.mtu = ptr.Mtu as _;
+                        interface.link_speed = link_speed(ptr);
                         interface.updated = true;
                     }
                     hash_map::Entry::Vacant(e) => {
@@ -139,8 +143,12 @@ impl NetworksInner {
                                 old_errors_out: ptr.OutErrors,
                                 mac_addr: MacAddr::UNSPECIFIED,
                                 ip_networks: vec![],
+                                flags: InterfaceFlags::default(),
+                                mtu: ptr.Mtu as _,
+                                link_speed: link_speed(ptr),
                                 updated: true,
                             },
+                            elapsed: None,
                         });
                     }
                 }
@@ -153,6 +161,45 @@ impl NetworksInner {
         refresh_networks_addresses(&mut self.interfaces);
     }
 
+    pub(crate) fn default_interface() -> Option<String> {
+        let mut table: *mut MIB_IPFORWARD_TABLE2 = std::ptr::null_mut();
+
+        unsafe {
+            if GetIpForwardTable2(AF_UNSPEC, &mut table).is_err() {
+                return None;
+            }
+            let rows =
+                std::slice::from_raw_parts((*table).Table.as_ptr(), (*table).NumEntries as _);
+            // The default routes have an empty prefix. The IPv4 ones are preferred, then the ones
+            // with the lowest metric. Like Windows does, the metric of a route is the sum of its
+            // own metric and of the one of its interface.
+            let index = rows
+                .iter()
+                .filter(|row| row.DestinationPrefix.PrefixLength == 0)
+                .filter_map(|row| {
+                    let metric = row.Metric.saturating_add(interface_metric(row)?);
+                    Some((row, metric))
+                })
+                .min_by_key(|(row, metric)| {
+                    (row.DestinationPrefix.Prefix.si_family != AF_INET, *metric)
+                })
+                .map(|(row, _)| row.InterfaceIndex);
+            FreeMibTable(table as _);
+
+            let mut entry = std::mem::MaybeUninit::<MIB_IF_ROW2>::zeroed().assume_init();
+            entry.InterfaceIndex = index?;
+            if GetIfEntry2(&mut entry).is_err() {
+                return None;
+            }
+            let len = entry
+                .Alias
+                .iter()
+                .position(|c| *c == 0)
+                .unwrap_or(entry.Alias.len());
+            String::from_utf16(&entry.Alias[..len]).ok()
+        }
+    }
+
     pub(crate) fn refresh(&mut self) {
         let entry = std::mem::MaybeUninit::<MIB_IF_ROW2>::zeroed();
 
@@ -186,6 +233,15 @@ impl NetworksInner {
     }
 }
 
+/// Returns the link speed of the interface in bits per second, or `0` if it is unknown.
+fn link_speed(row: &MIB_IF_ROW2) -> u64 {
+    // The speed is set to `u64::MAX` when it's unknown.
+    match row.TransmitLinkSpeed.max(row.ReceiveLinkSpeed) {
+        u64::MAX => 0,
+        speed => speed,
+    }
+}
+
 pub(crate) struct NetworkDataInner {
     id: NET_LUID_LH,
     current_out: u64,
@@ -203,6 +259,9 @@ pub(crate) struct NetworkDataInner {
     updated: bool,
     pub(crate) mac_addr: MacAddr,
     pub(crate) ip_networks: Vec<IpNetwork>,
+    pub(crate) flags: InterfaceFlags,
+    mtu: u64,
+    link_speed: u64,
 }
 
 impl NetworkDataInner {
@@ -261,4 +320,37 @@ impl NetworkDataInner {
     pub(crate) fn ip_networks(&self) -> &[IpNetwork] {
         &self.ip_networks
     }
+
+    pub(crate) fn is_up(&self) -> bool {
+        self.flags.is_up
+    }
+
+    pub(crate) fn is_loopback(&self) -> bool {
+        self.flags.is_loopback
+    }
+
+    pub(crate) fn is_running(&self) -> bool {
+        self.flags.is_running
+    }
+
+    pub(crate) fn mtu(&self) -> u64 {
+        self.mtu
+    }
+
+    pub(crate) fn link_speed(&self) -> u64 {
+        self.link_speed
+    }
+}
+
+/// Returns the metric of the interface of the `route`, for the address family of the route.
+unsafe fn interface_metric(route: &MIB_IPFORWARD_ROW2) -> Option<u32> {
+    let mut interface = MIB_IPINTERFACE_ROW {
+        Family: route.DestinationPrefix.Prefix.si_family,
+        InterfaceLuid: route.InterfaceLuid,
+        ..Default::default()
+    };
+    if GetIpInterfaceEntry(&mut interface).is_err() {
+        return None;
+    }
+    Some(interface.Metric)
 }
@@ -7,13 +7,15 @@ use std::ptr::{null_mut, NonNull};
 use windows::Win32::Foundation::{ERROR_BUFFER_OVERFLOW, ERROR_SUCCESS};
 use windows::Win32::NetworkManagement::IpHelper::{
     GetAdaptersAddresses, GAA_FLAG_SKIP_ANYCAST, GAA_FLAG_SKIP_DNS_SERVER, GAA_FLAG_SKIP_MULTICAST,
-    IP_ADAPTER_ADDRESSES_LH, IP_ADAPTER_UNICAST_ADDRESS_LH,
+    IF_TYPE_SOFTWARE_LOOPBACK, IP_ADAPTER_ADDRESSES_LH, IP_ADAPTER_UNICAST_ADDRESS_LH,
 };
+use windows::Win32::NetworkManagement::Ndis::IfOperStatusUp;
 use windows::Win32::Networking::WinSock::{
     AF_INET, AF_INET6, AF_UNSPEC, SOCKADDR, SOCKADDR_IN, SOCKADDR_IN6,
 };
 
 use crate::common::{IpNetwork, MacAddr};
+use crate::network::InterfaceFlags;
 
 /// this iterator yields an interface name and address
 pub(crate) struct InterfaceAddressIterator {
@@ -84,6 +86,38 @@ impl InterfaceAddressIterator {
         }
         results
     }
+
+    pub fn generate_flags(&mut self) -> HashMap<String, InterfaceFlags> {
+        let mut results = HashMap::new();
+        while !self.adapter.is_null() {
+            unsafe {
+                let adapter = self.adapter;
+                // Move to the next adapter
+                self.adapter = (*adapter).Next;
+                if let Ok(interface_name) = (*adapter).FriendlyName.to_string() {
+                    // Windows doesn't make the difference between an enabled interface and one
+                    // which can transmit packets.
+                    let is_up = (*adapter).OperStatus == IfOperStatusUp;
+                    results.insert(
+                        interface_name,
+                        InterfaceFlags {
+                            is_up,
+                            is_loopback: (*adapter).IfType == IF_TYPE_SOFTWARE_LOOPBACK,
+                            is_running: is_up,
+                        },
+                    );
+                }
+            }
+        }
+        results
+    }
+}
+
+pub(crate) unsafe fn get_interface_flags() -> HashMap<String, InterfaceFlags> {
+    match get_interface_address() {
+        Ok(mut interface_iter) => interface_iter.generate_flags(),
+        _ => HashMap::new(),
+    }
 }
 
 pub(crate) unsafe fn get_interface_ip_networks() -> HashMap<String, HashSet<IpNetwork>> {
@@ -2,53 +2,87 @@
 
 use crate::sys::system::is_proc_running;
 use crate::sys::utils::HandleWrapper;
-use crate::windows::Sid;
-use crate::{DiskUsage, Gid, Pid, ProcessRefreshKind, ProcessStatus, Signal, Uid};
+use crate::{DiskUsage, Gid, PageFaults, Pid, ProcessStatus, Signal, Uid};
 
 use std::ffi::{OsStr, OsString};
 use std::fmt;
 #[cfg(feature = "debug")]
 use std::io;
-use std::mem::{size_of, zeroed, MaybeUninit};
-use std::os::windows::ffi::OsStringExt;
+use std::mem::zeroed;
 use std::os::windows::process::CommandExt;
 use std::path::{Path, PathBuf};
 use std::process;
+use std::sync::Arc;
+
+use windows::Win32::Foundation::{BOOL, FILETIME, HANDLE};
+use windows::Win32::System::Diagnostics::Debug::CheckRemoteDebuggerPresent;
+use windows::Win32::System::RemoteDesktop::ProcessIdToSessionId;
+use windows::Win32::System::Threading::{GetProcessTimes, CREATE_NO_WINDOW};
+
+#[cfg(feature = "processes")]
+use crate::utils::RunTimeClock;
+#[cfg(feature = "processes")]
+use crate::windows::Sid;
+#[cfg(feature = "processes")]
+use crate::ProcessRefreshKind;
+
+#[cfg(feature = "processes")]
+use std::mem::{size_of, MaybeUninit};
+#[cfg(feature = "processes")]
+use std::os::windows::ffi::OsStringExt;
+#[cfg(feature = "processes")]
 use std::ptr::null_mut;
+#[cfg(feature = "processes")]
 use std::str;
-use std::sync::{Arc, OnceLock};
+#[cfg(feature = "processes")]
+use std::sync::OnceLock;
 
+#[cfg(feature = "processes")]
 use libc::c_void;
+#[cfg(feature = "processes")]
 use ntapi::ntexapi::{SystemProcessIdInformation, SYSTEM_PROCESS_ID_INFORMATION};
+#[cfg(feature = "processes")]
 use ntapi::ntrtl::RTL_USER_PROCESS_PARAMETERS;
+#[cfg(feature = "processes")]
 use ntapi::ntwow64::{PEB32, RTL_USER_PROCESS_PARAMETERS32};
+#[cfg(feature = "processes")]
 use windows::core::PCWSTR;
+#[cfg(feature = "processes")]
 use windows::Wdk::System::SystemInformation::{NtQuerySystemInformation, SYSTEM_INFORMATION_CLASS};
+#[cfg(feature = "processes")]
 use windows::Wdk::System::SystemServices::RtlGetVersion;
+#[cfg(feature = "processes")]
 use windows::Wdk::System::Threading::{
     NtQueryInformationProcess, ProcessBasicInformation, ProcessCommandLineInformation,
     ProcessWow64Information, PROCESSINFOCLASS,
 };
+#[cfg(feature = "processes")]
 use windows::Win32::Foundation::{
-    LocalFree, ERROR_INSUFFICIENT_BUFFER, FILETIME, HANDLE, HINSTANCE, HLOCAL, MAX_PATH,
-    STATUS_BUFFER_OVERFLOW, STATUS_BUFFER_TOO_SMALL, STATUS_INFO_LENGTH_MISMATCH, UNICODE_STRING,
+    LocalFree, ERROR_INSUFFICIENT_BUFFER, HINSTANCE, HLOCAL, MAX_PATH, STATUS_BUFFER_OVERFLOW,
+    STATUS_BUFFER_TOO_SMALL, STATUS_INFO_LENGTH_MISMATCH, UNICODE_STRING,
 };
+#[cfg(feature = "processes")]
 use windows::Win32::Security::{GetTokenInformation, TokenUser, TOKEN_QUERY, TOKEN_USER};
+#[cfg(feature = "processes")]
 use windows::Win32::System::Diagnostics::Debug::ReadProcessMemory;
+#[cfg(feature = "processes")]
 use windows::Win32::System::Memory::{
     GetProcessHeap, HeapAlloc, HeapFree, LocalAlloc, VirtualQueryEx, HEAP_ZERO_MEMORY, LMEM_FIXED,
     LMEM_ZEROINIT, MEMORY_BASIC_INFORMATION,
 };
+#[cfg(feature = "processes")]
 use windows::Win32::System::ProcessStatus::{
     GetModuleFileNameExW, GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS_EX,
 };
-use windows::Win32::System::RemoteDesktop::ProcessIdToSessionId;
+#[cfg(feature = "processes")]
 use windows::Win32::System::SystemInformation::OSVERSIONINFOEXW;
+#[cfg(feature = "processes")]
 use windows::Win32::System::Threading::{
-    GetProcessIoCounters, GetProcessTimes, GetSystemTimes, OpenProcess, OpenProcessToken,
-    CREATE_NO_WINDOW, IO_COUNTERS, PEB, PROCESS_BASIC_INFORMATION, PROCESS_QUERY_INFORMATION,
-    PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_VM_READ,
+    GetProcessIoCounters, GetSystemTimes, OpenProcess, OpenProcessToken, IO_COUNTERS, PEB,
+    PROCESS_BASIC_INFORMATION, PROCESS_QUERY_INFORMATION, PROCESS_QUERY_LIMITED_INFORMATION,
+    PROCESS_VM_READ,
 };
+#[cfg(feature = "processes")]
 use windows::Win32::UI::Shell::CommandLineToArgvW;
 
 impl fmt::Display for ProcessStatus {
@@ -60,6 +94,7 @@ impl fmt::Display for ProcessStatus {
     }
 }
 
+#[cfg(feature = "processes")]
 fn get_process_handler(pid: Pid) -> Option<HandleWrapper> {
     if pid.0 == 0 {
         return None;
@@ -86,6 +121,7 @@ fn get_process_handler(pid: Pid) -> Option<HandleWrapper> {
         })
 }
 
+#[cfg(feature = "processes")]
 unsafe fn get_process_user_id(process: &mut ProcessInner, refresh_kind: ProcessRefreshKind) {
     struct HeapWrap<T>(*mut T);
 
@@ -170,6 +206,7 @@ unsafe fn get_process_user_id(process: &mut ProcessInner, refresh_kind: ProcessR
 unsafe impl Send for HandleWrapper {}
 unsafe impl Sync for HandleWrapper {}
 
+#[derive(Clone)]
 pub(crate) struct ProcessInner {
     name: OsString,
     cmd: Vec<OsString>,
@@ -184,17 +221,23 @@ pub(crate) struct ProcessInner {
     pub(crate) parent: Option<Pid>,
     status: ProcessStatus,
     handle: Option<Arc<HandleWrapper>>,
+    #[cfg(feature = "processes")]
     cpu_calc_values: CPUsageCalculationValues,
     start_time: u64,
     pub(crate) run_time: u64,
+    accumulated_cpu_time: u64,
     cpu_usage: f32,
+    #[cfg(feature = "processes")]
     pub(crate) updated: bool,
     old_read_bytes: u64,
     old_written_bytes: u64,
     read_bytes: u64,
     written_bytes: u64,
+    pub(crate) page_faults: Option<PageFaults>,
 }
 
+#[cfg(feature = "processes")]
+#[derive(Clone)]
 struct CPUsageCalculationValues {
     old_process_sys_cpu: u64,
     old_process_user_cpu: u64,
@@ -202,6 +245,7 @@ struct CPUsageCalculationValues {
     old_system_user_cpu: u64,
 }
 
+#[cfg(feature = "processes")]
 impl CPUsageCalculationValues {
     fn new() -> Self {
         CPUsageCalculationValues {
@@ -213,6 +257,7 @@ impl CPUsageCalculationValues {
     }
 }
 
+#[cfg(feature = "processes")]
 fn windows_8_1_or_newer() -> &'static bool {
     static WINDOWS_8_1_OR_NEWER: OnceLock<bool> = OnceLock::new();
     WINDOWS_8_1_OR_NEWER.get_or_init(|| unsafe {
@@ -229,7 +274,7 @@ fn windows_8_1_or_newer() -> &'static bool {
     })
 }
 
-#[cfg(feature = "debug")]
+#[cfg(all(feature = "debug", feature = "processes"))]
 unsafe fn display_ntstatus_error(ntstatus: windows::core::HRESULT) {
     let code = ntstatus.0;
     let message = ntstatus.message();
@@ -242,6 +287,7 @@ unsafe fn display_ntstatus_error(ntstatus: windows::core::HRESULT) {
 
 // Take a look at https://www.geoffchappell.com/studies/windows/km/ntoskrnl/api/ex/sysinfo/query.htm
 // for explanations.
+#[cfg(feature = "processes")]
 unsafe fn get_process_name(pid: Pid) -> Option<OsString> {
     let mut info = SYSTEM_PROCESS_ID_INFORMATION {
         ProcessId: pid.0 as _,
@@ -315,6 +361,7 @@ unsafe fn get_process_name(pid: Pid) -> Option<OsString> {
     name
 }
 
+#[cfg(feature = "processes")]
 unsafe fn get_exe(process_handler: &HandleWrapper) -> Option<PathBuf> {
     let mut exe_buf = [0u16; MAX_PATH as usize + 1];
     GetModuleFileNameExW(
@@ -327,11 +374,12 @@ unsafe fn get_exe(process_handler: &HandleWrapper) -> Option<PathBuf> {
 }
 
 impl ProcessInner {
-    pub(crate) fn new_from_pid(pid: Pid, now: u64) -> Option<Self> {
+    #[cfg(feature = "processes")]
+    pub(crate) fn new_from_pid(pid: Pid, clock: RunTimeClock) -> Option<Self> {
         unsafe {
             let process_handler = get_process_handler(pid)?;
             let name = get_process_name(pid).unwrap_or_default();
-            let (start_time, run_time) = get_start_and_run_time(*process_handler, now);
+            let (start_time, run_time) = get_start_and_run_time(*process_handler, clock);
             Some(Self {
                 handle: Some(Arc::new(process_handler)),
                 name,
@@ -348,6 +396,7 @@ impl ProcessInner {
                 virtual_memory: 0,
                 cpu_usage: 0.,
                 cpu_calc_values: CPUsageCalculationValues::new(),
+                accumulated_cpu_time: 0,
                 start_time,
                 run_time,
                 updated: true,
@@ -355,20 +404,22 @@ impl ProcessInner {
                 old_written_bytes: 0,
                 read_bytes: 0,
                 written_bytes: 0,
+                page_faults: None,
             })
         }
     }
 
+    #[cfg(feature = "processes")]
     pub(crate) fn new_full(
         pid: Pid,
         parent: Option<Pid>,
         memory: u64,
         virtual_memory: u64,
         name: OsString,
-        now: u64,
+        clock: RunTimeClock,
     ) -> Self {
         let (handle, start_time, run_time) = if let Some(handle) = get_process_handler(pid) {
-            let (start_time, run_time) = get_start_and_run_time(*handle, now);
+            let (start_time, run_time) = get_start_and_run_time(*handle, clock);
             (Some(Arc::new(handle)), start_time, run_time)
         } else {
             (None, 0, 0)
@@ -389,6 +440,7 @@ impl ProcessInner {
             virtual_memory,
             cpu_usage: 0.,
             cpu_calc_values: CPUsageCalculationValues::new(),
+            accumulated_cpu_time: 0,
             start_time,
             run_time,
             updated: true,
@@ -396,14 +448,16 @@ impl ProcessInner {
             old_written_bytes: 0,
             read_bytes: 0,
             written_bytes: 0,
+            page_faults: None,
         }
     }
 
+    #[cfg(feature = "processes")]
     pub(crate) fn update(
         &mut self,
         refresh_kind: crate::ProcessRefreshKind,
         nb_cpus: u64,
-        now: u64,
+        clock: RunTimeClock,
         refresh_parent: bool,
     ) {
         if refresh_kind.cpu() {
@@ -427,7 +481,7 @@ impl ProcessInner {
                 };
             }
         }
-        self.run_time = now.saturating_sub(self.start_time());
+        self.run_time = clock.run_time(self.start_time());
         self.updated = true;
     }
 
@@ -435,6 +489,7 @@ impl ProcessInner {
         self.handle.as_ref().map(|h| ***h)
     }
 
+    #[cfg(feature = "processes")]
     pub(crate) fn get_start_time(&self) -> Option<u64> {
         self.handle.as_ref().map(|handle| get_start_time(***handle))
     }
@@ -502,6 +557,14 @@ impl ProcessInner {
         self.run_time
     }
 
+    pub(crate) fn accumulated_cpu_time(&self) -> u64 {
+        self.accumulated_cpu_time
+    }
+
+    pub(crate) fn page_faults(&self) -> Option<PageFaults> {
+        self.page_faults
+    }
+
     pub(crate) fn cpu_usage(&self) -> f32 {
         self.cpu_usage
     }
@@ -559,6 +622,22 @@ impl ProcessInner {
             None
         }
     }
+
+    pub(crate) fn is_being_traced(&self) -> Option<bool> {
+        let handle = self.get_handle()?;
+        unsafe {
+            let mut present = BOOL::default();
+            if let Err(_error) = CheckRemoteDebuggerPresent(handle, &mut present) {
+                sysinfo_debug!("CheckRemoteDebuggerPresent failed, error: {:?}", _error);
+                return None;
+            }
+            Some(present.as_bool())
+        }
+    }
+
+    pub(crate) fn swapped_memory(&self) -> u64 {
+        0
+    }
 }
 
 #[inline]
@@ -577,6 +656,7 @@ unsafe fn get_process_times(handle: HANDLE) -> u64 {
 }
 
 // On Windows, the root folder is always the current drive. So we get it from its `cwd`.
+#[cfg(feature = "processes")]
 fn update_root(refresh_kind: ProcessRefreshKind, cwd: &Path, root: &mut Option<PathBuf>) {
     if !refresh_kind.root().needs_update(|| root.is_none()) {
         return;
@@ -601,11 +681,12 @@ fn compute_start(process_times: u64) -> u64 {
     process_times / 10_000_000 - 11_644_473_600
 }
 
-fn get_start_and_run_time(handle: HANDLE, now: u64) -> (u64, u64) {
+#[cfg(feature = "processes")]
+fn get_start_and_run_time(handle: HANDLE, clock: RunTimeClock) -> (u64, u64) {
     unsafe {
         let process_times = get_process_times(handle);
         let start = compute_start(process_times);
-        let run_time = check_sub(now, start);
+        let run_time = clock.run_time(start);
         (start, run_time)
     }
 }
@@ -618,6 +699,7 @@ pub(crate) fn get_start_time(handle: HANDLE) -> u64 {
     }
 }
 
+#[cfg(feature = "processes")]
 unsafe fn ph_query_process_variable_size(
     process_handle: HANDLE,
     process_information_class: PROCESSINFOCLASS,
@@ -663,6 +745,7 @@ unsafe fn ph_query_process_variable_size(
     Some(buffer)
 }
 
+#[cfg(feature = "processes")]
 unsafe fn get_cmdline_from_buffer(buffer: PCWSTR) -> Vec<OsString> {
     // Get argc and argv from the command line
     let mut argc = MaybeUninit::<i32>::uninit();
@@ -683,6 +766,7 @@ unsafe fn get_cmdline_from_buffer(buffer: PCWSTR) -> Vec<OsString> {
     res
 }
 
+#[cfg(feature = "processes")]
 unsafe fn get_region_size(handle: HANDLE, ptr: *const c_void) -> Result<usize, &'static str> {
     let mut meminfo = MaybeUninit::<MEMORY_BASIC_INFORMATION>::uninit();
     if VirtualQueryEx(
@@ -698,6 +782,7 @@ unsafe fn get_region_size(handle: HANDLE, ptr: *const c_void) -> Result<usize, &
     Ok((meminfo.RegionSize as isize - ptr.offset_from(meminfo.BaseAddress)) as usize)
 }
 
+#[cfg(feature = "processes")]
 unsafe fn get_process_data(
     handle: HANDLE,
     ptr: *const c_void,
@@ -729,12 +814,14 @@ unsafe fn get_process_data(
     Ok(buffer)
 }
 
+#[cfg(feature = "processes")]
 trait RtlUserProcessParameters {
     fn get_cmdline(&self, handle: HANDLE) -> Result<Vec<u16>, &'static str>;
     fn get_cwd(&self, handle: HANDLE) -> Result<Vec<u16>, &'static str>;
     fn get_environ(&self, handle: HANDLE) -> Result<Vec<u16>, &'static str>;
 }
 
+#[cfg(feature = "processes")]
 macro_rules! impl_RtlUserProcessParameters {
     ($t:ty) => {
         impl RtlUserProcessParameters for $t {
@@ -759,9 +846,12 @@ macro_rules! impl_RtlUserProcessParameters {
     };
 }
 
+#[cfg(feature = "processes")]
 impl_RtlUserProcessParameters!(RTL_USER_PROCESS_PARAMETERS32);
+#[cfg(feature = "processes")]
 impl_RtlUserProcessParameters!(RTL_USER_PROCESS_PARAMETERS);
 
+#[cfg(feature = "processes")]
 fn has_anything_to_update(process: &ProcessInner, refresh_kind: ProcessRefreshKind) -> bool {
     refresh_kind.cmd().needs_update(|| process.cmd.is_empty())
         || refresh_kind
@@ -771,6 +861,7 @@ fn has_anything_to_update(process: &ProcessInner, refresh_kind: ProcessRefreshKi
         || refresh_kind.root().needs_update(|| process.root.is_none())
 }
 
+#[cfg(feature = "processes")]
 unsafe fn get_process_params(
     process: &mut ProcessInner,
     refresh_kind: ProcessRefreshKind,
@@ -919,6 +1010,7 @@ unsafe fn get_process_params(
     );
 }
 
+#[cfg(feature = "processes")]
 fn get_cwd_and_root<T: RtlUserProcessParameters>(
     params: &T,
     handle: HANDLE,
@@ -947,6 +1039,7 @@ fn get_cwd_and_root<T: RtlUserProcessParameters>(
     }
 }
 
+#[cfg(feature = "processes")]
 unsafe fn null_terminated_wchar_to_string(slice: &[u16]) -> OsString {
     match slice.iter().position(|&x| x == 0) {
         Some(pos) => OsString::from_wide(&slice[..pos]),
@@ -954,6 +1047,7 @@ unsafe fn null_terminated_wchar_to_string(slice: &[u16]) -> OsString {
     }
 }
 
+#[cfg(feature = "processes")]
 fn get_cmd_line_old<T: RtlUserProcessParameters>(params: &T, handle: HANDLE) -> Vec<OsString> {
     match params.get_cmdline(handle) {
         Ok(buffer) => unsafe { get_cmdline_from_buffer(PCWSTR::from_raw(buffer.as_ptr())) },
@@ -965,6 +1059,7 @@ fn get_cmd_line_old<T: RtlUserProcessParameters>(params: &T, handle: HANDLE) ->
 }
 
 #[allow(clippy::cast_ptr_alignment)]
+#[cfg(feature = "processes")]
 fn get_cmd_line_new(handle: HANDLE) -> Vec<OsString> {
     unsafe {
         if let Some(buffer) = ph_query_process_variable_size(handle, ProcessCommandLineInformation)
@@ -978,6 +1073,7 @@ fn get_cmd_line_new(handle: HANDLE) -> Vec<OsString> {
     }
 }
 
+#[cfg(feature = "processes")]
 fn get_cmd_line<T: RtlUserProcessParameters>(
     params: &T,
     handle: HANDLE,
@@ -994,6 +1090,7 @@ fn get_cmd_line<T: RtlUserProcessParameters>(
     }
 }
 
+#[cfg(feature = "processes")]
 fn get_proc_env<T: RtlUserProcessParameters>(
     params: &T,
     handle: HANDLE,
@@ -1026,6 +1123,7 @@ fn get_proc_env<T: RtlUserProcessParameters>(
     }
 }
 
+#[cfg(feature = "processes")]
 pub(crate) fn get_executable_path(_pid: Pid) -> Option<PathBuf> {
     /*let where_req = format!("ProcessId={}", pid);
 
@@ -1041,6 +1139,7 @@ pub(crate) fn get_executable_path(_pid: Pid) -> Option<PathBuf> {
 }
 
 #[inline]
+#[cfg(feature = "processes")]
 fn check_sub(a: u64, b: u64) -> u64 {
     if a < b {
         a
@@ -1051,6 +1150,7 @@ fn check_sub(a: u64, b: u64) -> u64 {
 
 /// Before changing this function, you must consider the following:
 /// <https://github.com/GuillaumeGomez/sysinfo/issues/459>
+#[cfg(feature = "processes")]
 pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, nb_cpus: u64) {
     unsafe {
         let mut ftime: FILETIME = zeroed();
@@ -1085,6 +1185,8 @@ pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, nb_cpus: u64) {
 
         p.cpu_calc_values.old_process_user_cpu = user;
         p.cpu_calc_values.old_process_sys_cpu = sys;
+        // The times are in 100-nanosecond intervals.
+        p.accumulated_cpu_time = user.saturating_add(sys) / 10_000;
         p.cpu_calc_values.old_system_user_cpu = global_user_time;
         p.cpu_calc_values.old_system_sys_cpu = global_kernel_time;
 
@@ -1101,6 +1203,7 @@ pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, nb_cpus: u64) {
     }
 }
 
+#[cfg(feature = "processes")]
 pub(crate) fn update_disk_usage(p: &mut ProcessInner) {
     let mut counters = MaybeUninit::<IO_COUNTERS>::uninit();
 
@@ -1119,6 +1222,7 @@ pub(crate) fn update_disk_usage(p: &mut ProcessInner) {
     }
 }
 
+#[cfg(feature = "processes")]
 pub(crate) fn update_memory(p: &mut ProcessInner) {
     if let Some(handle) = p.get_handle() {
         unsafe {
@@ -1132,12 +1236,16 @@ pub(crate) fn update_memory(p: &mut ProcessInner) {
             {
                 p.memory = pmc.WorkingSetSize as _;
                 p.virtual_memory = pmc.PrivateUsage as _;
+                // Windows doesn't tell apart the page faults which required to read from the
+                // disk, so they're all counted as minor ones.
+                p.page_faults = Some(PageFaults::new(p.page_faults, pmc.PageFaultCount as _, 0));
             }
         }
     }
 }
 
 #[inline(always)]
+#[cfg(feature = "processes")]
 const fn filetime_to_u64(ft: FILETIME) -> u64 {
     ((ft.dwHighDateTime as u64) << 32) + ft.dwLowDateTime as u64
 }
@@ -1,35 +1,52 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, ProcessRefreshKind};
+use crate::{Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, PowerSource, SwapFile};
+#[cfg(feature = "processes")]
+use crate::{PageFaults, ProcessRefreshKind, Uid, UpdateKind};
 
 use crate::sys::cpu::*;
+#[cfg(feature = "processes")]
 use crate::sys::process::get_start_time;
 use crate::sys::tools::*;
-use crate::sys::utils::{get_now, get_reg_string_value, get_reg_value_u32};
-use crate::{Process, ProcessInner};
+use crate::sys::utils::{filetime_to_u64, get_reg_string_value, get_reg_value_u32};
+use crate::Process;
+#[cfg(feature = "processes")]
+use crate::ProcessInner;
 
-use crate::utils::into_iter;
+#[cfg(feature = "processes")]
+use crate::utils::{into_iter, take_exited_processes, RunTimeClock};
 
+#[cfg(feature = "processes")]
 use std::cell::UnsafeCell;
 use std::collections::HashMap;
+#[cfg(feature = "processes")]
 use std::ffi::OsString;
 use std::mem::{size_of, zeroed};
+#[cfg(feature = "processes")]
 use std::os::windows::ffi::OsStringExt;
+#[cfg(feature = "processes")]
 use std::ptr;
 use std::time::SystemTime;
 
+#[cfg(feature = "processes")]
 use ntapi::ntexapi::SYSTEM_PROCESS_INFORMATION;
 use windows::core::PWSTR;
+#[cfg(feature = "processes")]
 use windows::Wdk::System::SystemInformation::{NtQuerySystemInformation, SystemProcessInformation};
-use windows::Win32::Foundation::{HANDLE, STATUS_INFO_LENGTH_MISMATCH, STILL_ACTIVE};
+#[cfg(feature = "processes")]
+use windows::Win32::Foundation::STATUS_INFO_LENGTH_MISMATCH;
+use windows::Win32::Foundation::{FILETIME, HANDLE, STILL_ACTIVE};
+use windows::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+#[cfg(feature = "processes")]
+use windows::Win32::System::ProcessStatus::K32EnumProcesses;
 use windows::Win32::System::ProcessStatus::{K32GetPerformanceInfo, PERFORMANCE_INFORMATION};
 use windows::Win32::System::Registry::HKEY_LOCAL_MACHINE;
 use windows::Win32::System::SystemInformation;
 use windows::Win32::System::SystemInformation::{
-    ComputerNamePhysicalDnsHostname, GetComputerNameExW, GetTickCount64, GlobalMemoryStatusEx,
-    MEMORYSTATUSEX, SYSTEM_INFO,
+    ComputerNamePhysicalDnsHostname, GetComputerNameExW, GetSystemInfo, GetTickCount64,
+    GlobalMemoryStatusEx, MEMORYSTATUSEX, SYSTEM_INFO,
 };
-use windows::Win32::System::Threading::GetExitCodeProcess;
+use windows::Win32::System::Threading::{GetExitCodeProcess, GetSystemTimes};
 
 const WINDOWS_ELEVEN_BUILD_NUMBER: u32 = 22000;
 
@@ -44,12 +61,32 @@ impl SystemInner {
 }
 
 // Useful for parallel iterations.
+#[cfg(feature = "processes")]
 struct Wrap<T>(T);
 
+#[cfg(feature = "processes")]
 #[allow(clippy::non_send_fields_in_send_ty)]
 unsafe impl<T> Send for Wrap<T> {}
+#[cfg(feature = "processes")]
 unsafe impl<T> Sync for Wrap<T> {}
 
+/// Updates the global CPU usage from the counters of the (already refreshed) `query`.
+fn update_global_cpu_usage(query: &Query, cpus: &mut CpusWrapper, alpha: f32) {
+    let mut total_idle_time = None;
+    if let Some(ref key_used) = *get_key_used(cpus.global_cpu_mut()) {
+        total_idle_time = Some(
+            query
+                .get(&key_used.unique_id)
+                .expect("global_key_idle disappeared"),
+        );
+    }
+    if let Some(total_idle_time) = total_idle_time {
+        cpus.global_cpu_mut()
+            .inner
+            .set_cpu_usage(100.0 - total_idle_time, alpha);
+    }
+}
+
 unsafe fn boot_time() -> u64 {
     match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
         Ok(n) => n.as_secs().saturating_sub(GetTickCount64() / 1_000),
@@ -83,7 +120,7 @@ impl SystemInner {
         }
     }
 
-    pub(crate) fn refresh_cpu_specifics(&mut self, refresh_kind: CpuRefreshKind) {
+    fn init_query(&mut self, refresh_kind: CpuRefreshKind) {
         if self.query.is_none() {
             self.query = Query::new();
             if let Some(ref mut query) = self.query {
@@ -103,22 +140,36 @@ impl SystemInner {
                 }
             }
         }
+    }
+
+    /// Returns the smoothing factor to apply to the CPU usage: there is nothing to blend it with
+    /// before the first refresh.
+    fn cpu_smoothing_alpha(&self) -> f32 {
+        if self.cpus.cpus().is_empty() {
+            1.
+        } else {
+            self.cpus.cpu_smoothing
+        }
+    }
+
+    pub(crate) fn refresh_global_cpu_usage(&mut self) {
+        let alpha = self.cpu_smoothing_alpha();
+        self.init_query(CpuRefreshKind::new().with_cpu_usage());
+        // The PDH query collects the counters of all the CPUs at once, only the global one is
+        // read here.
         if let Some(ref mut query) = self.query {
             query.refresh();
-            let mut total_idle_time = None;
-            if let Some(ref key_used) = *get_key_used(self.cpus.global_cpu_mut()) {
-                total_idle_time = Some(
-                    query
-                        .get(&key_used.unique_id)
-                        .expect("global_key_idle disappeared"),
-                );
-            }
-            if let Some(total_idle_time) = total_idle_time {
-                self.cpus
-                    .global_cpu_mut()
-                    .inner
-                    .set_cpu_usage(100.0 - total_idle_time);
-            }
+            update_global_cpu_usage(query, &mut self.cpus, alpha);
+            self.cpus.refresh_global_times();
+        }
+    }
+
+    pub(crate) fn refresh_cpu_specifics(&mut self, refresh_kind: CpuRefreshKind) {
+        let alpha = self.cpu_smoothing_alpha();
+        self.init_query(refresh_kind);
+        if let Some(ref mut query) = self.query {
+            query.refresh();
+            update_global_cpu_usage(query, &mut self.cpus, alpha);
             for p in self.cpus.iter_mut(refresh_kind) {
                 let mut idle_time = None;
                 if let Some(ref key_used) = *get_key_used(p) {
@@ -129,9 +180,12 @@ impl SystemInner {
                     );
                 }
                 if let Some(idle_time) = idle_time {
-                    p.inner.set_cpu_usage(100.0 - idle_time);
+                    p.inner.set_cpu_usage(100.0 - idle_time, alpha);
                 }
             }
+            if refresh_kind.cpu_usage() {
+                self.cpus.refresh_times();
+            }
             if refresh_kind.frequency() {
                 self.cpus.get_frequencies();
             }
@@ -175,24 +229,25 @@ impl SystemInner {
     }
 
     #[allow(clippy::map_entry)]
+    #[cfg(feature = "processes")]
     pub(crate) fn refresh_process_specifics(
         &mut self,
         pid: Pid,
         refresh_kind: ProcessRefreshKind,
     ) -> bool {
-        let now = get_now();
+        let clock = RunTimeClock::now();
         let nb_cpus = self.cpus.len() as u64;
 
         if let Some(proc_) = self.process_list.get_mut(&pid) {
-            if let Some(ret) = refresh_existing_process(proc_, nb_cpus, now, refresh_kind) {
+            if let Some(ret) = refresh_existing_process(proc_, nb_cpus, clock, refresh_kind) {
                 return ret;
             }
             // We need to re-make the process because the PID owner changed.
         }
-        if let Some(mut p) = ProcessInner::new_from_pid(pid, now) {
-            p.update(refresh_kind, nb_cpus, now, true);
+        if let Some(mut p) = ProcessInner::new_from_pid(pid, clock) {
+            p.update(refresh_kind, nb_cpus, clock, true);
             p.updated = false;
-            self.process_list.insert(pid, Process { inner: p });
+            self.process_list.insert(pid, Process::new(p));
             true
         } else {
             false
@@ -200,11 +255,21 @@ impl SystemInner {
     }
 
     #[allow(clippy::cast_ptr_alignment)]
+    #[cfg(feature = "processes")]
     pub(crate) fn refresh_processes_specifics(
         &mut self,
         filter: Option<&[Pid]>,
+        uid_filter: Option<&Uid>,
         refresh_kind: ProcessRefreshKind,
+        exited: Option<&mut Vec<Process>>,
     ) {
+        // The process owner is only known once its handle was opened, so we need to retrieve it
+        // to be able to filter.
+        let refresh_kind = if uid_filter.is_some() {
+            refresh_kind.with_user(UpdateKind::OnlyIfNotSet)
+        } else {
+            refresh_kind
+        };
         // Windows 10 notebook requires at least 512KiB of memory to make it in one go
         let mut buffer_size = 512 * 1024;
         let mut process_information: Vec<u8> = Vec::with_capacity(buffer_size);
@@ -302,7 +367,7 @@ impl SystemInner {
                 0
             };
 
-            let now = get_now();
+            let clock = RunTimeClock::now();
 
             #[cfg(feature = "multithread")]
             use rayon::iter::ParallelIterator;
@@ -333,7 +398,7 @@ impl SystemInner {
                                 proc_.memory = pi.WorkingSetSize as _;
                                 proc_.virtual_memory = pi.VirtualSize as _;
                             }
-                            proc_.update(refresh_kind, nb_cpus, now, false);
+                            proc_.update(refresh_kind, nb_cpus, clock, false);
                             // Update the parent in case it changed.
                             proc_.parent = parent;
                             return None;
@@ -348,18 +413,24 @@ impl SystemInner {
                         (0, 0)
                     };
                     let mut p =
-                        ProcessInner::new_full(pid, parent, memory, virtual_memory, name, now);
-                    p.update(refresh_kind.without_memory(), nb_cpus, now, false);
-                    Some(Process { inner: p })
+                        ProcessInner::new_full(pid, parent, memory, virtual_memory, name, clock);
+                    if refresh_kind.memory() {
+                        p.page_faults = Some(PageFaults::new(None, pi.PageFaultCount as _, 0));
+                    }
+                    p.update(refresh_kind.without_memory(), nb_cpus, clock, false);
+                    Some(Process::new(p))
                 })
                 .collect::<Vec<_>>();
             for p in processes.into_iter() {
                 self.process_list.insert(p.pid(), p);
             }
+            if let Some(exited) = exited {
+                take_exited_processes(&mut self.process_list, exited, |p| !p.inner.updated);
+            }
             self.process_list.retain(|_, v| {
                 let x = v.inner.updated;
                 v.inner.updated = false;
-                x
+                x && uid_filter.map_or(true, |uid| v.inner.user_id() == Some(uid))
             });
         }
     }
@@ -368,6 +439,39 @@ impl SystemInner {
         &self.process_list
     }
 
+    #[cfg(feature = "processes")]
+    pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
+        &mut self.process_list
+    }
+
+    #[cfg(feature = "processes")]
+    pub(crate) fn list_pids(&self) -> Vec<Pid> {
+        let mut pids: Vec<u32> = Vec::with_capacity(self.process_list.len().max(512) + 64);
+        loop {
+            let mut needed = 0;
+            let size = (pids.capacity() * size_of::<u32>()) as u32;
+            unsafe {
+                if !K32EnumProcesses(pids.as_mut_ptr(), size, &mut needed).as_bool() {
+                    sysinfo_debug!("K32EnumProcesses failed");
+                    return Vec::new();
+                }
+                // If the buffer was filled, some PIDs might be missing so we retry with a bigger
+                // one.
+                if needed < size {
+                    pids.set_len(needed as usize / size_of::<u32>());
+                    break;
+                }
+            }
+            // `reserve` is relative to the length, which is still `0` here.
+            pids.reserve(pids.capacity() * 2);
+        }
+        pids.into_iter().map(|pid| Pid(pid as _)).collect()
+    }
+
+    pub(crate) fn remove_process(&mut self, pid: Pid) -> Option<Process> {
+        self.process_list.remove(&pid)
+    }
+
     pub(crate) fn process(&self, pid: Pid) -> Option<&Process> {
         self.process_list.get(&pid)
     }
@@ -380,14 +484,44 @@ impl SystemInner {
         self.cpus.cpus()
     }
 
+    pub(crate) fn set_error_callback(&mut self, _callback: crate::utils::ErrorCallback) {}
+
+    pub(crate) fn set_cpu_smoothing(&mut self, alpha: f32) {
+        self.cpus.cpu_smoothing = alpha;
+    }
+
+    pub(crate) fn cpu_smoothing(&self) -> f32 {
+        self.cpus.cpu_smoothing
+    }
+
+    pub(crate) fn logical_cpu_count(&self) -> Option<usize> {
+        get_logical_cpu_count()
+    }
+
+    pub(crate) fn online_cpu_count(&self) -> Option<usize> {
+        get_logical_cpu_count()
+    }
+
     pub(crate) fn physical_core_count(&self) -> Option<usize> {
         get_physical_core_count()
     }
 
+    pub(crate) fn cache_size(&self, level: u8) -> Option<u64> {
+        get_cache_size(level)
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
 
+    pub(crate) fn page_size(&self) -> u64 {
+        unsafe {
+            let mut info = SYSTEM_INFO::default();
+            GetSystemInfo(&mut info);
+            info.dwPageSize as _
+        }
+    }
+
     pub(crate) fn free_memory(&self) -> u64 {
         // MEMORYSTATUSEX doesn't report free memory
         self.mem_available
@@ -413,10 +547,35 @@ impl SystemInner {
         self.swap_used
     }
 
+    pub(crate) fn swap_files(&self) -> Vec<SwapFile> {
+        Vec::new()
+    }
+
+    pub(crate) fn idle_time(&self) -> Option<u64> {
+        let mut idle_time = FILETIME::default();
+        unsafe {
+            GetSystemTimes(Some(&mut idle_time), None, None).ok()?;
+        }
+        // The value is in 100 nanoseconds units.
+        Some(filetime_to_u64(idle_time) / 10_000_000)
+    }
+
     pub(crate) fn uptime() -> u64 {
         unsafe { GetTickCount64() / 1_000 }
     }
 
+    pub(crate) fn power_source() -> PowerSource {
+        let mut status = SYSTEM_POWER_STATUS::default();
+        if unsafe { GetSystemPowerStatus(&mut status) }.is_err() {
+            return PowerSource::Unknown;
+        }
+        match status.ACLineStatus {
+            0 => PowerSource::Battery,
+            1 => PowerSource::Ac,
+            _ => PowerSource::Unknown,
+        }
+    }
+
     pub(crate) fn boot_time() -> u64 {
         unsafe { boot_time() }
     }
@@ -449,6 +608,14 @@ impl SystemInner {
         get_dns_hostname()
     }
 
+    pub(crate) fn hardware_model() -> Option<String> {
+        get_reg_string_value(
+            HKEY_LOCAL_MACHINE,
+            r"HARDWARE\DESCRIPTION\System\BIOS",
+            "SystemProductName",
+        )
+    }
+
     pub(crate) fn kernel_version() -> Option<String> {
         get_reg_string_value(
             HKEY_LOCAL_MACHINE,
@@ -515,10 +682,11 @@ pub(crate) fn is_proc_running(handle: HANDLE) -> bool {
 
 /// If it returns `None`, it means that the PID owner changed and that the `Process` must be
 /// completely recomputed.
+#[cfg(feature = "processes")]
 fn refresh_existing_process(
     proc_: &mut Process,
     nb_cpus: u64,
-    now: u64,
+    clock: RunTimeClock,
     refresh_kind: ProcessRefreshKind,
 ) -> Option<bool> {
     let proc_ = &mut proc_.inner;
@@ -534,13 +702,14 @@ fn refresh_existing_process(
     } else {
         return Some(false);
     }
-    proc_.update(refresh_kind, nb_cpus, now, false);
+    proc_.update(refresh_kind, nb_cpus, clock, false);
     proc_.updated = false;
     Some(true)
 }
 
 #[allow(clippy::size_of_in_element_count)]
 //^ needed for "name.Length as usize / std::mem::size_of::<u16>()"
+#[cfg(feature = "processes")]
 pub(crate) fn get_process_name(process: &SYSTEM_PROCESS_INFORMATION, process_id: Pid) -> OsString {
     let name = &process.ImageName;
     if name.Buffer.is_null() {
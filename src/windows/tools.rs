@@ -2,6 +2,7 @@
 
 use crate::sys::cpu::Query;
 
+#[derive(Clone)]
 pub(crate) struct KeyHandler {
     pub unique_id: String,
 }
@@ -7,6 +7,7 @@ use crate::{
     Group, GroupInner, User,
 };
 
+use std::path::Path;
 use std::ptr::null_mut;
 use windows::core::{w, PCWSTR};
 use windows::Win32::Foundation::{ERROR_MORE_DATA, LUID};
@@ -63,6 +64,14 @@ impl UserInner {
             Vec::new()
         }
     }
+
+    pub(crate) fn home_directory(&self) -> Option<&Path> {
+        None
+    }
+
+    pub(crate) fn shell(&self) -> Option<&Path> {
+        None
+    }
 }
 
 struct NetApiBuffer<T>(*mut T);
@@ -12,21 +12,12 @@ use windows::Win32::System::Registry::{
 use std::ffi::OsStr;
 use std::ops::Deref;
 use std::os::windows::ffi::OsStrExt;
-use std::time::SystemTime;
 
 #[inline]
 pub(crate) fn filetime_to_u64(f: FILETIME) -> u64 {
     (f.dwHighDateTime as u64) << 32 | (f.dwLowDateTime as u64)
 }
 
-#[inline]
-pub(crate) fn get_now() -> u64 {
-    SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .map(|n| n.as_secs())
-        .unwrap_or(0)
-}
-
 pub(crate) unsafe fn to_utf8_str(p: PWSTR) -> String {
     if p.is_null() {
         return String::new();
@@ -141,6 +132,7 @@ pub(crate) fn get_reg_value_u32(hkey: HKEY, path: &str, field_name: &str) -> Opt
 pub(crate) struct HandleWrapper(pub(crate) HANDLE);
 
 impl HandleWrapper {
+    #[cfg(feature = "processes")]
     pub(crate) fn new(handle: HANDLE) -> Option<Self> {
         if handle.is_invalid() {
             None
@@ -15,3 +15,23 @@ fn test_components() {
     c.refresh_list();
     assert!(!c.is_empty());
 }
+
+#[test]
+fn test_components_refresh_list() {
+    let mut c = sysinfo::Components::new_with_refreshed_list();
+    let count = c.len();
+
+    // The list is rebuilt from scratch so components shouldn't be duplicated.
+    c.refresh_list();
+    assert_eq!(c.len(), count);
+}
+
+#[test]
+fn test_components_cpu_temperature() {
+    assert_eq!(sysinfo::Components::new().cpu_temperature(), None);
+
+    let c = sysinfo::Components::new_with_refreshed_list();
+    if let Some(temperature) = c.cpu_temperature() {
+        assert!(temperature.is_finite());
+    }
+}
@@ -36,6 +36,19 @@ fn test_physical_core_numbers() {
     }
 }
 
+#[test]
+fn test_logical_cpu_count() {
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        let mut s = sysinfo::System::new();
+        let count = s
+            .logical_cpu_count()
+            .expect("failed to get logical CPU count");
+        assert!(count >= s.physical_core_count().unwrap_or(0));
+        s.refresh_cpu_all();
+        assert_eq!(s.cpus().len(), count);
+    }
+}
+
 #[test]
 fn test_global_cpu_info_not_set() {
     let mut s = sysinfo::System::new();
@@ -62,3 +75,126 @@ fn test_too_rapid_cpu_refresh() {
 
     assert!(s.cpus().iter().any(|c| !c.cpu_usage().is_nan()));
 }
+
+#[test]
+fn test_cpu_nice_usage() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let mut s = sysinfo::System::new();
+    s.refresh_cpu_usage();
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    s.refresh_cpu_usage();
+
+    // The nice usage is part of the CPU usage.
+    for cpu in s.cpus().iter().chain(std::iter::once(s.global_cpu_info())) {
+        assert!(cpu.nice_usage() >= 0.);
+        assert!(cpu.nice_usage() <= cpu.cpu_usage() + 0.001);
+    }
+}
+
+#[test]
+fn test_global_cpu_usage() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let mut s = sysinfo::System::new();
+    s.prime_cpu_usage();
+    assert!(!s.cpus().is_empty());
+    assert_eq!(s.global_cpu_usage(), s.global_cpu_info().cpu_usage());
+}
+
+#[test]
+fn test_cpu_usage_since() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let mut s = sysinfo::System::new();
+    s.refresh_cpu_usage();
+    let times = s.cpu_times();
+    for _ in 0..2 {
+        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        s.refresh_cpu_usage();
+    }
+
+    let usages = s.cpu_usage_since(&times);
+    assert_eq!(usages.len(), s.cpus().len());
+    for usage in usages
+        .into_iter()
+        .chain(std::iter::once(s.global_cpu_usage_since(&times)))
+    {
+        assert!((0. ..=100.).contains(&usage));
+    }
+    // Nothing happened since the last refresh.
+    assert_eq!(s.global_cpu_usage_since(&s.cpu_times()), 0.);
+}
+
+#[test]
+fn test_cache_sizes() {
+    let s = sysinfo::System::new();
+    for size in [s.l1_cache_size(), s.l2_cache_size(), s.l3_cache_size()]
+        .into_iter()
+        .flatten()
+    {
+        assert!(size != 0);
+    }
+}
+
+#[test]
+fn test_refresh_global_cpu_usage() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = sysinfo::System::new();
+    s.refresh_global_cpu_usage();
+    assert!(!s.cpus().is_empty());
+    let cpus_usage = s.cpus().iter().map(|c| c.cpu_usage()).collect::<Vec<_>>();
+
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    s.refresh_global_cpu_usage();
+    assert!((0. ..=100.).contains(&s.global_cpu_usage()));
+    // The CPUs aren't updated.
+    assert_eq!(
+        s.cpus().iter().map(|c| c.cpu_usage()).collect::<Vec<_>>(),
+        cpus_usage,
+    );
+}
+
+#[test]
+fn test_cpu_smoothing() {
+    let mut s = sysinfo::System::new();
+    assert_eq!(s.cpu_smoothing(), 1.);
+    s.set_cpu_smoothing(2.);
+    assert_eq!(s.cpu_smoothing(), 1.);
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    // With an `alpha` of `0`, the CPU usage never changes after the first refresh.
+    s.set_cpu_smoothing(0.);
+    s.refresh_cpu_usage();
+    let global_usage = s.global_cpu_usage();
+    let cpus_usage = s.cpus().iter().map(|c| c.cpu_usage()).collect::<Vec<_>>();
+
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    s.refresh_cpu_usage();
+    assert_eq!(s.global_cpu_usage(), global_usage);
+    assert_eq!(
+        s.cpus().iter().map(|c| c.cpu_usage()).collect::<Vec<_>>(),
+        cpus_usage,
+    );
+}
+
+#[test]
+fn test_online_cpu_count() {
+    let s = sysinfo::System::new();
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_eq!(s.online_cpu_count(), None);
+        return;
+    }
+    let online = s
+        .online_cpu_count()
+        .expect("failed to get online CPU count");
+    assert!(online > 0);
+    assert!(online <= s.logical_cpu_count().unwrap_or(online));
+}
@@ -13,3 +13,147 @@ fn test_disks() {
         }
     }
 }
+
+#[test]
+fn test_boot_disk() {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    // There can't be more than one boot disk.
+    assert!(
+        disks
+            .list()
+            .iter()
+            .filter(|disk| disk.is_boot_disk())
+            .count()
+            <= 1
+    );
+}
+
+#[test]
+fn test_refresh_disk() {
+    let mut disks = sysinfo::Disks::new_with_refreshed_list();
+    assert!(!disks.refresh_disk(std::path::Path::new("/sysinfo/not/a/mount/point")));
+    if let Some(mount_point) = disks
+        .list()
+        .first()
+        .map(|disk| disk.mount_point().to_owned())
+    {
+        assert!(disks.refresh_disk(&mount_point));
+    }
+}
+
+#[test]
+fn test_refresh_with_timeout() {
+    let mut disks = sysinfo::Disks::new_with_refreshed_list();
+    disks.refresh_with_timeout(std::time::Duration::from_secs(10));
+    assert!(disks.list().iter().all(|disk| !disk.is_timed_out()));
+}
+
+#[test]
+fn test_refresh_list_with_timeout() {
+    let mut disks = sysinfo::Disks::new();
+    assert!(disks.refresh_list_with_timeout(std::time::Duration::from_secs(10)));
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(!disks.list().is_empty());
+    }
+}
+
+#[test]
+fn test_disks_inodes() {
+    let mut disks = sysinfo::Disks::new_with_refreshed_list();
+    disks.refresh();
+    for disk in disks.list() {
+        assert!(disk.available_inodes() <= disk.total_inodes());
+    }
+    if cfg!(target_os = "linux") && sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(disks.list().iter().any(|disk| disk.total_inodes() != 0));
+    }
+}
+
+#[test]
+fn test_disks_device_name() {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    for disk in disks.list() {
+        if let Some(device_name) = disk.device_name() {
+            assert!(!device_name.is_empty());
+            assert!(!device_name.to_string_lossy().starts_with("/dev/"));
+        }
+    }
+}
+
+#[test]
+fn test_disks_available_space_crossing() {
+    use sysinfo::ThresholdCrossing;
+
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    // Nothing changed since the disks were listed.
+    for disk in disks.list() {
+        assert_eq!(
+            disk.available_space_crossing(disk.available_space()),
+            ThresholdCrossing::NoChange,
+        );
+        assert_eq!(
+            disks.available_space_crossing(disk.mount_point(), 0),
+            Some(ThresholdCrossing::NoChange),
+        );
+    }
+    assert_eq!(
+        disks.available_space_crossing(std::path::Path::new("/does/not/exist"), 0),
+        None,
+    );
+}
+
+#[test]
+fn test_disks_disk_for_path() {
+    use std::path::Path;
+
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    assert!(disks.disk_for_path(Path::new("/does/not/exist")).is_none());
+    if let Some(root) = disks
+        .iter()
+        .find(|disk| disk.mount_point() == Path::new("/"))
+    {
+        assert_eq!(
+            disks
+                .disk_for_path(Path::new("/"))
+                .map(|disk| disk.mount_point()),
+            Some(root.mount_point()),
+        );
+    }
+    // On macOS, `/Users` is firmlinked to the data volume.
+    if cfg!(target_os = "macos") {
+        let data = Path::new("/System/Volumes/Data");
+        if disks.iter().any(|disk| disk.mount_point() == data) {
+            assert_eq!(
+                disks
+                    .disk_for_path(Path::new("/Users"))
+                    .map(|disk| disk.mount_point()),
+                Some(data),
+            );
+        }
+    }
+}
+
+#[test]
+fn test_disks_refresh_list_diff() {
+    let mut disks = sysinfo::Disks::new();
+    let diff = disks.refresh_list_diff();
+    assert!(diff.removed.is_empty());
+    assert!(disks
+        .list()
+        .iter()
+        .all(|disk| diff.added.iter().any(|path| path == disk.mount_point())));
+    // Nothing should have been mounted or unmounted in the meantime.
+    assert_eq!(disks.refresh_list_diff(), sysinfo::DiskListDiff::default());
+}
+
+#[test]
+fn test_disks_export_metrics() {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    let metrics = disks.export_metrics();
+    assert_eq!(metrics.len(), disks.list().len() * 4);
+    // Mount points are sanitized so each name always has exactly 3 components.
+    for (name, _) in metrics {
+        assert_eq!(name.split('.').count(), 3, "{name}");
+        assert!(!name.contains('/'), "{name}");
+    }
+}
@@ -15,3 +15,112 @@ fn test_networks() {
         assert!(n.iter().count() > 0);
     }
 }
+
+#[test]
+fn test_networks_rates() {
+    use sysinfo::Networks;
+
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        let mut n = Networks::new_with_refreshed_list();
+        // Only one refresh so far, so no rate can be computed.
+        for (_, data) in &n {
+            assert_eq!(data.received_rate(), 0.);
+            assert_eq!(data.transmitted_rate(), 0.);
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        n.refresh();
+        for (_, data) in &n {
+            assert!(data.received_rate().is_finite());
+            assert!(data.transmitted_rate().is_finite());
+        }
+    }
+}
+
+#[test]
+fn test_networks_flags() {
+    use sysinfo::Networks;
+
+    if sysinfo::IS_SUPPORTED_SYSTEM && !cfg!(windows) {
+        let n = Networks::new_with_refreshed_list();
+        // There should always be an enabled loopback interface.
+        assert!(n
+            .iter()
+            .any(|(_, data)| data.is_loopback() && data.is_up() && data.is_running()));
+    }
+}
+
+#[test]
+fn test_default_interface() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    // There might be no default route (when offline for example), so we can only check that the
+    // interface exists when there is one.
+    if let Some(name) = sysinfo::Networks::default_interface() {
+        assert!(!name.is_empty());
+        // Software interfaces are not listed on Windows.
+        if !cfg!(windows) {
+            let networks = sysinfo::Networks::new_with_refreshed_list();
+            assert!(networks.contains_key(&name), "{name} not found");
+        }
+    }
+}
+
+#[test]
+fn test_networks_mtu() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let networks = sysinfo::Networks::new_with_refreshed_list();
+    // All the interfaces which can transmit packets have an MTU.
+    assert!(networks
+        .values()
+        .filter(|network| network.is_running())
+        .all(|network| network.mtu() > 0));
+}
+
+#[test]
+fn test_ip_networks_by_family() {
+    use sysinfo::AddressFamily;
+
+    let networks = sysinfo::Networks::new_with_refreshed_list();
+    for network in networks.values() {
+        let ipv4 = network
+            .ip_networks_by_family(AddressFamily::Ipv4)
+            .collect::<Vec<_>>();
+        let ipv6 = network
+            .ip_networks_by_family(AddressFamily::Ipv6)
+            .collect::<Vec<_>>();
+        assert!(ipv4.iter().all(|ip_network| ip_network.addr.is_ipv4()));
+        assert!(ipv6.iter().all(|ip_network| ip_network.addr.is_ipv6()));
+        assert_eq!(ipv4.len() + ipv6.len(), network.ip_networks().len());
+        assert_eq!(
+            network
+                .ip_networks_by_family(AddressFamily::default())
+                .count(),
+            network.ip_networks().len(),
+        );
+    }
+}
+
+#[test]
+fn test_global_network() {
+    let networks = sysinfo::Networks::new_with_refreshed_list();
+    let global = networks.global_network();
+    let expected = networks
+        .values()
+        .filter(|network| !network.is_loopback())
+        .map(|network| network.total_received())
+        .sum::<u64>();
+    assert_eq!(global.total_received, expected);
+    assert!(global.received_rate >= 0. && global.transmitted_rate >= 0.);
+}
+
+#[test]
+fn test_refresh_rates() {
+    let mut networks = sysinfo::Networks::new_with_refreshed_list();
+    networks.refresh_rates(std::time::Duration::from_millis(100));
+    assert!(networks
+        .values()
+        .all(|network| network.received_rate() >= 0. && network.transmitted_rate() >= 0.));
+}
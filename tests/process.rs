@@ -1,5 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+#![cfg(feature = "processes")]
+
 use bstr::ByteSlice;
 use sysinfo::{Pid, ProcessRefreshKind, System, UpdateKind};
 
@@ -329,6 +331,44 @@ fn test_process_session_id() {
     assert!(s.processes().values().any(|p| p.session_id().is_some()));
 }
 
+// Checks that the page faults are retrieved and that the totals don't decrease.
+#[test]
+fn test_process_page_faults() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_process_specifics(pid, ProcessRefreshKind::new().with_memory());
+    let first = s
+        .process(pid)
+        .and_then(|p| p.page_faults())
+        .expect("no page faults");
+    // Touching new memory pages creates (minor) page faults.
+    let v = vec![1u8; 16 * 1024 * 1024];
+    assert_eq!(v.iter().map(|x| *x as u64).sum::<u64>(), v.len() as u64);
+    s.refresh_process_specifics(pid, ProcessRefreshKind::new().with_memory());
+    let second = s
+        .process(pid)
+        .and_then(|p| p.page_faults())
+        .expect("no page faults");
+    assert!(second.total_minor >= first.total_minor);
+    assert!(second.total_major >= first.total_major);
+    assert_eq!(second.minor, second.total_minor - first.total_minor);
+}
+
+#[test]
+fn test_process_is_being_traced() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_process_specifics(pid, ProcessRefreshKind::new());
+    // We don't know if the tests are run under a debugger but we can always inspect ourselves.
+    assert!(s.process(pid).and_then(|p| p.is_being_traced()).is_some());
+}
+
 // Checks that `refresh_processes` is removing dead processes.
 #[test]
 fn test_refresh_processes() {
@@ -851,3 +891,376 @@ fn test_parent_change() {
     // We kill the child to clean up.
     child.kill();
 }
+
+#[test]
+fn test_refresh_processes_filtered() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let self_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes_filtered(None);
+
+    let uid = s
+        .process(self_pid)
+        .expect("current process not found")
+        .user_id()
+        .cloned();
+    // The user ID isn't retrieved with the default refresh kind on all platforms.
+    if let Some(uid) = uid {
+        s.refresh_processes_specifics(ProcessRefreshKind::new().with_user(UpdateKind::Always));
+        let all = s.processes().len();
+        s.refresh_processes_filtered(Some(&uid));
+        assert!(s.processes().len() <= all);
+        assert!(s.process(self_pid).is_some());
+    }
+}
+
+#[test]
+fn test_current_process() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    assert!(s.current_process().is_none());
+
+    let self_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_process(self_pid);
+    assert_eq!(s.current_process().map(|p| p.pid()), Some(self_pid));
+}
+
+#[test]
+fn test_process_clone() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    let self_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_process(self_pid);
+    let p = s
+        .process(self_pid)
+        .expect("current process not found")
+        .clone();
+    s.refresh_process(self_pid);
+    let current = s.process(self_pid).expect("current process not found");
+    assert_eq!(p.pid(), current.pid());
+    assert_eq!(p.name(), current.name());
+    assert_eq!(p.start_time(), current.start_time());
+}
+
+#[test]
+fn test_refresh_processes_diff() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") || cfg!(windows) {
+        return;
+    }
+    let mut s = System::new();
+    let diff = s.refresh_processes_diff();
+    assert!(diff.exited.is_empty());
+    assert_eq!(diff.added.len(), s.processes().len());
+
+    let mut p = std::process::Command::new("sleep")
+        .arg("3")
+        .stdout(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+    let pid = Pid::from_u32(p.id() as _);
+    std::thread::sleep(std::time::Duration::from_millis(250));
+    let diff = s.refresh_processes_diff();
+    assert!(diff.added.contains(&pid));
+
+    p.kill().expect("Unable to kill process.");
+    let _ = p.wait();
+    let diff = s.refresh_processes_diff();
+    assert!(diff.exited.iter().any(|p| p.pid() == pid));
+    assert!(s.process(pid).is_none());
+}
+
+#[test]
+fn test_average_cpu_usage() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    s.refresh_processes();
+    for process in s.processes().values() {
+        let usage = process.average_cpu_usage();
+        assert!(usage.is_finite() && usage >= 0.);
+        if process.run_time() == 0 {
+            assert_eq!(usage, 0.);
+        }
+    }
+}
+
+#[test]
+fn test_top_processes_by_memory() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    s.refresh_processes();
+    let top = s.top_processes_by_memory(3);
+    assert_eq!(top.len(), s.processes().len().min(3));
+    assert!(top.windows(2).all(|w| w[0].memory() >= w[1].memory()));
+    assert!(s.top_processes_by_cpu(0).is_empty());
+}
+
+#[test]
+fn test_processes_using_swap() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    s.refresh_processes();
+    let processes = s.processes_using_swap();
+    assert!(processes.iter().all(|p| p.swapped_memory() != 0));
+    assert!(processes
+        .windows(2)
+        .all(|w| w[0].swapped_memory() >= w[1].swapped_memory()));
+}
+
+#[test]
+fn test_process_command_line() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_process_specifics(pid, ProcessRefreshKind::everything());
+    let process = s.process(pid).expect("current process not found");
+    // Either the arguments or the executable path.
+    assert!(!process.command_line().is_empty());
+}
+
+#[test]
+fn test_list_pids() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let s = System::new();
+    let pids = s.list_pids();
+    assert!(pids.contains(&sysinfo::get_current_pid().unwrap()));
+    // No process information should have been retrieved.
+    assert!(s.processes().is_empty());
+}
+
+#[test]
+fn test_process_run_time_clock() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().unwrap();
+    s.refresh_process(pid);
+    let p = s.process(pid).expect("current process not found");
+    // The current process can't have been running for longer than the system.
+    assert!(p.run_time() <= System::uptime());
+    assert!(p.start_time() >= System::boot_time());
+}
+
+#[test]
+fn test_for_each_process() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let current_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    let mut found = false;
+    s.for_each_process(ProcessRefreshKind::new(), |process| {
+        found |= process.pid() == current_pid;
+    });
+    assert!(found);
+    assert!(s.processes().is_empty());
+}
+
+#[test]
+fn test_process_exe_resolved() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let current_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_process_specifics(
+        current_pid,
+        ProcessRefreshKind::new().with_exe(UpdateKind::Always),
+    );
+    let p = s.process(current_pid).expect("no process found");
+    let expected = std::fs::canonicalize(std::env::current_exe().unwrap()).unwrap();
+    assert_eq!(p.exe_resolved(), Some(expected));
+}
+
+#[test]
+fn test_process_swapped_memory() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let current_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_process_specifics(
+        current_pid,
+        ProcessRefreshKind::new()
+            .with_memory()
+            .with_memory_regions(),
+    );
+    let p = s.process(current_pid).expect("no process found");
+    assert!(p.swapped_memory() <= p.virtual_memory());
+}
+
+#[test]
+fn test_subtree_cpu_usage() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    s.refresh_processes();
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    s.refresh_processes();
+
+    let current_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let current = s.process(current_pid).expect("no process found");
+    assert!(s.subtree_cpu_usage(current_pid) >= current.cpu_usage());
+    assert_eq!(s.subtree_cpu_usage(Pid::from_u32(u32::MAX)), 0.);
+}
+
+#[test]
+fn test_user_processes() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    s.refresh_processes();
+    let pid = sysinfo::get_current_pid().unwrap();
+    assert!(s.user_processes(false).any(|p| p.pid() == pid));
+    assert!(s
+        .user_processes(false)
+        .all(|p| p.pid().as_u32() > 1 && p.thread_kind() != Some(sysinfo::ThreadKind::Kernel)));
+    assert!(s.user_processes(true).all(|p| p.pid().as_u32() != 0));
+    assert!(s.user_processes(true).count() <= s.processes().len());
+}
+
+#[test]
+fn test_orphaned_processes() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    s.refresh_processes();
+    // The current process' parent is still running.
+    let pid = sysinfo::get_current_pid().unwrap();
+    assert!(!s.orphaned_processes().contains(&pid));
+    for orphan in s.orphaned_processes() {
+        let parent = s.process(orphan).and_then(|p| p.parent()).unwrap();
+        assert!(s.process(parent).is_none());
+    }
+    for zombie in s.zombie_processes() {
+        assert_eq!(
+            s.process(zombie).unwrap().status(),
+            sysinfo::ProcessStatus::Zombie
+        );
+    }
+}
+
+#[test]
+fn test_process_supplementary_groups() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(windows) || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_process(pid);
+    let process = s.process(pid).expect("current process not found");
+    if let Some(groups) = process.supplementary_groups() {
+        // The primary group of the user is always included.
+        let users = sysinfo::Users::new_with_refreshed_list();
+        if let Some(user) = process.user_id().and_then(|uid| users.get_user_by_id(uid)) {
+            assert!(groups.contains(&user.group_id()));
+        }
+        assert_eq!(process.supplementary_groups(), Some(groups));
+    }
+}
+
+#[test]
+fn test_process_fd_breakdown() {
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_process(pid);
+    let fds = s
+        .process(pid)
+        .expect("current process not found")
+        .fd_breakdown();
+    if cfg!(all(target_os = "macos", not(feature = "apple-sandbox"))) {
+        // At least the executable and the standard streams are opened.
+        assert!(fds.total() > 0);
+    } else {
+        assert_eq!(fds, sysinfo::FdBreakdown::default());
+    }
+}
+
+#[test]
+fn test_process_first_and_last_seen() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes();
+    let (first_seen, last_seen) = {
+        let process = s.process(pid).expect("current process not found");
+        (process.first_seen(), process.last_seen())
+    };
+    assert!(first_seen <= last_seen);
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    s.refresh_processes();
+    let process = s.process(pid).expect("current process not found");
+    // The process was already listed, so only the last seen instant changes.
+    assert_eq!(process.first_seen(), first_seen);
+    assert!(process.last_seen() > last_seen);
+}
+
+#[test]
+fn test_process_command_line_truncated() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_process(pid);
+    let process = s.process(pid).expect("current process not found");
+    let command_line = process.command_line().to_string_lossy().into_owned();
+    assert_eq!(
+        process.command_line_truncated(command_line.len()),
+        command_line
+    );
+    for max_bytes in [0, 5, command_line.len() - 1] {
+        let truncated = process.command_line_truncated(max_bytes);
+        assert!(truncated.len() <= max_bytes);
+        assert!(max_bytes < 3 || truncated.ends_with('…'));
+    }
+}
+
+#[test]
+fn test_process_io_policy() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") || cfg!(windows) {
+        return;
+    }
+    let mut p = std::process::Command::new("sleep")
+        .arg("3")
+        .stdout(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(250));
+    let current_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let child_pid = Pid::from_u32(p.id() as _);
+    let mut s = System::new();
+    s.refresh_processes();
+    let current = s.process(current_pid).expect("no process found");
+    let child = s.process(child_pid).map(|child| child.io_policy());
+    p.kill().expect("Unable to kill process.");
+    let _ = p.wait();
+    if cfg!(target_os = "macos") {
+        assert!(current.io_policy().is_some());
+    } else {
+        assert_eq!(current.io_policy(), None);
+    }
+    // The I/O policy of other processes can never be queried.
+    assert_eq!(child.expect("no child process found"), None);
+}
@@ -8,4 +8,6 @@ fn test_send_sync() {
 
     is_send::<sysinfo::System>();
     is_sync::<sysinfo::System>();
+    is_send::<sysinfo::Snapshot>();
+    is_sync::<sysinfo::Snapshot>();
 }
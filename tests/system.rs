@@ -24,7 +24,7 @@ fn test_refresh_process() {
     assert!(sys.processes().is_empty(), "no process should be listed!");
     // We don't want to test on unsupported systems.
 
-    #[cfg(not(feature = "apple-sandbox"))]
+    #[cfg(all(not(feature = "apple-sandbox"), feature = "processes"))]
     if sysinfo::IS_SUPPORTED_SYSTEM {
         assert!(
             sys.refresh_process(sysinfo::get_current_pid().expect("failed to get current pid")),
@@ -53,7 +53,7 @@ fn test_get_process() {
     if let Some(p) = sys.process(current_pid) {
         assert!(p.memory() > 0);
     } else {
-        #[cfg(not(feature = "apple-sandbox"))]
+        #[cfg(all(not(feature = "apple-sandbox"), feature = "processes"))]
         assert!(!sysinfo::IS_SUPPORTED_SYSTEM);
     }
 }
@@ -88,7 +88,7 @@ fn check_if_send_and_sync() {
         p.bar(); // If this doesn't compile, it'll simply mean that the Process type
                  // doesn't implement the Sync trait.
     } else {
-        #[cfg(not(feature = "apple-sandbox"))]
+        #[cfg(all(not(feature = "apple-sandbox"), feature = "processes"))]
         assert!(!sysinfo::IS_SUPPORTED_SYSTEM);
     }
 }
@@ -117,6 +117,16 @@ fn check_boot_time() {
     }
 }
 
+#[test]
+fn check_idle_time() {
+    let s = System::new();
+    if sysinfo::IS_SUPPORTED_SYSTEM && !cfg!(target_os = "freebsd") {
+        assert!(s.idle_time().is_some());
+    } else {
+        assert_eq!(s.idle_time(), None);
+    }
+}
+
 // This test is used to ensure that the CPU usage computation isn't completely going off
 // when refreshing it too frequently (ie, multiple times in a row in a very small interval).
 #[test]
@@ -231,3 +241,113 @@ fn test_refresh_memory() {
     assert_ne!(s.total_memory(), 0);
     assert_ne!(s.free_memory(), 0);
 }
+
+#[test]
+fn test_snapshot() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let s = System::new_all();
+    let snapshot = s.snapshot();
+    assert_eq!(snapshot.total_memory, s.total_memory());
+    assert_eq!(snapshot.cpus.len(), s.cpus().len());
+    assert_eq!(snapshot.processes.len(), s.processes().len());
+    assert!(snapshot.disks.is_empty());
+    assert!(snapshot.networks.is_empty());
+
+    let snapshot = std::thread::spawn(move || snapshot.clone())
+        .join()
+        .expect("failed to send snapshot");
+    assert!(snapshot
+        .processes
+        .keys()
+        .all(|pid| s.process(*pid).is_some()));
+}
+
+#[test]
+fn test_os_descriptor() {
+    let descriptor = System::os_descriptor();
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let descriptor = descriptor.expect("failed to get OS descriptor");
+    if let Some(kernel_version) = System::kernel_version() {
+        assert!(descriptor.contains(&kernel_version));
+    }
+}
+
+#[test]
+fn test_try_new_with_specifics() -> Result<(), Box<dyn std::error::Error>> {
+    use sysinfo::{CpuRefreshKind, RefreshKind};
+
+    // The error can be propagated with `?`.
+    let s =
+        System::try_new_with_specifics(RefreshKind::new().with_cpu(CpuRefreshKind::everything()))?;
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(!s.cpus().is_empty());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_page_size() {
+    let s = System::new();
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(s.page_size().is_power_of_two());
+    } else {
+        assert_eq!(s.page_size(), 0);
+        assert_eq!(s.total_pages(), 0);
+    }
+}
+
+#[test]
+fn test_usage_percent() {
+    // Nothing was refreshed so the totals are unknown.
+    let s = System::new();
+    assert_eq!(s.memory_usage_percent(), 0.);
+    assert_eq!(s.swap_usage_percent(), 0.);
+
+    let s = System::new_all();
+    assert!((0. ..=100.).contains(&s.memory_usage_percent()));
+    assert!((0. ..=100.).contains(&s.swap_usage_percent()));
+}
+
+#[test]
+fn test_hardware_model() {
+    if let Some(model) = System::hardware_model() {
+        assert!(!model.is_empty());
+        assert!(!model.ends_with(char::is_whitespace));
+    }
+}
+
+#[test]
+fn test_export_metrics() {
+    let s = System::new_all();
+    let metrics = s.export_metrics();
+    let get = |name: &str| metrics.iter().find(|(n, _)| n == name).map(|(_, v)| *v);
+
+    assert_eq!(get("memory.total"), Some(s.total_memory() as f64));
+    assert!(get("cpu.usage").is_some());
+    for index in 0..s.cpus().len() {
+        assert!(get(&format!("cpu.{index}.usage")).is_some());
+    }
+}
+
+#[test]
+fn test_error_callback() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let errors = Arc::new(AtomicUsize::new(0));
+    let mut s = System::new();
+    let callback_errors = Arc::clone(&errors);
+    s.set_error_callback(move |error| {
+        assert!(error.contains(" failed: "), "{error}");
+        callback_errors.fetch_add(1, Ordering::Relaxed);
+    });
+    s.refresh_all();
+    // Failures are only reported on Apple targets.
+    if !cfg!(any(target_os = "macos", target_os = "ios")) {
+        assert_eq!(errors.load(Ordering::Relaxed), 0);
+    }
+}